@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Benchmark of name resolution when analyzing a large generated design
+//!
+//! Run with: cargo bench -p vhdl_lang --bench name_resolution
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use vhdl_lang::{Config, NullMessages, Project, Source};
+
+fn libraries_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("vhdl_libraries")
+}
+
+/// A package with a record type and overloaded functions followed by an architecture
+/// with `num_signals` signals that are used through selected names, indexed names
+/// and overloaded calls
+fn generate_design(num_signals: usize) -> String {
+    let mut code = String::new();
+    code.push_str(
+        "
+package pkg is
+  type rec_t is record
+    field : natural;
+    flag : boolean;
+  end record;
+
+  type arr_t is array (natural range <>) of rec_t;
+
+  function conv(arg : natural) return natural;
+  function conv(arg : boolean) return natural;
+  function conv(arg : rec_t) return natural;
+end package;
+
+package body pkg is
+  function conv(arg : natural) return natural is
+  begin
+    return arg;
+  end function;
+
+  function conv(arg : boolean) return natural is
+  begin
+    if arg then
+      return 1;
+    else
+      return 0;
+    end if;
+  end function;
+
+  function conv(arg : rec_t) return natural is
+  begin
+    return arg.field;
+  end function;
+end package body;
+
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+",
+    );
+
+    for i in 0..num_signals {
+        writeln!(code, "  signal rec{i} : rec_t;").unwrap();
+        writeln!(code, "  signal arr{i} : arr_t(0 to 7);").unwrap();
+        writeln!(code, "  signal res{i} : natural;").unwrap();
+    }
+
+    code.push_str("begin\n");
+
+    for i in 0..num_signals {
+        let j = (i + 1) % num_signals;
+        writeln!(code, "  res{i} <= conv(rec{i}.field) + conv(arr{j}(3).flag) + conv(rec{j}) + conv(arr{i}(0));").unwrap();
+    }
+
+    code.push_str("end architecture;\n");
+    code
+}
+
+fn create_project(code: &str) -> (Project, Source) {
+    let config = Config::from_str("[libraries]\nstd.files = ['std/*.vhd']", &libraries_root())
+        .expect("Failed to parse config");
+    let mut project = Project::from_config(&config, &mut NullMessages);
+    let source = Source::inline(Path::new("generated.vhd"), code);
+    project.update_source(&source);
+    (project, source)
+}
+
+fn name_resolution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("name_resolution");
+    group.sample_size(10);
+
+    for num_signals in [500, 2000] {
+        let code = generate_design(num_signals);
+        let (mut project, source) = create_project(&code);
+
+        // Baseline that does not depend on timing, all names must resolve without errors
+        let diagnostics = project.analyse();
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+        let (_, unresolved) = project.find_all_unresolved();
+        assert!(unresolved.is_empty(), "{unresolved:#?}");
+
+        group.bench_function(BenchmarkId::from_parameter(num_signals), |b| {
+            b.iter(|| {
+                // Updating the source forces the design to be analyzed again
+                project.update_source(&source);
+                project.analyse()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, name_resolution);
+criterion_main!(benches);
@@ -8,11 +8,13 @@
 mod analyze;
 mod assignment;
 mod association;
+mod combinational_loop;
 mod concurrent;
 mod declarative;
 mod design_unit;
 mod expression;
 mod formal_region;
+mod lint;
 mod literals;
 mod lock;
 mod named_entity;
@@ -20,9 +22,11 @@ mod names;
 mod overloaded;
 mod package_instance;
 mod range;
+mod read_before_write;
 mod region;
 mod root;
 mod semantic;
+mod sensitivity;
 mod sequential;
 mod standard;
 mod static_expression;
@@ -32,8 +36,11 @@ mod visibility;
 #[cfg(test)]
 mod tests;
 
-pub use self::root::{DesignRoot, EntHierarchy};
+pub use self::analyze::CircularDependencyError;
+pub use self::formal_region::InterfaceEnt;
+pub use self::lint::{builtin_lint_rules, LintRule, LowercaseSignalNames, NoConcurrentVariables};
+pub use self::root::{DesignRoot, EntHierarchy, ScopeSnapshot};
 pub use named_entity::{
-    AnyEnt, AnyEntKind, Concurrent, Design, EntRef, EntityId, HasEntityId, Object, Overloaded,
-    Related, Sequential, Type,
+    AnyEnt, AnyEntKind, Concurrent, Design, DesignEnt, EntRef, EntityId, HasEntityId, Object,
+    Overloaded, Related, Sequential, Type,
 };
@@ -9,10 +9,20 @@ use super::region::*;
 use super::root::*;
 use crate::ast::*;
 use crate::data::*;
+use crate::VhdlStandard;
 use fnv::FnvHashSet;
 use std::cell::RefCell;
 use std::ops::Deref;
 
+/// A block or generate statement of an architecture that can be configured
+/// by a nested block configuration
+pub(super) struct ConfigurableBlock<'a> {
+    pub label: EntRef<'a>,
+    /// The components declared within the block
+    pub components: Vec<EntRef<'a>>,
+    pub blocks: Vec<ConfigurableBlock<'a>>,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum AnalysisError {
     Fatal(CircularDependencyError),
@@ -155,6 +165,8 @@ pub(super) struct AnalyzeContext<'a> {
     uses: RefCell<FnvHashSet<UnitId>>,
     missing_unit: RefCell<FnvHashSet<(Symbol, Symbol, Option<Symbol>)>>,
     uses_library_all: RefCell<FnvHashSet<Symbol>>,
+    // The names visible within each recorded source range
+    pub(super) scopes: RefCell<Vec<(SrcPos, Vec<EntityId>)>>,
 }
 
 impl<'a> AnalyzeContext<'a> {
@@ -178,9 +190,23 @@ impl<'a> AnalyzeContext<'a> {
             uses: RefCell::new(FnvHashSet::default()),
             missing_unit: RefCell::new(FnvHashSet::default()),
             uses_library_all: RefCell::new(FnvHashSet::default()),
+            scopes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Record the names visible within the source range when enabled
+    pub fn record_scope(&self, pos: &SrcPos, scope: &Scope<'a>) {
+        if self.root.record_scopes {
+            let visible = scope.visible_names().iter().map(|ent| ent.id()).collect();
+            self.scopes.borrow_mut().push((pos.clone(), visible));
         }
     }
 
+    /// The VHDL standard that is analyzed against
+    pub fn standard(&self) -> VhdlStandard {
+        self.root.standard
+    }
+
     pub fn work_library_name(&self) -> &Symbol {
         self.current_unit.library_name()
     }
@@ -246,6 +272,11 @@ impl<'a> AnalyzeContext<'a> {
         let units = self.root.get_library_units(library_name).unwrap();
 
         for unit in units.values() {
+            // The unit being analyzed is not yet part of the library
+            if unit.unit_id() == &self.current_unit {
+                continue;
+            }
+
             match unit.kind() {
                 AnyKind::Primary(..) => {
                     let data = self.get_analysis(Some(use_pos), unit)?;
@@ -386,6 +417,80 @@ impl<'a> AnalyzeContext<'a> {
         )))
     }
 
+    /// The block and generate statements within the statement part of an architecture
+    pub(super) fn get_architecture_blocks(
+        &self,
+        library_name: &Symbol,
+        pos: &SrcPos,
+        entity_name: &Symbol,
+        architecture_name: &Symbol,
+    ) -> FatalResult<Vec<ConfigurableBlock<'a>>> {
+        let mut blocks = Vec::new();
+        if let Some(unit) = self.get_secondary_unit(library_name, entity_name, architecture_name) {
+            let data = self.get_analysis(Some(pos), unit)?;
+            if let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(arch)) = data.deref() {
+                self.add_configurable_blocks(&arch.statements, &mut blocks);
+            }
+        }
+        Ok(blocks)
+    }
+
+    fn add_configurable_blocks(
+        &self,
+        statements: &[LabeledConcurrentStatement],
+        blocks: &mut Vec<ConfigurableBlock<'a>>,
+    ) {
+        for statement in statements.iter() {
+            let bodies: Vec<(Option<&[Declaration]>, &[LabeledConcurrentStatement])> =
+                match statement.statement.item {
+                    ConcurrentStatement::Block(ref block) => {
+                        vec![(Some(&block.decl), &block.statements)]
+                    }
+                    ConcurrentStatement::ForGenerate(ref gen) => {
+                        vec![(gen.body.decl.as_deref(), &gen.body.statements)]
+                    }
+                    ConcurrentStatement::IfGenerate(ref gen) => gen
+                        .conds
+                        .conditionals
+                        .iter()
+                        .map(|cond| &cond.item)
+                        .chain(gen.conds.else_item.iter())
+                        .map(|body| (body.decl.as_deref(), body.statements.as_slice()))
+                        .collect(),
+                    ConcurrentStatement::CaseGenerate(ref gen) => gen
+                        .sels
+                        .alternatives
+                        .iter()
+                        .map(|alt| (alt.item.decl.as_deref(), alt.item.statements.as_slice()))
+                        .collect(),
+                    _ => continue,
+                };
+
+            let Some(label) = statement.label.decl else {
+                continue;
+            };
+
+            let mut block = ConfigurableBlock {
+                label: self.arena.get(label),
+                components: Vec::new(),
+                blocks: Vec::new(),
+            };
+
+            for (decl, statements) in bodies {
+                for decl in decl.unwrap_or_default() {
+                    if let Declaration::Component(component) = decl {
+                        if let Some(id) = component.ident.decl {
+                            block.components.push(self.arena.get(id));
+                        }
+                    }
+                }
+                self.add_configurable_blocks(statements, &mut block.blocks);
+            }
+
+            blocks.push(block);
+        }
+    }
+
     pub fn lookup_in_library(
         &self,
         library_name: &Symbol,
@@ -24,9 +24,10 @@ impl<'a> AnalyzeContext<'a> {
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         let ttyp = as_fatal(self.resolve_target(scope, target, assignment_type, diagnostics))?;
+        let tlen = self.static_target_length(target);
         match rhs {
             AssignmentRightHand::Simple(expr) => {
-                self.analyze_expression_for_target(scope, ttyp, expr, diagnostics)?;
+                self.analyze_expression_for_target(scope, ttyp, tlen, expr, diagnostics)?;
             }
             AssignmentRightHand::Conditional(conditionals) => {
                 let Conditionals {
@@ -35,11 +36,11 @@ impl<'a> AnalyzeContext<'a> {
                 } = conditionals;
                 for conditional in conditionals {
                     let Conditional { condition, item } = conditional;
-                    self.analyze_expression_for_target(scope, ttyp, item, diagnostics)?;
+                    self.analyze_expression_for_target(scope, ttyp, tlen, item, diagnostics)?;
                     self.boolean_expr(scope, condition, diagnostics)?;
                 }
                 if let Some(expr) = else_item {
-                    self.analyze_expression_for_target(scope, ttyp, expr, diagnostics)?;
+                    self.analyze_expression_for_target(scope, ttyp, tlen, expr, diagnostics)?;
                 }
             }
             AssignmentRightHand::Selected(selection) => {
@@ -49,7 +50,7 @@ impl<'a> AnalyzeContext<'a> {
                 } = selection;
                 let ctyp = as_fatal(self.expr_unambiguous_type(scope, expression, diagnostics))?;
                 for Alternative { choices, item } in alternatives.iter_mut() {
-                    self.analyze_expression_for_target(scope, ttyp, item, diagnostics)?;
+                    self.analyze_expression_for_target(scope, ttyp, tlen, item, diagnostics)?;
                     self.choice_with_ttyp(scope, ctyp, choices, diagnostics)?;
                 }
             }
@@ -66,22 +67,26 @@ impl<'a> AnalyzeContext<'a> {
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         let ttyp = as_fatal(self.resolve_target(scope, target, assignment_type, diagnostics))?;
+        let tlen = self.static_target_length(target);
         match rhs {
             AssignmentRightHand::Simple(wavf) => {
-                self.analyze_waveform(scope, ttyp, wavf, diagnostics)?;
+                self.analyze_waveform(scope, ttyp, tlen, wavf, diagnostics)?;
             }
             AssignmentRightHand::Conditional(conditionals) => {
                 let Conditionals {
                     conditionals,
                     else_item,
                 } = conditionals;
-                for conditional in conditionals {
+                for conditional in conditionals.iter_mut() {
                     let Conditional { condition, item } = conditional;
-                    self.analyze_waveform(scope, ttyp, item, diagnostics)?;
+                    self.analyze_waveform(scope, ttyp, tlen, item, diagnostics)?;
                     self.boolean_expr(scope, condition, diagnostics)?;
                 }
                 if let Some(wavf) = else_item {
-                    self.analyze_waveform(scope, ttyp, wavf, diagnostics)?;
+                    self.analyze_waveform(scope, ttyp, tlen, wavf, diagnostics)?;
+                }
+                if self.root.unreachable_warnings {
+                    self.check_unreachable_conditionals(conditionals, else_item, diagnostics);
                 }
             }
             AssignmentRightHand::Selected(selection) => {
@@ -91,29 +96,112 @@ impl<'a> AnalyzeContext<'a> {
                 } = selection;
                 let ctyp = as_fatal(self.expr_unambiguous_type(scope, expression, diagnostics))?;
                 for Alternative { choices, item } in alternatives.iter_mut() {
-                    self.analyze_waveform(scope, ttyp, item, diagnostics)?;
+                    self.analyze_waveform(scope, ttyp, tlen, item, diagnostics)?;
                     self.choice_with_ttyp(scope, ctyp, choices, diagnostics)?;
                 }
+                if self.root.unreachable_warnings {
+                    if let Some(ctyp) = ctyp {
+                        self.check_unreachable_others(expression, ctyp, alternatives, diagnostics);
+                    }
+                }
             }
         }
         Ok(())
     }
 
+    /// Warn on the alternatives after a condition that is statically true
+    fn check_unreachable_conditionals(
+        &self,
+        conditionals: &[Conditional<Waveform>],
+        else_item: &Option<Waveform>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let boolean = self.boolean().base();
+        let Some(idx) = conditionals.iter().position(|conditional| {
+            self.static_discrete_value(boolean, &conditional.condition.item) == Some(1)
+        }) else {
+            return;
+        };
+
+        for conditional in conditionals[idx + 1..].iter() {
+            diagnostics.warning(
+                &conditional.condition.pos,
+                "Unreachable alternative, a previous condition is always true",
+            );
+        }
+
+        if let Some(Waveform::Elements(elems)) = else_item {
+            if let Some(elem) = elems.first() {
+                diagnostics.warning(
+                    &elem.value.pos,
+                    "Unreachable alternative, a previous condition is always true",
+                );
+            }
+        }
+    }
+
+    /// Warn on an others choice when the other choices already cover all values
+    fn check_unreachable_others(
+        &self,
+        selector: &WithPos<Expression>,
+        ctyp: TypeEnt<'a>,
+        alternatives: &[Alternative<Waveform>],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let Some(others) = alternatives
+            .iter()
+            .flat_map(|alt| alt.choices.iter())
+            .find(|choice| matches!(choice.item, Choice::Others))
+        else {
+            return;
+        };
+
+        let Some(range) = self.selector_range(selector, ctyp) else {
+            return;
+        };
+
+        if self.choices_cover_range(
+            ctyp.base(),
+            alternatives.iter().flat_map(|alt| alt.choices.iter()),
+            range,
+        ) {
+            diagnostics.warning(
+                &others.pos,
+                "Others can never be selected, all values are already covered",
+            );
+        }
+    }
+
     fn analyze_waveform(
         &self,
         scope: &Scope<'a>,
         ttyp: Option<TypeEnt<'a>>,
+        tlen: Option<i64>,
         wavf: &mut Waveform,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         match wavf {
             Waveform::Elements(ref mut elems) => {
+                let mut prev_time = None;
                 for elem in elems.iter_mut() {
                     let WaveformElement { value, after } = elem;
-                    self.analyze_expression_for_target(scope, ttyp, value, diagnostics)?;
-                    if let Some(expr) = after {
+                    self.analyze_expression_for_target(scope, ttyp, tlen, value, diagnostics)?;
+
+                    // An element without after clause is scheduled at 0 ns
+                    let (time, pos) = if let Some(expr) = after {
                         self.expr_with_ttyp(scope, self.time(), expr, diagnostics)?;
+                        (self.static_time_value(&expr.item), &expr.pos)
+                    } else {
+                        (Some(0), &value.pos)
+                    };
+
+                    if let (Some(prev_time), Some(time)) = (prev_time, time) {
+                        if time <= prev_time {
+                            diagnostics
+                                .warning(pos, "Waveform element times must be in ascending order");
+                        }
                     }
+                    prev_time = time;
                 }
             }
             Waveform::Unaffected => {}
@@ -125,6 +213,7 @@ impl<'a> AnalyzeContext<'a> {
         &self,
         scope: &Scope<'a>,
         ttyp: Option<TypeEnt<'a>>,
+        tlen: Option<i64>,
         expr: &mut WithPos<Expression>,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
@@ -133,6 +222,72 @@ impl<'a> AnalyzeContext<'a> {
         } else {
             self.expr_unknown_ttyp(scope, expr, diagnostics)?;
         }
+
+        if let (Some(tlen), Some(len)) = (tlen, self.static_length(&expr.item)) {
+            if tlen != len {
+                diagnostics.error(
+                    &expr.pos,
+                    format!("Length mismatch, target has length {tlen} but value has length {len}"),
+                );
+            }
+        }
         Ok(())
     }
+
+    fn static_target_length(&self, target: &WithPos<Target>) -> Option<i64> {
+        match target.item {
+            Target::Name(ref name) => self.static_name_length(name),
+            Target::Aggregate(..) => None,
+        }
+    }
+
+    /// The statically known length of a one dimensional array expression
+    pub(super) fn static_length(&self, expr: &Expression) -> Option<i64> {
+        match expr {
+            Expression::Name(name) => self.static_name_length(name),
+            Expression::Literal(Literal::String(string)) => Some(string.len() as i64),
+            _ => None,
+        }
+    }
+
+    fn static_name_length(&self, name: &Name) -> Option<i64> {
+        Some(self.static_name_range(name)?.length())
+    }
+
+    /// The statically known index range of a one dimensional array name
+    pub(super) fn static_name_range(&self, name: &Name) -> Option<StaticRange> {
+        match name {
+            Name::Designator(..) | Name::Selected(..) => {
+                let subtype = self.object_subtype(name)?;
+                one_dimensional_index(subtype)?;
+                subtype.static_range()
+            }
+            Name::Slice(prefix, drange) => {
+                let index_type = one_dimensional_index(self.object_subtype(&prefix.item)?)?;
+                self.static_drange(index_type, drange)
+            }
+            _ => None,
+        }
+    }
+
+    /// The statically known length of a one dimensional array subtype
+    pub(super) fn static_subtype_length(&self, subtype: Subtype<'a>) -> Option<i64> {
+        one_dimensional_index(subtype)?;
+        Some(subtype.static_range()?.length())
+    }
+
+    fn object_subtype(&self, name: &Name) -> Option<Subtype<'a>> {
+        let ent = self.arena.get(name.get_suffix_reference()?);
+        match ent.actual_kind() {
+            AnyEntKind::Object(obj) => Some(obj.subtype),
+            _ => None,
+        }
+    }
+}
+
+fn one_dimensional_index(subtype: Subtype) -> Option<BaseType> {
+    match subtype.base().kind() {
+        Type::Array { indexes, .. } if indexes.len() == 1 => indexes[0],
+        _ => None,
+    }
 }
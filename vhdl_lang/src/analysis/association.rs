@@ -58,6 +58,13 @@ impl<'a> ResolvedFormal<'a> {
         }
     }
 
+    fn is_output(&self) -> bool {
+        let (ResolvedFormal::Basic(_, ent)
+        | ResolvedFormal::Selected(_, ent, _)
+        | ResolvedFormal::Converted(_, ent, _)) = self;
+        matches!(ent.kind(), AnyEntKind::Object(obj) if obj.mode() == Some(Mode::Out))
+    }
+
     // The position of the formal in the formal region
     fn idx(&self) -> usize {
         *match self {
@@ -257,8 +264,20 @@ impl<'a> AnalyzeContext<'a> {
         let mut missing = false;
         let mut associated_indexes: FnvHashSet<usize> = Default::default();
         let mut extra_associations: Vec<SrcPos> = Default::default();
+        let mut others_pos: Option<SrcPos> = None;
+
+        for (idx, elem) in elems.iter_mut().enumerate() {
+            if elem.is_others() {
+                // others => open leaves all remaining formals unassociated
+                if !matches!(elem.actual.item, ActualPart::Open) {
+                    missing = true;
+                    diagnostics.error(&elem.actual.pos, "Only open may be associated with others");
+                }
+                others_pos = elem.formal.as_ref().map(|formal| formal.pos.clone());
+                continue;
+            }
 
-        for (idx, AssociationElement { formal, actual }) in elems.iter_mut().enumerate() {
+            let AssociationElement { formal, actual } = elem;
             if let Some(ref mut formal) = formal {
                 // Call by name using formal
                 match self.resolve_formal(
@@ -287,12 +306,7 @@ impl<'a> AnalyzeContext<'a> {
 
         let mut not_associated = Vec::new();
         for (idx, formal) in formal_region.iter().enumerate() {
-            if !(associated_indexes.contains(&idx)
-                // Default may be unconnected
-                || formal.has_default()
-                // Output ports are allowed to be unconnected
-                || (formal_region.typ == InterfaceType::Port && formal.is_out_or_inout_signal()))
-            {
+            if !(associated_indexes.contains(&idx) || may_be_unconnected(formal_region, &formal)) {
                 not_associated.push(idx);
             }
         }
@@ -303,10 +317,17 @@ impl<'a> AnalyzeContext<'a> {
             // Only complain if nothing else is wrong
             for idx in not_associated {
                 if let Some(formal) = formal_region.nth(idx) {
-                    let mut diagnostic = Diagnostic::error(
-                        error_pos,
-                        format!("No association of {}", formal.describe()),
-                    );
+                    let mut diagnostic = if let Some(ref others_pos) = others_pos {
+                        Diagnostic::error(
+                            others_pos,
+                            format!("{} cannot be left open by others", formal.describe()),
+                        )
+                    } else {
+                        Diagnostic::error(
+                            error_pos,
+                            format!("No association of {}", formal.describe()),
+                        )
+                    };
 
                     if let Some(decl_pos) = formal.decl_pos() {
                         diagnostic.add_related(decl_pos, "Defined here");
@@ -337,11 +358,24 @@ impl<'a> AnalyzeContext<'a> {
             elems,
             diagnostics,
         ))? {
-            for (formal, actual) in formals
-                .iter()
-                .zip(elems.iter_mut().map(|assoc| &mut assoc.actual))
-            {
+            for (formal, actual) in formals.iter().zip(
+                elems
+                    .iter_mut()
+                    .filter(|assoc| !assoc.is_others())
+                    .map(|assoc| &mut assoc.actual),
+            ) {
                 match &mut actual.item {
+                    // The actual of an output is written rather than read
+                    ActualPart::Expression(Expression::Name(name)) if formal.is_output() => {
+                        self.name_with_ttyp(
+                            scope,
+                            &actual.pos,
+                            name,
+                            formal.type_mark(),
+                            false,
+                            diagnostics,
+                        )?;
+                    }
                     ActualPart::Expression(expr) => {
                         self.expr_pos_with_ttyp(
                             scope,
@@ -351,12 +385,72 @@ impl<'a> AnalyzeContext<'a> {
                             diagnostics,
                         )?;
                     }
-                    ActualPart::Open => {}
+                    ActualPart::Open => {
+                        if let ResolvedFormal::Basic(_, ent) = formal {
+                            if matches!(ent.kind(), AnyEntKind::Object(_))
+                                && !may_be_unconnected(formal_region, ent)
+                            {
+                                let mut diagnostic = Diagnostic::error(
+                                    &actual.pos,
+                                    format!(
+                                        "{} without a default value cannot be left open",
+                                        ent.describe()
+                                    ),
+                                );
+                                if let Some(decl_pos) = ent.decl_pos() {
+                                    diagnostic.add_related(decl_pos, "Defined here");
+                                }
+                                diagnostics.push(diagnostic);
+                            }
+                        }
+                    }
+                }
+
+                if let ActualPart::Expression(ref expr) = actual.item {
+                    self.check_actual_length(formal, &actual.pos, expr, diagnostics);
                 }
             }
         }
         Ok(())
     }
+
+    /// An unconstrained array formal takes the index range of the actual
+    /// while the actual of a constrained array formal must have the same length
+    fn check_actual_length(
+        &self,
+        formal: &ResolvedFormal<'a>,
+        pos: &SrcPos,
+        expr: &Expression,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let ResolvedFormal::Basic(_, ent) = formal else {
+            return;
+        };
+        let AnyEntKind::Object(obj) = ent.kind() else {
+            return;
+        };
+
+        if let (Some(flen), Some(len)) = (
+            self.static_subtype_length(obj.subtype),
+            self.static_length(expr),
+        ) {
+            if flen != len {
+                diagnostics.error(
+                    pos,
+                    format!(
+                        "Length mismatch, formal has length {flen} but actual has length {len}"
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn may_be_unconnected(formal_region: &FormalRegion, formal: &InterfaceEnt) -> bool {
+    // Default may be unconnected
+    formal.has_default()
+        // Output ports are allowed to be unconnected
+        || (formal_region.typ == InterfaceType::Port && formal.is_out_or_inout_signal())
 }
 
 fn to_formal_conversion_argument(
@@ -0,0 +1,375 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Detection of combinational loops among concurrent signal assignments and processes
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::sensitivity::for_each_statement;
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::data::*;
+use fnv::FnvHashMap;
+
+/// An assigned signal depends on the signals that are read by the assignment
+struct Dependency {
+    target: EntityId,
+    source: EntityId,
+    pos: SrcPos,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    OnStack,
+    Done,
+}
+
+impl<'a> AnalyzeContext<'a> {
+    /// Warn on cycles between signals that are assigned in concurrent signal assignments
+    /// or combinational processes
+    /// Signals are treated as a whole and assignments that depend on a clock edge
+    /// as well as processes with a clock edge or a wait statement break the loop
+    pub fn check_combinational_loops(
+        &self,
+        statements: &mut [LabeledConcurrentStatement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut dependencies = Vec::new();
+        self.concurrent_dependencies(statements, false, &mut dependencies);
+
+        // Signals are visited in order of assignment to get a deterministic result
+        let mut order: Vec<EntityId> = Vec::new();
+        let mut edges: FnvHashMap<EntityId, Vec<&Dependency>> = FnvHashMap::default();
+        for dependency in dependencies.iter() {
+            if !edges.contains_key(&dependency.target) {
+                order.push(dependency.target);
+            }
+            edges.entry(dependency.target).or_default().push(dependency);
+        }
+
+        let mut visited = FnvHashMap::default();
+        let mut stack = Vec::new();
+        let mut loops = Vec::new();
+        for signal in order {
+            if !visited.contains_key(&signal) {
+                find_loops(signal, &edges, &mut visited, &mut stack, &mut loops);
+            }
+        }
+
+        for signals in loops {
+            let names: Vec<_> = signals
+                .iter()
+                .map(|dependency| format!("'{}'", self.arena.get(dependency.target).designator()))
+                .collect();
+
+            let mut diagnostic = Diagnostic::warning(
+                &signals[0].pos,
+                format!("Combinational loop through {}", names.join(", ")),
+            );
+            for dependency in signals.iter().skip(1) {
+                diagnostic.add_related(&dependency.pos, "Part of the combinational loop");
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    fn concurrent_dependencies(
+        &self,
+        statements: &mut [LabeledConcurrentStatement],
+        guard_is_clocked: bool,
+        dependencies: &mut Vec<Dependency>,
+    ) {
+        for statement in statements.iter_mut() {
+            match statement.statement.item {
+                ConcurrentStatement::Assignment(ref mut assign) => {
+                    if assign.guarded && guard_is_clocked {
+                        continue;
+                    }
+                    self.assignment_dependencies(&mut assign.target, &mut assign.rhs, dependencies);
+                }
+                ConcurrentStatement::Block(ref mut block) => {
+                    let guard_is_clocked = block
+                        .guard_condition
+                        .as_ref()
+                        .is_some_and(|cond| self.is_clock_edge(&cond.item));
+                    self.concurrent_dependencies(
+                        &mut block.statements,
+                        guard_is_clocked,
+                        dependencies,
+                    );
+                }
+                ConcurrentStatement::ForGenerate(ref mut gen) => {
+                    self.concurrent_dependencies(&mut gen.body.statements, false, dependencies);
+                }
+                ConcurrentStatement::IfGenerate(ref mut gen) => {
+                    for cond in gen.conds.conditionals.iter_mut() {
+                        self.concurrent_dependencies(
+                            &mut cond.item.statements,
+                            false,
+                            dependencies,
+                        );
+                    }
+                    if let Some(ref mut body) = gen.conds.else_item {
+                        self.concurrent_dependencies(&mut body.statements, false, dependencies);
+                    }
+                }
+                ConcurrentStatement::CaseGenerate(ref mut gen) => {
+                    for alternative in gen.sels.alternatives.iter_mut() {
+                        self.concurrent_dependencies(
+                            &mut alternative.item.statements,
+                            false,
+                            dependencies,
+                        );
+                    }
+                }
+                ConcurrentStatement::Process(ref mut process) => {
+                    self.process_dependencies(process, dependencies);
+                }
+                ConcurrentStatement::ProcedureCall(..)
+                | ConcurrentStatement::Assert(..)
+                | ConcurrentStatement::Instance(..) => {}
+            }
+        }
+    }
+
+    /// The signals assigned by a process without a clock edge or wait statement
+    /// depend on the signals that it is sensitive to
+    fn process_dependencies(
+        &self,
+        process: &mut ProcessStatement,
+        dependencies: &mut Vec<Dependency>,
+    ) {
+        let mut is_combinational = true;
+        let mut targets = Vec::new();
+        for_each_statement(
+            &mut process.statements,
+            &mut |statement| match statement.statement.item {
+                SequentialStatement::Wait(..) => is_combinational = false,
+                SequentialStatement::If(ref ifstmt) => {
+                    is_combinational &= !ifstmt
+                        .conds
+                        .conditionals
+                        .iter()
+                        .any(|cond| self.is_clock_edge(&cond.condition.item));
+                }
+                SequentialStatement::SignalAssignment(ref mut assign) => {
+                    if let AssignmentRightHand::Conditional(ref conditionals) = assign.rhs {
+                        if conditionals
+                            .conditionals
+                            .iter()
+                            .any(|cond| self.is_clock_edge(&cond.condition.item))
+                        {
+                            is_combinational = false;
+                        }
+                    }
+                    let target_ids = self.assigned_signals(&mut assign.target);
+                    targets.push((target_ids, assign.target.pos.clone()));
+                }
+                SequentialStatement::SignalForceAssignment(ref mut assign) => {
+                    let target_ids = self.assigned_signals(&mut assign.target);
+                    targets.push((target_ids, assign.target.pos.clone()));
+                }
+                _ => {}
+            },
+        );
+
+        if !is_combinational {
+            return;
+        }
+
+        let sources = match process.sensitivity_list {
+            Some(SensitivityList::Names(ref mut names)) => {
+                let mut sources = FindSignals::new(self);
+                for name in names.iter_mut() {
+                    let _ = name.search(&mut sources);
+                }
+                sources.signals
+            }
+            Some(SensitivityList::All) => self
+                .implicit_sensitivity_set(&mut process.statements)
+                .into_iter()
+                .map(|ent| ent.id())
+                .collect(),
+            // Without a sensitivity list or wait statement the process never suspends
+            None => return,
+        };
+
+        for (target_ids, pos) in targets {
+            for target_id in target_ids {
+                for source in sources.iter() {
+                    dependencies.push(Dependency {
+                        target: target_id,
+                        source: *source,
+                        pos: pos.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn assignment_dependencies(
+        &self,
+        target: &mut WithPos<Target>,
+        rhs: &mut AssignmentRightHand<Waveform>,
+        dependencies: &mut Vec<Dependency>,
+    ) {
+        let mut sources = FindSignals::new(self);
+        match rhs {
+            AssignmentRightHand::Simple(waveform) => {
+                let _ = waveform.search(&mut sources);
+            }
+            AssignmentRightHand::Conditional(conditionals) => {
+                for cond in conditionals.conditionals.iter_mut() {
+                    // The assignment infers a register
+                    if self.is_clock_edge(&cond.condition.item) {
+                        return;
+                    }
+                    let _ = cond.condition.search(&mut sources);
+                    let _ = cond.item.search(&mut sources);
+                }
+                if let Some(ref mut waveform) = conditionals.else_item {
+                    let _ = waveform.search(&mut sources);
+                }
+            }
+            AssignmentRightHand::Selected(selection) => {
+                let _ = selection.expression.search(&mut sources);
+                for alternative in selection.alternatives.iter_mut() {
+                    let _ = alternative.item.search(&mut sources);
+                }
+            }
+        }
+
+        for target_id in self.assigned_signals(target) {
+            for source in sources.signals.iter() {
+                dependencies.push(Dependency {
+                    target: target_id,
+                    source: *source,
+                    pos: target.pos.clone(),
+                });
+            }
+        }
+    }
+
+    /// The signals that are assigned by a target
+    fn assigned_signals(&self, target: &mut WithPos<Target>) -> Vec<EntityId> {
+        match target.item {
+            Target::Name(ref name) => self.assigned_signal(name).into_iter().collect(),
+            Target::Aggregate(..) => {
+                let mut targets = FindSignals::new(self);
+                let _ = target.search(&mut targets);
+                targets.signals
+            }
+        }
+    }
+
+    /// The signal that is assigned by a target name such as rec.field(0)
+    fn assigned_signal(&self, name: &Name) -> Option<EntityId> {
+        match name {
+            Name::Designator(designator) => designator
+                .reference
+                .filter(|id| is_signal(self.arena.get(*id))),
+            Name::Selected(prefix, suffix) => suffix
+                .item
+                .reference
+                .filter(|id| is_signal(self.arena.get(*id)))
+                .or_else(|| self.assigned_signal(&prefix.item)),
+            Name::Slice(prefix, _) => self.assigned_signal(&prefix.item),
+            Name::CallOrIndexed(call) => self.assigned_signal(&call.name.item),
+            Name::SelectedAll(..) | Name::Attribute(..) | Name::External(..) => None,
+        }
+    }
+
+    /// A condition containing 'event, rising_edge or falling_edge
+    fn is_clock_edge(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Binary(_, left, right) => {
+                self.is_clock_edge(&left.item) || self.is_clock_edge(&right.item)
+            }
+            Expression::Unary(_, expr) => self.is_clock_edge(&expr.item),
+            Expression::Name(name) => match name.as_ref() {
+                Name::Attribute(attr) => {
+                    attr.attr.item == AttributeDesignator::Signal(SignalAttribute::Event)
+                }
+                Name::CallOrIndexed(call) => {
+                    if let Name::Designator(ref designator) = call.name.item {
+                        [
+                            self.root.symbol_utf8("rising_edge"),
+                            self.root.symbol_utf8("falling_edge"),
+                        ]
+                        .iter()
+                        .any(|sym| designator.item == Designator::Identifier(sym.clone()))
+                    } else {
+                        false
+                    }
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+pub(super) fn is_signal(ent: EntRef) -> bool {
+    matches!(ent.kind(), AnyEntKind::Object(obj) if obj.class == ObjectClass::Signal)
+}
+
+fn find_loops<'d>(
+    signal: EntityId,
+    edges: &FnvHashMap<EntityId, Vec<&'d Dependency>>,
+    visited: &mut FnvHashMap<EntityId, Visit>,
+    stack: &mut Vec<&'d Dependency>,
+    loops: &mut Vec<Vec<&'d Dependency>>,
+) {
+    visited.insert(signal, Visit::OnStack);
+
+    for dependency in edges.get(&signal).into_iter().flatten() {
+        match visited.get(&dependency.source) {
+            None => {
+                stack.push(dependency);
+                find_loops(dependency.source, edges, visited, stack, loops);
+                stack.pop();
+            }
+            Some(Visit::OnStack) => {
+                let start = stack
+                    .iter()
+                    .position(|other| other.target == dependency.source)
+                    .unwrap_or(stack.len());
+                let mut signals = stack[start..].to_vec();
+                signals.push(dependency);
+                loops.push(signals);
+            }
+            Some(Visit::Done) => {}
+        }
+    }
+
+    visited.insert(signal, Visit::Done);
+}
+
+/// Collect the signals that are referenced by a part of a statement
+struct FindSignals<'s, 'a> {
+    ctx: &'s AnalyzeContext<'a>,
+    signals: Vec<EntityId>,
+}
+
+impl<'s, 'a> FindSignals<'s, 'a> {
+    fn new(ctx: &'s AnalyzeContext<'a>) -> Self {
+        FindSignals {
+            ctx,
+            signals: Vec::new(),
+        }
+    }
+}
+
+impl<'s, 'a> Searcher for FindSignals<'s, 'a> {
+    fn search_pos_with_ref(&mut self, _pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        if let Some(id) = *reference {
+            if is_signal(self.ctx.arena.get(id)) && !self.signals.contains(&id) {
+                self.signals.push(id);
+            }
+        }
+        SearchState::NotFinished
+    }
+}
@@ -23,6 +23,7 @@ impl<'a> AnalyzeContext<'a> {
         statements: &mut [LabeledConcurrentStatement],
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
+        let _span = enter_span!(DEBUG, "concurrent_part", parent = %parent.designator());
         for statement in statements.iter_mut() {
             let parent = if let Some(id) = statement.label.decl {
                 self.arena.get(id)
@@ -44,11 +45,20 @@ impl<'a> AnalyzeContext<'a> {
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         for statement in statements.iter_mut() {
+            let label_typ =
+                if let ConcurrentStatement::Instance(ref mut instance) = statement.statement.item {
+                    Some(Concurrent::Instance(
+                        self.instantiated_unit(scope, instance)?,
+                    ))
+                } else {
+                    statement.statement.item.label_typ()
+                };
+
             if let Some(ref mut label) = statement.label.tree {
                 let ent = self.arena.explicit(
                     label.name(),
                     parent,
-                    AnyEntKind::Concurrent(statement.statement.item.label_typ()),
+                    AnyEntKind::Concurrent(label_typ),
                     Some(label.pos()),
                 );
                 statement.label.decl = Some(ent.id());
@@ -59,7 +69,7 @@ impl<'a> AnalyzeContext<'a> {
                     Designator::Anonymous(scope.next_anonymous()),
                     Some(parent),
                     Related::None,
-                    AnyEntKind::Concurrent(statement.statement.item.label_typ()),
+                    AnyEntKind::Concurrent(label_typ),
                     None,
                 );
                 statement.label.decl = Some(ent.id());
@@ -69,6 +79,36 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// The entity or component of an instance such that external names can refer to its ports
+    /// Errors are reported later when the instance is analyzed
+    fn instantiated_unit(
+        &self,
+        scope: &Scope<'a>,
+        instance: &mut InstantiationStatement,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        let name = match instance.unit {
+            InstantiatedUnit::Entity(ref mut name, _)
+            | InstantiatedUnit::Component(ref mut name) => name,
+            InstantiatedUnit::Configuration(..) => return Ok(None),
+        };
+
+        match self.resolve_selected_name(scope, name) {
+            Ok(NamedEntities::Single(ent))
+                if matches!(
+                    ent.kind(),
+                    AnyEntKind::Design(Design::Entity(..)) | AnyEntKind::Component(_)
+                ) =>
+            {
+                Ok(Some(ent))
+            }
+            Ok(_) => Ok(None),
+            Err(err) => {
+                err.add_to(&mut NullDiagnostics)?;
+                Ok(None)
+            }
+        }
+    }
+
     fn analyze_concurrent_statement(
         &self,
         scope: &Scope<'a>,
@@ -78,10 +118,26 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match statement.statement.item {
             ConcurrentStatement::Block(ref mut block) => {
+                let nested = scope.nested();
                 if let Some(ref mut guard_condition) = block.guard_condition {
                     self.boolean_expr(scope, guard_condition, diagnostics)?;
+
+                    // A guarded block implicitly declares a GUARD signal
+                    let guard = self.arena.alloc(
+                        Designator::Identifier(self.root.symbol_utf8("guard")),
+                        Some(parent),
+                        Related::ImplicitOf(parent),
+                        AnyEntKind::Object(Object {
+                            class: ObjectClass::Signal,
+                            iface: None,
+                            subtype: Subtype::new(self.boolean()),
+                            has_default: true,
+                            signal_kind: None,
+                        }),
+                        Some(guard_condition.pos.clone()),
+                    );
+                    nested.add(guard, diagnostics);
                 }
-                let nested = scope.nested();
                 if let Some(ref mut list) = block.header.generic_clause {
                     self.analyze_interface_list(&nested, parent, list, diagnostics)?;
                 }
@@ -96,12 +152,13 @@ impl<'a> AnalyzeContext<'a> {
                 }
 
                 self.define_labels_for_concurrent_part(
-                    scope,
+                    &nested,
                     parent,
                     &mut block.statements,
                     diagnostics,
                 )?;
                 self.analyze_declarative_part(&nested, parent, &mut block.decl, diagnostics)?;
+                self.record_scope(&statement.statement.pos, &nested);
                 self.analyze_concurrent_part(&nested, parent, &mut block.statements, diagnostics)?;
             }
             ConcurrentStatement::Process(ref mut process) => {
@@ -123,7 +180,16 @@ impl<'a> AnalyzeContext<'a> {
                 let nested = scope.nested();
                 self.define_labels_for_sequential_part(scope, parent, statements, diagnostics)?;
                 self.analyze_declarative_part(&nested, parent, decl, diagnostics)?;
+                self.record_scope(&statement.statement.pos, &nested);
                 self.analyze_sequential_part(&nested, parent, statements, diagnostics)?;
+
+                if sensitivity_list.is_some() {
+                    self.check_no_wait_statement(statements, diagnostics);
+                }
+
+                if self.root.read_before_write_warnings {
+                    self.check_read_before_write(decl, statements, diagnostics);
+                }
             }
             ConcurrentStatement::ForGenerate(ref mut gen) => {
                 let ForGenerateStatement {
@@ -132,7 +198,8 @@ impl<'a> AnalyzeContext<'a> {
                     body,
                     end_label_pos: _,
                 } = gen;
-                let typ = as_fatal(self.drange_type(scope, discrete_range, diagnostics))?;
+                let typ =
+                    as_fatal(self.drange_loop_parameter_type(scope, discrete_range, diagnostics))?;
                 let nested = scope.nested();
                 nested.add(
                     index_name.define(self.arena, parent, AnyEntKind::LoopParameter(typ)),
@@ -157,7 +224,13 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             ConcurrentStatement::CaseGenerate(ref mut gen) => {
-                for alternative in gen.sels.alternatives.iter_mut() {
+                let Selection {
+                    expression,
+                    alternatives,
+                } = &mut gen.sels;
+                let ctyp = as_fatal(self.expr_unambiguous_type(scope, expression, diagnostics))?;
+                for alternative in alternatives.iter_mut() {
+                    self.choice_with_ttyp(scope, ctyp, &mut alternative.choices, diagnostics)?;
                     let nested = scope.nested();
                     self.analyze_generate_body(
                         &nested,
@@ -172,7 +245,49 @@ impl<'a> AnalyzeContext<'a> {
             }
             ConcurrentStatement::Assignment(ref mut assign) => {
                 // @TODO more delaymechanism
-                let ConcurrentSignalAssignment { target, rhs, .. } = assign;
+                let ConcurrentSignalAssignment {
+                    target,
+                    rhs,
+                    guarded,
+                    ..
+                } = assign;
+
+                if *guarded {
+                    let guard = Designator::Identifier(self.root.symbol_utf8("guard"));
+                    match scope.lookup(&target.pos, &guard) {
+                        Ok(named) => {
+                            // An explicitly declared GUARD must be a boolean signal
+                            let is_guard_signal = named
+                                .as_non_overloaded()
+                                .and_then(ObjectEnt::from_any)
+                                .map(|obj| {
+                                    obj.class() == ObjectClass::Signal
+                                        && obj.type_mark().base() == self.boolean().base()
+                                })
+                                .unwrap_or(false);
+                            if !is_guard_signal {
+                                let mut diagnostic = Diagnostic::error(
+                                    &target.pos,
+                                    format!(
+                                        "Guarded signal assignment requires a GUARD signal of type '{}', got {}",
+                                        self.boolean().designator(),
+                                        named.first().describe()
+                                    ),
+                                );
+                                if let Some(decl_pos) = named.first().decl_pos() {
+                                    diagnostic.add_related(decl_pos, "Defined here");
+                                }
+                                diagnostics.push(diagnostic);
+                            }
+                        }
+                        Err(_) => {
+                            diagnostics.error(
+                                &target.pos,
+                                "Guarded signal assignment requires a GUARD signal",
+                            );
+                        }
+                    }
+                }
                 self.analyze_waveform_assignment(
                     scope,
                     target,
@@ -188,20 +303,9 @@ impl<'a> AnalyzeContext<'a> {
             ConcurrentStatement::Assert(ref mut assert) => {
                 let ConcurrentAssertStatement {
                     postponed: _postponed,
-                    statement:
-                        AssertStatement {
-                            condition,
-                            report,
-                            severity,
-                        },
+                    statement,
                 } = assert;
-                self.boolean_expr(scope, condition, diagnostics)?;
-                if let Some(expr) = report {
-                    self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
-                }
-                if let Some(expr) = severity {
-                    self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
-                }
+                self.analyze_assert_statement(scope, statement, diagnostics)?;
             }
         };
         Ok(())
@@ -233,10 +337,10 @@ impl<'a> AnalyzeContext<'a> {
         }
 
         // Pre-declare labels
-        self.define_labels_for_concurrent_part(scope, parent, statements, diagnostics)?;
+        self.define_labels_for_concurrent_part(scope, inner_parent, statements, diagnostics)?;
 
         if let Some(ref mut decl) = decl {
-            self.analyze_declarative_part(scope, parent, decl, diagnostics)?;
+            self.analyze_declarative_part(scope, inner_parent, decl, diagnostics)?;
         }
         self.analyze_concurrent_part(scope, inner_parent, statements, diagnostics)?;
 
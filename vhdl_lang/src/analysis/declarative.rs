@@ -8,6 +8,7 @@ use super::formal_region::FormalRegion;
 use super::formal_region::RecordRegion;
 use super::named_entity::*;
 use super::names::*;
+use super::visibility::Visibility;
 use super::*;
 use crate::ast;
 use crate::ast::*;
@@ -26,6 +27,7 @@ impl<'a> AnalyzeContext<'a> {
         declarations: &mut [Declaration],
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
+        let _span = enter_span!(DEBUG, "declarative_part", parent = %parent.designator());
         let mut incomplete_types: FnvHashMap<Symbol, (EntRef<'a>, SrcPos)> = FnvHashMap::default();
 
         for i in 0..declarations.len() {
@@ -104,6 +106,13 @@ impl<'a> AnalyzeContext<'a> {
                         }
                     }
                 },
+                Declaration::PackageDeclaration(package) => {
+                    // The body of a nested package shall be in the same declarative part
+                    let has_body = remaining.iter().any(|decl| {
+                        matches!(decl, Declaration::PackageBody(body) if body.ident.tree.item == package.ident.tree.item)
+                    });
+                    self.analyze_nested_package(scope, parent, package, has_body, diagnostics)?;
+                }
                 _ => {
                     self.analyze_declaration(scope, parent, &mut declarations[i], diagnostics)?;
                 }
@@ -112,6 +121,147 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// A package declared within a declarative part (VHDL-2008)
+    fn analyze_nested_package(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        package: &mut PackageDeclaration,
+        has_body: bool,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let ent = self.arena.define(
+            &mut package.ident,
+            parent,
+            AnyEntKind::Design(Design::Package(Visibility::default(), Region::default())),
+        );
+        scope.add(ent, diagnostics);
+
+        let nested = scope.nested().in_package_declaration();
+        if let Some(ref mut list) = package.generic_clause {
+            self.analyze_interface_list(&nested, ent, list, diagnostics)?;
+        }
+        self.analyze_declarative_part(&nested, ent, &mut package.decl, diagnostics)?;
+
+        if !has_body {
+            nested.close(diagnostics);
+        }
+
+        let region = nested.into_region();
+        let kind = if package.generic_clause.is_some() {
+            AnyEntKind::Design(Design::UninstPackage(Visibility::default(), region))
+        } else {
+            AnyEntKind::Design(Design::Package(Visibility::default(), region))
+        };
+        unsafe {
+            ent.set_kind(kind);
+        }
+        Ok(())
+    }
+
+    fn analyze_nested_package_body(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        body: &mut PackageBody,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let designator = Designator::Identifier(body.ident.tree.item.clone());
+        let primary = match scope.lookup(&body.ident.tree.pos, &designator) {
+            Ok(NamedEntities::Single(ent)) => ent,
+            Ok(NamedEntities::Overloaded(overloaded)) => overloaded.first().into(),
+            Err(err) => {
+                diagnostics.push(err);
+                return Ok(());
+            }
+        };
+
+        let region = match primary.kind() {
+            AnyEntKind::Design(Design::Package(_, ref region))
+            | AnyEntKind::Design(Design::UninstPackage(_, ref region)) => region,
+            _ => {
+                let mut diagnostic = Diagnostic::error(&body.ident.tree.pos, "Expected a package");
+                if let Some(pos) = primary.decl_pos() {
+                    diagnostic.add_related(pos, format!("Found {}", primary.describe()));
+                }
+                diagnostics.push(diagnostic);
+                return Ok(());
+            }
+        };
+
+        let ent = self.arena.alloc(
+            designator,
+            Some(parent),
+            Related::DeclaredBy(primary),
+            AnyEntKind::Design(Design::PackageBody),
+            Some(body.ident.tree.pos.clone()),
+        );
+        body.ident.decl = Some(ent.id());
+
+        let nested = Scope::extend(region, Some(scope));
+        self.analyze_declarative_part(&nested, ent, &mut body.decl, diagnostics)?;
+        nested.close(diagnostics);
+        Ok(())
+    }
+
+    /// A mode view gives each element of a record type a mode
+    fn analyze_view_declaration(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        view: &mut ModeViewDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<EntRef<'a>> {
+        let subtype = catch_analysis_err(
+            self.resolve_subtype_indication(scope, &mut view.typ, diagnostics),
+            diagnostics,
+        )?;
+        let typ = subtype.type_mark();
+
+        let Type::Record(region) = typ.base_type().kind() else {
+            diagnostics.error(
+                &view.typ.type_mark.pos,
+                format!(
+                    "A mode view must be of a record type, got {}",
+                    typ.describe()
+                ),
+            );
+            return Err(EvalError::Unknown);
+        };
+
+        let mut elements: Vec<(EntRef<'a>, Mode)> = Vec::new();
+        for element in view.elements.iter_mut() {
+            for name in element.names.iter_mut() {
+                let designator = Designator::Identifier(name.item.item.clone());
+                if let Some(elem) = region.lookup(&designator) {
+                    name.set_unique_reference(&elem);
+                    elements.push((elem.into(), element.mode.item));
+                } else {
+                    diagnostics.push(Diagnostic::no_declaration_within(
+                        &typ,
+                        &name.item.pos,
+                        &designator,
+                    ));
+                }
+            }
+        }
+
+        for elem in region.iter() {
+            if !elements.iter().any(|(ent, _)| ent.id() == elem.id()) {
+                diagnostics.error(
+                    view.ident.pos(),
+                    format!("Missing mode for {}", elem.describe()),
+                );
+            }
+        }
+
+        Ok(self.arena.define(
+            &mut view.ident,
+            parent,
+            AnyEntKind::View(ModeView { typ, elements }),
+        ))
+    }
+
     fn analyze_alias_declaration(
         &self,
         scope: &Scope<'a>,
@@ -128,32 +278,69 @@ impl<'a> AnalyzeContext<'a> {
 
         let resolved_name = self.name_resolve(scope, &name.pos, &mut name.item, diagnostics);
 
-        if let Some(ref mut subtype_indication) = subtype_indication {
+        let subtype = if let Some(ref mut subtype_indication) = subtype_indication {
             // Object alias
-            self.analyze_subtype_indication(scope, subtype_indication, diagnostics)?;
-        }
+            match self.resolve_subtype_indication(scope, subtype_indication, diagnostics) {
+                Ok(subtype) => Some(subtype),
+                Err(err) => {
+                    err.add_to(diagnostics)?;
+                    None
+                }
+            }
+        } else {
+            None
+        };
 
         let resolved_name = resolved_name?;
 
+        if let Some(ref subtype_indication) = subtype_indication {
+            if !matches!(resolved_name, ResolvedName::ObjectName(_)) {
+                diagnostics.error(
+                    &subtype_indication.type_mark.pos,
+                    format!(
+                        "Only an object alias may have a subtype indication, got {}",
+                        resolved_name.describe()
+                    ),
+                );
+            }
+        }
+
         let kind = {
             match resolved_name {
                 ResolvedName::ObjectName(oname) => {
                     if let Some(ref signature) = signature {
                         diagnostics.push(Diagnostic::should_not_have_signature("Alias", signature));
                     }
+
+                    // The subtype of the alias is given by the subtype indication if present
+                    let type_mark = if let Some(subtype) = subtype {
+                        if subtype.base() != oname.type_mark().base() {
+                            if let Some(ref subtype_indication) = subtype_indication {
+                                diagnostics.push(Diagnostic::type_mismatch(
+                                    &subtype_indication.type_mark.pos,
+                                    &oname.describe_type(),
+                                    subtype.type_mark(),
+                                ));
+                            }
+                        }
+                        subtype.type_mark()
+                    } else {
+                        oname.type_mark()
+                    };
+
                     match oname.base {
                         ObjectBase::Object(base_object) => AnyEntKind::ObjectAlias {
                             base_object,
-                            type_mark: oname.type_mark(),
+                            type_mark,
                         },
-                        ObjectBase::ObjectAlias(base_object, _) => AnyEntKind::ObjectAlias {
+                        ObjectBase::ObjectAlias(base_object, _)
+                        | ObjectBase::ViewElement(base_object, _) => AnyEntKind::ObjectAlias {
                             base_object,
-                            type_mark: oname.type_mark(),
-                        },
-                        ObjectBase::ExternalName(class) => AnyEntKind::ExternalAlias {
-                            class,
-                            type_mark: oname.type_mark(),
+                            type_mark,
                         },
+                        ObjectBase::ExternalName(class) => {
+                            AnyEntKind::ExternalAlias { class, type_mark }
+                        }
                         ObjectBase::DeferredConstant(_) => {
                             // @TODO handle
                             return Err(EvalError::Unknown);
@@ -267,6 +454,13 @@ impl<'a> AnalyzeContext<'a> {
                             &mut expr.item,
                             diagnostics,
                         )?;
+                        self.check_static_initial_value(subtype, expr, diagnostics);
+                        self.check_others_has_bounds(
+                            &object_decl.subtype_indication,
+                            subtype,
+                            expr,
+                            diagnostics,
+                        );
                     } else {
                         self.expr_unknown_ttyp(scope, expr, diagnostics)?;
                     }
@@ -274,6 +468,12 @@ impl<'a> AnalyzeContext<'a> {
 
                 match subtype {
                     Ok(subtype) => {
+                        let subtype = if let Some(ref expr) = object_decl.expression {
+                            self.constrain_by_initial_value(subtype, &expr.item)
+                        } else {
+                            subtype
+                        };
+
                         let kind = if object_decl.class == ObjectClass::Constant
                             && object_decl.expression.is_none()
                         {
@@ -284,6 +484,7 @@ impl<'a> AnalyzeContext<'a> {
                                 iface: None,
                                 has_default: object_decl.expression.is_some(),
                                 subtype,
+                                signal_kind: object_decl.signal_kind,
                             })
                         };
 
@@ -333,11 +534,20 @@ impl<'a> AnalyzeContext<'a> {
                         }
                     };
 
+                if let Some(subtype) = subtype {
+                    if !matches!(subtype.base_type().kind(), Type::File) {
+                        diagnostics.error(
+                            &subtype_indication.type_mark.pos,
+                            format!("{} is not a file type", subtype.type_mark().describe()),
+                        );
+                    }
+                }
+
                 if let Some(ref mut expr) = open_info {
-                    self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+                    self.expr_with_ttyp(scope, self.file_open_kind(), expr, diagnostics)?;
                 }
                 if let Some(ref mut expr) = file_name {
-                    self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+                    self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
                 }
 
                 if let Some(subtype) = subtype {
@@ -347,6 +557,13 @@ impl<'a> AnalyzeContext<'a> {
                     );
                 }
             }
+            Declaration::View(ref mut view) => {
+                if let Some(ent) =
+                    as_fatal(self.analyze_view_declaration(scope, parent, view, diagnostics))?
+                {
+                    scope.add(ent, diagnostics);
+                }
+            }
             Declaration::Component(ref mut component) => {
                 let nested = scope.nested();
                 let ent = self.arena.define(
@@ -506,6 +723,7 @@ impl<'a> AnalyzeContext<'a> {
                     &mut body.declarations,
                     diagnostics,
                 )?;
+                self.record_scope(&body.pos, &subpgm_region);
 
                 self.analyze_sequential_part(
                     &subpgm_region,
@@ -553,8 +771,16 @@ impl<'a> AnalyzeContext<'a> {
                     scope.add(ent, diagnostics);
                 }
             }
+            Declaration::PackageBody(ref mut body) => {
+                self.analyze_nested_package_body(scope, parent, body, diagnostics)?;
+            }
             Declaration::Configuration(..) => {}
-            Declaration::Type(..) => unreachable!("Handled elsewhere"),
+            Declaration::Disconnection(ref mut disconnection) => {
+                self.analyze_disconnection_specification(scope, disconnection, diagnostics)?;
+            }
+            Declaration::Type(..) | Declaration::PackageDeclaration(..) => {
+                unreachable!("Handled elsewhere")
+            }
         };
 
         Ok(())
@@ -578,6 +804,92 @@ impl<'a> AnalyzeContext<'a> {
         None
     }
 
+    fn analyze_disconnection_specification(
+        &self,
+        scope: &Scope<'a>,
+        disconnection: &mut DisconnectionSpecification,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let typ = match self.resolve_type_mark(scope, &mut disconnection.type_mark) {
+            Ok(typ) => Some(typ),
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                None
+            }
+        };
+
+        if let GuardedSignalList::Names(ref mut names) = disconnection.signals {
+            for name in names.iter_mut() {
+                let Some(oname) = as_fatal(self.resolve_object_name(
+                    scope,
+                    &name.pos,
+                    &mut name.item,
+                    "cannot be disconnected",
+                    diagnostics,
+                ))?
+                else {
+                    continue;
+                };
+
+                let is_guarded = matches!(
+                    oname.base,
+                    ObjectBase::Object(obj) if obj.object().signal_kind.is_some()
+                );
+                if !is_guarded {
+                    diagnostics.error(
+                        &name.pos,
+                        format!("{} is not a guarded signal", oname.base.describe()),
+                    );
+                } else if let Some(typ) = typ {
+                    if oname.type_mark().base() != typ.base() {
+                        diagnostics.push(Diagnostic::type_mismatch(
+                            &name.pos,
+                            &oname.base.describe(),
+                            typ,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let time = &mut disconnection.time;
+        self.expr_pos_with_ttyp(scope, self.time(), &time.pos, &mut time.item, diagnostics)
+    }
+
+    /// Every method of the protected type declaration must be implemented in the body
+    fn check_protected_methods_have_body(
+        &self,
+        ptype_region: &Region<'a>,
+        body_scope: &Scope<'a>,
+        body_pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        for method in ptype_region.immediates() {
+            let Some(method) = OverloadedEnt::from_any(method) else {
+                continue;
+            };
+            if !method.is_subprogram_decl() {
+                continue;
+            }
+
+            let has_body = match body_scope.lookup_immediate(method.designator()) {
+                Some(NamedEntities::Overloaded(overloaded)) => overloaded
+                    .get(&method.signature().key())
+                    .is_some_and(|ent| ent.is_subprogram()),
+                _ => false,
+            };
+
+            if !has_body {
+                let mut diagnostic =
+                    Diagnostic::error(body_pos, format!("Missing body for {}", method.describe()));
+                if let Some(pos) = method.decl_pos() {
+                    diagnostic.add_related(pos, "Declared here");
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
     fn find_deferred_constant_declaration(
         &self,
         scope: &Scope<'a>,
@@ -681,6 +993,13 @@ impl<'a> AnalyzeContext<'a> {
                                             diagnostics,
                                         )?;
 
+                                        self.check_protected_methods_have_body(
+                                            ptype_region,
+                                            &region,
+                                            &type_decl.ident.tree.pos,
+                                            diagnostics,
+                                        );
+
                                         let kind = Type::Protected(region.into_region(), true);
                                         unsafe {
                                             ptype_body.set_kind(AnyEntKind::Type(kind));
@@ -778,8 +1097,13 @@ impl<'a> AnalyzeContext<'a> {
                 let mut elems = RecordRegion::default();
                 let mut region = Region::default();
                 for elem_decl in element_decls.iter_mut() {
-                    let subtype =
-                        self.resolve_subtype_indication(scope, &mut elem_decl.subtype, diagnostics);
+                    let subtype = self.resolve_element_subtype_indication(
+                        scope,
+                        type_decl.ident.name(),
+                        overwrite_id,
+                        &mut elem_decl.subtype,
+                        diagnostics,
+                    );
                     match subtype {
                         Ok(subtype) => {
                             let elem = self.arena.define(
@@ -812,6 +1136,17 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             TypeDefinition::Access(ref mut subtype_indication) => {
+                // An access type may designate the incomplete type it completes
+                if overwrite_id.is_none()
+                    && is_self_reference(type_decl.ident.name(), &subtype_indication.type_mark)
+                {
+                    diagnostics.push(self_reference_error(
+                        type_decl.ident.name(),
+                        &subtype_indication.type_mark.pos,
+                    ));
+                    return Ok(());
+                }
+
                 let subtype =
                     self.resolve_subtype_indication(scope, subtype_indication, diagnostics);
                 match subtype {
@@ -839,23 +1174,31 @@ impl<'a> AnalyzeContext<'a> {
             }
             TypeDefinition::Array(ref mut array_indexes, ref mut subtype_indication) => {
                 let mut indexes: Vec<Option<BaseType>> = Vec::with_capacity(array_indexes.len());
+                let mut index_ranges: Vec<Option<StaticRange>> =
+                    Vec::with_capacity(array_indexes.len());
                 for index in array_indexes.iter_mut() {
-                    indexes.push(as_fatal(self.analyze_array_index(
-                        scope,
-                        index,
-                        diagnostics,
-                    ))?);
+                    let index = as_fatal(self.analyze_array_index(scope, index, diagnostics))?;
+                    indexes.push(index.map(|(typ, _)| typ));
+                    index_ranges.push(index.and_then(|(_, range)| range));
                 }
 
-                let elem_type =
-                    match self.resolve_subtype_indication(scope, subtype_indication, diagnostics) {
-                        Ok(subtype) => subtype.type_mark().to_owned(),
-                        Err(err) => {
-                            err.add_to(diagnostics)?;
-                            return Ok(());
-                        }
-                    };
+                let elem_type = match self.resolve_element_subtype_indication(
+                    scope,
+                    type_decl.ident.name(),
+                    overwrite_id,
+                    subtype_indication,
+                    diagnostics,
+                ) {
+                    Ok(subtype) => subtype.type_mark().to_owned(),
+                    Err(err) => {
+                        err.add_to(diagnostics)?;
+                        return Ok(());
+                    }
+                };
 
+                let is_constrained = array_indexes
+                    .iter()
+                    .any(|index| matches!(index, ArrayIndex::Discrete(..)));
                 let is_1d = indexes.len() == 1;
                 let array_ent = TypeEnt::define_with_opt_id(
                     self.arena,
@@ -863,7 +1206,12 @@ impl<'a> AnalyzeContext<'a> {
                     &mut type_decl.ident,
                     parent,
                     None,
-                    Type::Array { indexes, elem_type },
+                    Type::Array {
+                        indexes,
+                        index_ranges,
+                        elem_type,
+                        is_constrained,
+                    },
                 );
 
                 scope.add(array_ent.into(), diagnostics);
@@ -877,7 +1225,13 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             TypeDefinition::Subtype(ref mut subtype_indication) => {
-                match self.resolve_subtype_indication(scope, subtype_indication, diagnostics) {
+                match self.resolve_element_subtype_indication(
+                    scope,
+                    type_decl.ident.name(),
+                    overwrite_id,
+                    subtype_indication,
+                    diagnostics,
+                ) {
                     Ok(subtype) => {
                         let type_ent = TypeEnt::define_with_opt_id(
                             self.arena,
@@ -965,10 +1319,12 @@ impl<'a> AnalyzeContext<'a> {
             TypeDefinition::Numeric(ref mut range) => {
                 self.range_unknown_typ(scope, range, diagnostics)?;
 
+                let mut static_range = None;
                 let universal_type = if let Some(range_typ) =
                     as_fatal(self.range_type(scope, range, diagnostics))?
                 {
                     if range_typ.is_any_integer() {
+                        static_range = self.static_range(range_typ, range);
                         UniversalType::Integer
                     } else if range_typ.is_any_real() {
                         UniversalType::Real
@@ -987,7 +1343,7 @@ impl<'a> AnalyzeContext<'a> {
                     parent,
                     None,
                     match universal_type {
-                        UniversalType::Integer => Type::Integer,
+                        UniversalType::Integer => Type::Integer(static_range),
                         UniversalType::Real => Type::Real,
                     },
                 );
@@ -1135,17 +1491,33 @@ impl<'a> AnalyzeContext<'a> {
                 }
 
                 let subtype = subtype?;
+                let iface = if let Some(ref mut mode_view) = object_decl.mode_view {
+                    let view = self.resolve_mode_view(scope, mode_view)?;
+                    if view.view().typ.base() != subtype.base() {
+                        diagnostics.push(Diagnostic::type_mismatch(
+                            &object_decl.subtype_indication.type_mark.pos,
+                            &format!(
+                                "view '{}' of {}",
+                                view.designator(),
+                                view.view().typ.describe()
+                            ),
+                            subtype.type_mark(),
+                        ));
+                    }
+                    ObjectInterface::ModeView(object_decl.list_type, view)
+                } else {
+                    ObjectInterface::new(object_decl.list_type, object_decl.mode)
+                };
+
                 self.arena.define(
                     &mut object_decl.ident,
                     parent,
                     AnyEntKind::Object(Object {
                         class: object_decl.class,
-                        iface: Some(ObjectInterface::new(
-                            object_decl.list_type,
-                            object_decl.mode,
-                        )),
+                        iface: Some(iface),
                         subtype,
                         has_default: object_decl.expression.is_some(),
+                        signal_kind: None,
                     }),
                 )
             }
@@ -1239,23 +1611,141 @@ impl<'a> AnalyzeContext<'a> {
         Ok(params)
     }
 
+    /// A statically known initial value of a scalar object must be within the range of its subtype
+    fn check_static_initial_value(
+        &self,
+        subtype: &Subtype<'a>,
+        expr: &WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let base = subtype.base();
+        if !base.is_discrete() {
+            return;
+        }
+
+        let Some(range) = subtype.static_range() else {
+            return;
+        };
+
+        let Some(value) = self.static_discrete_value(base, &expr.item) else {
+            return;
+        };
+
+        if !range.contains(value) {
+            let describe = |value: i64| -> String {
+                if base.is_enum() {
+                    base.enum_literals()
+                        .nth(value as usize)
+                        .map(|ent| ent.designator().to_string())
+                        .unwrap_or_else(|| value.to_string())
+                } else {
+                    value.to_string()
+                }
+            };
+
+            diagnostics.error(
+                &expr.pos,
+                format!(
+                    "Value {} is outside of the range {} {} {}",
+                    describe(value),
+                    describe(range.left),
+                    range.direction,
+                    describe(range.right)
+                ),
+            );
+        }
+    }
+
+    /// An others choice needs the bounds of the aggregate which cannot be taken
+    /// from an unconstrained array subtype
+    fn check_others_has_bounds(
+        &self,
+        subtype_indication: &SubtypeIndication,
+        subtype: &Subtype<'a>,
+        expr: &WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let Expression::Aggregate(ref assocs) = expr.item else {
+            return;
+        };
+        // The constraint of an object is lost when taking its 'subtype
+        if subtype_indication.type_mark.item.attr.is_some() || !subtype.is_unconstrained_array() {
+            return;
+        }
+
+        for assoc in assocs.iter() {
+            if let ElementAssociation::Named(choices, _) = assoc {
+                for choice in choices.iter() {
+                    if matches!(choice.item, Choice::Others) {
+                        diagnostics.error(
+                            &choice.pos,
+                            format!(
+                                "'others' cannot be used when the bounds of {} are not known",
+                                subtype.type_mark().describe()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// An object of an unconstrained one dimensional array subtype
+    /// takes its index range from the initial value
+    fn constrain_by_initial_value(&self, subtype: Subtype<'a>, expr: &Expression) -> Subtype<'a> {
+        if subtype.static_range().is_some() {
+            return subtype;
+        }
+
+        let Type::Array { index_ranges, .. } = subtype.base().kind() else {
+            return subtype;
+        };
+
+        let range = match (index_ranges.as_slice(), expr) {
+            ([_], Expression::Name(name)) => self.static_name_range(name),
+            ([Some(index_range)], Expression::Literal(Literal::String(string))) => {
+                // The left bound is the left bound of the index subtype
+                let len = string.len() as i64;
+                let left = index_range.left;
+                Some(match index_range.direction {
+                    Direction::Ascending => {
+                        StaticRange::new(left, left + len - 1, Direction::Ascending)
+                    }
+                    Direction::Descending => {
+                        StaticRange::new(left, left - len + 1, Direction::Descending)
+                    }
+                })
+            }
+            _ => None,
+        };
+
+        if range.is_some() {
+            subtype.with_range(range)
+        } else {
+            subtype
+        }
+    }
+
     fn analyze_array_index(
         &self,
         scope: &Scope<'a>,
         array_index: &mut ArrayIndex,
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> EvalResult<BaseType<'a>> {
+    ) -> EvalResult<(BaseType<'a>, Option<StaticRange>)> {
         match array_index {
             ArrayIndex::IndexSubtypeDefintion(ref mut type_mark) => {
                 match self.resolve_type_mark(scope, type_mark) {
-                    Ok(typ) => Ok(typ.base()),
+                    Ok(typ) => Ok((typ.base(), typ.static_range())),
                     Err(err) => {
                         err.add_to(diagnostics)?;
                         Err(EvalError::Unknown)
                     }
                 }
             }
-            ArrayIndex::Discrete(ref mut drange) => self.drange_type(scope, drange, diagnostics),
+            ArrayIndex::Discrete(ref mut drange) => {
+                let typ = self.drange_type(scope, drange, diagnostics)?;
+                Ok((typ, self.static_drange(typ, drange)))
+            }
         }
     }
 
@@ -1269,7 +1759,10 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match constraint {
             SubtypeConstraint::Array(ref mut dranges, ref mut constraint) => {
-                if let Type::Array { indexes, elem_type } = base_type.kind() {
+                if let Type::Array {
+                    indexes, elem_type, ..
+                } = base_type.kind()
+                {
                     for (idx, drange) in dranges.iter_mut().enumerate() {
                         if let Some(index_typ) = indexes.get(idx) {
                             if let Some(index_typ) = index_typ {
@@ -1368,6 +1861,33 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// Resolve a subtype indication within the declaration of a type
+    /// The type cannot be used within its own declaration, neither directly nor
+    /// through the element type of another type, except by an access type
+    fn resolve_element_subtype_indication(
+        &self,
+        scope: &Scope<'a>,
+        type_name: &Symbol,
+        // The id of the incomplete type which is completed by the declaration
+        overwrite_id: Option<EntityId>,
+        subtype_indication: &mut SubtypeIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> AnalysisResult<Subtype<'a>> {
+        let type_mark_pos = subtype_indication.type_mark.pos.clone();
+        if overwrite_id.is_none() && is_self_reference(type_name, &subtype_indication.type_mark) {
+            return Err(self_reference_error(type_name, &type_mark_pos).into());
+        }
+
+        let subtype = self.resolve_subtype_indication(scope, subtype_indication, diagnostics)?;
+        if let Some(id) = overwrite_id {
+            // Do not define the type such that the cycle cannot be followed later
+            if contains_type(subtype.type_mark(), id) {
+                return Err(self_reference_error(type_name, &type_mark_pos).into());
+            }
+        }
+        Ok(subtype)
+    }
+
     pub fn resolve_subtype_indication(
         &self,
         scope: &Scope<'a>,
@@ -1391,21 +1911,88 @@ impl<'a> AnalyzeContext<'a> {
                 &mut constraint.item,
                 diagnostics,
             )?;
-        }
 
-        Ok(Subtype::new(base_type))
+            Ok(self.constrained_subtype(base_type, &constraint.item))
+        } else if type_mark.item.attr == Some(TypeAttribute::Subtype) {
+            // The 'subtype attribute denotes the subtype of the object including its constraints
+            let subtype = type_mark
+                .item
+                .name
+                .item
+                .get_suffix_reference()
+                .and_then(|id| match self.arena.get(id).kind() {
+                    AnyEntKind::Object(obj) => Some(obj.subtype),
+                    AnyEntKind::ElementDeclaration(subtype) => Some(*subtype),
+                    _ => None,
+                });
+            Ok(subtype.unwrap_or_else(|| Subtype::new(base_type)))
+        } else {
+            Ok(Subtype::new(base_type))
+        }
     }
 
-    pub fn analyze_subtype_indication(
+    /// The subtype of a type mark with an already analyzed constraint
+    fn constrained_subtype(
         &self,
-        scope: &Scope<'a>,
-        subtype_indication: &mut SubtypeIndication,
-        diagnostics: &mut dyn DiagnosticHandler,
-    ) -> FatalResult {
-        if let Err(err) = self.resolve_subtype_indication(scope, subtype_indication, diagnostics) {
-            err.add_to(diagnostics)?;
+        type_mark: TypeEnt<'a>,
+        constraint: &SubtypeConstraint,
+    ) -> Subtype<'a> {
+        let subtype = Subtype::new(type_mark);
+        match constraint {
+            SubtypeConstraint::Range(range) => subtype
+                .with_range(self.static_range(type_mark.base(), range))
+                .with_generic_range(self.generic_range(type_mark.base(), range)),
+            SubtypeConstraint::Array(dranges, _) => {
+                let subtype = subtype.with_index_constraint();
+                let index_typ = type_mark
+                    .array_type()
+                    .and_then(|(_, indexes)| indexes.first().copied().flatten());
+                if let (Some(index_typ), Some(drange)) = (index_typ, dranges.first()) {
+                    subtype
+                        .with_range(self.static_drange(index_typ, drange))
+                        .with_generic_range(self.generic_drange(index_typ, drange))
+                } else {
+                    subtype
+                }
+            }
+            SubtypeConstraint::Record(constraints) => {
+                let Type::Record(region) = type_mark.base().kind() else {
+                    return subtype;
+                };
+
+                // Selecting an element of the subtype shall give the constrained element subtype
+                let mut elems = RecordRegion::default();
+                for elem in region.iter() {
+                    let des = elem.designator();
+                    let constraint = constraints.iter().find(|constraint| {
+                        &Designator::Identifier(constraint.ident.item.clone()) == des
+                    });
+
+                    if let Some(constraint) = constraint {
+                        let elem_subtype =
+                            self.constrained_subtype(elem.type_mark(), &constraint.constraint.item);
+                        elems.add(self.arena.alloc(
+                            des.clone(),
+                            elem.parent,
+                            Related::InstanceOf(elem.into()),
+                            AnyEntKind::ElementDeclaration(elem_subtype),
+                            elem.decl_pos().cloned(),
+                        ));
+                    } else {
+                        elems.add(elem.into());
+                    }
+                }
+
+                let elements = self.arena.alloc(
+                    type_mark.designator().clone(),
+                    Some(type_mark.into()),
+                    Related::InstanceOf(type_mark.into()),
+                    AnyEntKind::Type(Type::Record(elems)),
+                    type_mark.decl_pos().cloned(),
+                );
+                subtype.with_elements(TypeEnt::from_any(elements))
+            }
         }
-        Ok(())
     }
 
     fn subprogram_declaration(
@@ -1470,6 +2057,38 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+fn is_self_reference(type_name: &Symbol, type_mark: &WithPos<TypeMark>) -> bool {
+    match &type_mark.item.name.item {
+        SelectedName::Designator(des) => des.item == Designator::Identifier(type_name.clone()),
+        SelectedName::Selected(..) => false,
+    }
+}
+
+fn self_reference_error(type_name: &Symbol, pos: &SrcPos) -> Diagnostic {
+    Diagnostic::error(
+        pos,
+        format!("Type '{type_name}' cannot be used within its own declaration"),
+    )
+}
+
+/// True if the type is the type with the id or has it as element type
+/// Access types are not followed since they may designate an incomplete type
+fn contains_type(typ: TypeEnt, id: EntityId) -> bool {
+    if typ.id() == id {
+        return true;
+    }
+
+    match typ.kind() {
+        Type::Subtype(subtype) => contains_type(subtype.type_mark(), id),
+        Type::Alias(typ) => contains_type(*typ, id),
+        Type::Array { elem_type, .. } => contains_type(*elem_type, id),
+        Type::Record(region) => region
+            .iter()
+            .any(|elem| contains_type(elem.type_mark(), id)),
+        _ => false,
+    }
+}
+
 fn find_full_type_definition<'a>(
     name: &Symbol,
     decls: &'a [Declaration],
@@ -113,6 +113,13 @@ impl<'a> AnalyzeContext<'a> {
                         ));
                     }
                 }
+
+                self.analyze_block_configuration(
+                    &root_region,
+                    named_entity,
+                    &mut unit.block_config,
+                    diagnostics,
+                )?;
             }
             Err(err) => {
                 err.add_to(diagnostics)?;
@@ -128,6 +135,297 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// Analyze the block configuration of an architecture of an entity
+    fn analyze_block_configuration(
+        &self,
+        scope: &Scope<'a>,
+        entity: DesignEnt<'a>,
+        block: &mut BlockConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let (arch_scope, blocks) =
+            match self.resolve_configured_architecture(entity, &mut block.block_spec) {
+                Ok(configured) => configured,
+                Err(err) => {
+                    err.add_to(diagnostics)?;
+                    (scope.clone(), Vec::new())
+                }
+            };
+
+        self.analyze_configuration_items(scope, &arch_scope, &blocks, &mut block.items, diagnostics)
+    }
+
+    /// Returns the scope of the configured architecture
+    /// and the block and generate statements within it
+    fn resolve_configured_architecture(
+        &self,
+        entity: DesignEnt<'a>,
+        block_spec: &mut WithPos<Name>,
+    ) -> AnalysisResult<(Scope<'a>, Vec<ConfigurableBlock<'a>>)> {
+        let Name::Designator(ref mut designator) = block_spec.item else {
+            return Err(AnalysisError::not_fatal_error(
+                &block_spec.pos,
+                "Expected the name of an architecture",
+            ));
+        };
+
+        let (
+            Some(library_name),
+            Designator::Identifier(entity_name),
+            Designator::Identifier(architecture_name),
+        ) = (
+            entity.library_name(),
+            entity.designator(),
+            designator.item.clone(),
+        )
+        else {
+            return Err(AnalysisError::not_fatal_error(
+                &block_spec.pos,
+                "Expected the name of an architecture",
+            ));
+        };
+
+        let arch = self.get_architecture(
+            library_name,
+            &block_spec.pos,
+            entity_name,
+            &architecture_name,
+        )?;
+        designator.set_unique_reference(&arch);
+
+        let blocks = self.get_architecture_blocks(
+            library_name,
+            &block_spec.pos,
+            entity_name,
+            &architecture_name,
+        )?;
+
+        // Names within the block configuration are resolved as within the architecture
+        let arch_scope = if let Design::Architecture(ref visibility, ref region, _) = arch.kind() {
+            let root_scope = Scope::new(Region::with_visibility(visibility.clone()));
+            Scope::extend(region, Some(&root_scope))
+        } else {
+            Scope::default()
+        };
+
+        Ok((arch_scope, blocks))
+    }
+
+    /// Analyze the configuration items of a block
+    /// Component and block names are resolved in the scope of the configured block while
+    /// the entities of binding indications are resolved in the scope of the configuration
+    fn analyze_configuration_items(
+        &self,
+        scope: &Scope<'a>,
+        block_scope: &Scope<'a>,
+        blocks: &[ConfigurableBlock<'a>],
+        items: &mut [ConfigurationItem],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for item in items.iter_mut() {
+            match item {
+                ConfigurationItem::Block(ref mut block_config) => {
+                    // Nested block configurations refer to block and generate statements
+                    // within the same architecture
+                    let block = match self.resolve_configured_block(
+                        block_scope,
+                        blocks,
+                        &mut block_config.block_spec,
+                    ) {
+                        Ok(block) => block,
+                        Err(err) => {
+                            err.add_to(diagnostics)?;
+                            continue;
+                        }
+                    };
+
+                    let nested = block_scope.nested();
+                    for ent in block
+                        .components
+                        .iter()
+                        .chain(block.blocks.iter().map(|block| &block.label))
+                    {
+                        nested.make_potentially_visible(None, ent);
+                    }
+
+                    self.analyze_configuration_items(
+                        scope,
+                        &nested,
+                        &block.blocks,
+                        &mut block_config.items,
+                        diagnostics,
+                    )?;
+                }
+                ConfigurationItem::Component(ref mut config) => {
+                    self.analyze_component_configuration(scope, block_scope, config, diagnostics)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve the label of a block or generate statement within a block specification
+    fn resolve_configured_block<'b>(
+        &self,
+        block_scope: &Scope<'a>,
+        blocks: &'b [ConfigurableBlock<'a>],
+        block_spec: &mut WithPos<Name>,
+    ) -> AnalysisResult<&'b ConfigurableBlock<'a>> {
+        // The index specification of a generate statement is not analyzed
+        let (label, pos) = match block_spec.item {
+            Name::Designator(ref mut designator) => (designator, &block_spec.pos),
+            Name::CallOrIndexed(ref mut call) => match call.name.item {
+                Name::Designator(ref mut designator) => (designator, &call.name.pos),
+                _ => {
+                    return Err(AnalysisError::not_fatal_error(
+                        &call.name.pos,
+                        "Expected the label of a block or generate statement",
+                    ))
+                }
+            },
+            _ => {
+                return Err(AnalysisError::not_fatal_error(
+                    &block_spec.pos,
+                    "Expected the label of a block or generate statement",
+                ))
+            }
+        };
+
+        let expected = "block or generate statement";
+        let entities = block_scope
+            .lookup(pos, label.designator())
+            .map_err(AnalysisError::NotFatal)?;
+        let ent = self.resolve_non_overloaded(entities, pos, expected)?;
+        let Some(block) = blocks.iter().find(|block| block.label.id() == ent.id()) else {
+            return Err(AnalysisError::NotFatal(ent.kind_error(pos, expected)));
+        };
+
+        label.set_unique_reference(block.label);
+        Ok(block)
+    }
+
+    fn analyze_component_configuration(
+        &self,
+        scope: &Scope<'a>,
+        block_scope: &Scope<'a>,
+        config: &mut ComponentConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        fn is_component(kind: &AnyEntKind) -> bool {
+            matches!(kind, AnyEntKind::Component(_))
+        }
+
+        let component_name = &mut config.spec.component_name;
+        if let Err(err) = self
+            .resolve_selected_name(block_scope, component_name)
+            .and_then(|entities| {
+                self.resolve_non_overloaded_with_kind(
+                    entities,
+                    component_name.suffix_pos(),
+                    &is_component,
+                    "component",
+                )
+            })
+        {
+            err.add_to(diagnostics)?;
+        }
+
+        let mut bound_entity = None;
+        if let Some(ref mut bind_ind) = config.bind_ind {
+            bound_entity = self.analyze_binding_indication(scope, bind_ind, diagnostics)?;
+        }
+
+        if let Some(ref mut block) = config.block_config {
+            if let Some(entity) = bound_entity {
+                self.analyze_block_configuration(scope, entity, block, diagnostics)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the entity that is bound if any
+    fn analyze_binding_indication(
+        &self,
+        scope: &Scope<'a>,
+        bind_ind: &mut BindingIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<DesignEnt<'a>>> {
+        // The actuals of the generic and port maps refer to the local generics and ports
+        // of the component which are not visible here
+        match bind_ind.entity_aspect {
+            Some(EntityAspect::Entity(ref mut entity_name, ref architecture_name)) => {
+                let entity = self
+                    .resolve_selected_name(scope, entity_name)
+                    .and_then(|entities| {
+                        let ent = self.resolve_non_overloaded(
+                            entities,
+                            entity_name.suffix_pos(),
+                            "entity",
+                        )?;
+                        match DesignEnt::from_any(ent) {
+                            Some(design) if matches!(design.kind(), Design::Entity(..)) => {
+                                Ok(design)
+                            }
+                            _ => Err(AnalysisError::NotFatal(
+                                ent.kind_error(entity_name.suffix_pos(), "entity"),
+                            )),
+                        }
+                    });
+
+                let entity = match entity {
+                    Ok(entity) => entity,
+                    Err(err) => {
+                        err.add_to(diagnostics)?;
+                        return Ok(None);
+                    }
+                };
+
+                if let (
+                    Some(architecture_name),
+                    Some(library_name),
+                    Designator::Identifier(entity_ident),
+                ) = (
+                    architecture_name,
+                    entity.library_name(),
+                    entity.designator(),
+                ) {
+                    if let Err(err) = self.get_architecture(
+                        library_name,
+                        &architecture_name.pos,
+                        entity_ident,
+                        &architecture_name.item,
+                    ) {
+                        err.add_to(diagnostics)?;
+                    }
+                }
+
+                Ok(Some(entity))
+            }
+            Some(EntityAspect::Configuration(ref mut config_name)) => {
+                fn is_configuration(kind: &AnyEntKind) -> bool {
+                    matches!(kind, AnyEntKind::Design(Design::Configuration))
+                }
+
+                if let Err(err) =
+                    self.resolve_selected_name(scope, config_name)
+                        .and_then(|entities| {
+                            self.resolve_non_overloaded_with_kind(
+                                entities,
+                                config_name.suffix_pos(),
+                                &is_configuration,
+                                "configuration",
+                            )
+                        })
+                {
+                    err.add_to(diagnostics)?;
+                }
+                Ok(None)
+            }
+            Some(EntityAspect::Open) | None => Ok(None),
+        }
+    }
+
     fn analyze_package(
         &self,
         unit: &mut PackageDeclaration,
@@ -262,10 +560,15 @@ impl<'a> AnalyzeContext<'a> {
         self.analyze_context_clause(&root_scope, &mut unit.context_clause, diagnostics)?;
         let scope = Scope::extend(region, Some(&root_scope));
 
+        // Pre-define architecture and overwrite it later
         let arch = self.arena.define(
             &mut unit.ident,
             primary.into(),
-            AnyEntKind::Design(Design::Architecture(primary)),
+            AnyEntKind::Design(Design::Architecture(
+                Visibility::default(),
+                Region::default(),
+                primary,
+            )),
         );
 
         // Architecture name is visible
@@ -275,6 +578,17 @@ impl<'a> AnalyzeContext<'a> {
         self.analyze_declarative_part(&scope, arch, &mut unit.decl, diagnostics)?;
         self.analyze_concurrent_part(&scope, arch, &mut unit.statements, diagnostics)?;
         scope.close(diagnostics);
+
+        if self.root.combinational_loop_warnings {
+            self.check_combinational_loops(&mut unit.statements, diagnostics);
+        }
+
+        // Keep the scope of the architecture for configurations of it
+        let region = scope.into_region();
+        let visibility = root_scope.into_visibility();
+        let kind = AnyEntKind::Design(Design::Architecture(visibility, region, primary));
+        unsafe { arch.set_kind(kind) }
+
         Ok(())
     }
 
@@ -474,6 +788,7 @@ impl<'a> AnalyzeContext<'a> {
         context_clause: &mut [WithPos<ContextItem>],
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
+        let _span = enter_span!(DEBUG, "context_clause");
         for context_item in context_clause.iter_mut() {
             match context_item.item {
                 ContextItem::Library(LibraryClause { ref mut name_list }) => {
@@ -6,6 +6,7 @@
 
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
+use itertools::Itertools;
 
 use super::analyze::*;
 use super::formal_region::RecordElement;
@@ -38,6 +39,14 @@ impl<'a> ExpressionType<'a> {
         }
     }
 
+    fn disambiguated(&self) -> Option<DisambiguatedType<'a>> {
+        match self {
+            ExpressionType::Unambiguous(typ) => Some(DisambiguatedType::Unambiguous(*typ)),
+            ExpressionType::Ambiguous(types) => Some(DisambiguatedType::Ambiguous(types.clone())),
+            ExpressionType::String | ExpressionType::Null | ExpressionType::Aggregate => None,
+        }
+    }
+
     pub(crate) fn describe(&self) -> String {
         match self {
             ExpressionType::Unambiguous(typ) => format!("expression with {}", typ.describe()),
@@ -74,7 +83,7 @@ impl<'c, 'a> TypeMatcher<'c, 'a> {
             true
         } else if self.implicit_type_conversion {
             match ttyp.kind() {
-                Type::Integer => types.match_type(self.context.universal_integer()),
+                Type::Integer(..) => types.match_type(self.context.universal_integer()),
                 Type::Real => types.match_type(self.context.universal_real()),
                 Type::Universal(UniversalType::Integer)
                     if self.implicit_type_conversion_from_universal =>
@@ -184,7 +193,7 @@ impl<'a> AnalyzeContext<'a> {
         self.any_matcher().is_possible(types, ttyp)
     }
 
-    pub fn common_type(&self, typ1: BaseType<'a>, typ2: BaseType<'a>) -> Option<BaseType<'a>> {
+    pub fn common_base_type(&self, typ1: BaseType<'a>, typ2: BaseType<'a>) -> Option<BaseType<'a>> {
         if typ1.id() == typ2.id() {
             Some(typ1)
         } else if typ1.is_universal_of(typ2) {
@@ -203,10 +212,73 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FnvHashSet<BaseType<'a>> {
         types
             .into_iter()
-            .filter_map(|t| self.common_type(t, typ))
+            .filter_map(|t| self.common_base_type(t, typ))
             .collect()
     }
 
+    /// The type of both operands where a universal operand converts implicitly
+    /// Closely related types require an explicit conversion and have no common type
+    pub fn common_type(
+        &self,
+        typ1: DisambiguatedType<'a>,
+        typ2: DisambiguatedType<'a>,
+    ) -> Option<TypeEnt<'a>> {
+        let types: Vec<_> = match (typ1, typ2) {
+            (DisambiguatedType::Unambiguous(l), DisambiguatedType::Unambiguous(r)) => {
+                // Keep the subtype of the operand which is not universal
+                return self.common_base_type(l.base(), r.base()).map(|typ| {
+                    if typ == r.base() {
+                        r
+                    } else {
+                        l
+                    }
+                });
+            }
+            (DisambiguatedType::Unambiguous(l), DisambiguatedType::Ambiguous(r))
+            | (DisambiguatedType::Ambiguous(r), DisambiguatedType::Unambiguous(l)) => r
+                .into_iter()
+                .filter_map(|rtyp| self.common_base_type(rtyp, l.base()))
+                .unique()
+                .collect(),
+            (DisambiguatedType::Ambiguous(l), DisambiguatedType::Ambiguous(r)) => l
+                .iter()
+                .cartesian_product(r.iter())
+                .filter_map(|(ltyp, rtyp)| self.common_base_type(*ltyp, *rtyp))
+                .unique()
+                .collect(),
+        };
+
+        match types.as_slice() {
+            [typ] => Some((*typ).into()),
+            _ => None,
+        }
+    }
+
+    /// The type of the alternatives of a conditional or selected expression so far
+    /// A universal alternative takes the type of the other alternatives and
+    /// otherwise an alternative of known type decides the type of the expression
+    fn alternatives_type(
+        &self,
+        result: Option<ExpressionType<'a>>,
+        typ: ExpressionType<'a>,
+    ) -> ExpressionType<'a> {
+        let Some(result) = result else {
+            return typ;
+        };
+
+        if let (Some(rtyp), Some(ttyp)) = (result.disambiguated(), typ.disambiguated()) {
+            if let Some(common) = self.common_type(rtyp, ttyp) {
+                return ExpressionType::Unambiguous(common);
+            }
+        }
+
+        if matches!(result, ExpressionType::Unambiguous(_)) {
+            result
+        } else {
+            typ
+        }
+    }
+
     pub fn can_be_target_type(&self, typ: TypeEnt<'a>, ttyp: BaseType<'a>) -> bool {
         self.any_matcher().can_be_target_type(typ, ttyp)
     }
@@ -414,7 +486,7 @@ impl<'a> AnalyzeContext<'a> {
 
     fn as_universal(&self, typ: BaseType<'a>) -> Option<BaseType<'a>> {
         match typ.kind() {
-            Type::Integer => Some(self.universal_integer()),
+            Type::Integer(..) => Some(self.universal_integer()),
             Type::Real => Some(self.universal_real()),
             _ => None,
         }
@@ -479,21 +551,37 @@ impl<'a> AnalyzeContext<'a> {
                 let typ = self.analyze_qualified_expression(scope, qexpr, diagnostics)?;
                 Ok(ExpressionType::Unambiguous(typ))
             }
-            Expression::New(ref mut alloc) => match &mut alloc.item {
-                Allocator::Qualified(ref mut qexpr) => {
-                    let typ = self.analyze_qualified_expression(scope, qexpr, diagnostics)?;
-                    Ok(ExpressionType::Unambiguous(typ))
+            Expression::New(ref mut alloc) => {
+                let typ = self.analyze_allocation(scope, alloc, diagnostics)?;
+                Ok(ExpressionType::Unambiguous(typ))
+            }
+            Expression::Conditional(ref mut conds) => {
+                for cond in conds.conditionals.iter_mut() {
+                    self.boolean_expr(scope, &mut cond.condition, diagnostics)?;
                 }
-                Allocator::Subtype(ref mut subtype) => {
-                    match self.resolve_subtype_indication(scope, subtype, diagnostics) {
-                        Ok(typ) => Ok(ExpressionType::Unambiguous(typ.type_mark())),
-                        Err(err) => {
-                            diagnostics.push(err.into_non_fatal()?);
-                            Err(EvalError::Unknown)
-                        }
-                    }
+
+                let mut result = None;
+                let items = conds
+                    .conditionals
+                    .iter_mut()
+                    .map(|cond| &mut cond.item)
+                    .chain(conds.else_item.as_mut());
+                for item in items {
+                    let typ = self.expr_type(scope, item, diagnostics)?;
+                    result = Some(self.alternatives_type(result, typ));
                 }
-            },
+                result.ok_or(EvalError::Unknown)
+            }
+            Expression::Selected(ref mut selection) => {
+                self.analyze_selected_expression_choices(scope, selection, diagnostics)?;
+
+                let mut result = None;
+                for alternative in selection.alternatives.iter_mut() {
+                    let typ = self.expr_type(scope, &mut alternative.item, diagnostics)?;
+                    result = Some(self.alternatives_type(result, typ));
+                }
+                result.ok_or(EvalError::Unknown)
+            }
             Expression::Literal(ref mut literal) => match literal {
                 Literal::Physical(PhysicalLiteral { ref mut unit, .. }) => {
                     match self.resolve_physical_unit(scope, unit) {
@@ -600,21 +688,27 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// Returns the designated type of the allocated object
     pub fn analyze_allocation(
         &self,
         scope: &Scope<'a>,
         alloc: &mut WithPos<Allocator>,
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> FatalResult {
+    ) -> EvalResult<TypeEnt<'a>> {
         match &mut alloc.item {
             Allocator::Qualified(ref mut qexpr) => {
-                as_fatal(self.analyze_qualified_expression(scope, qexpr, diagnostics))?;
+                self.analyze_qualified_expression(scope, qexpr, diagnostics)
             }
             Allocator::Subtype(ref mut subtype) => {
-                self.analyze_subtype_indication(scope, subtype, diagnostics)?;
+                match self.resolve_subtype_indication(scope, subtype, diagnostics) {
+                    Ok(typ) => Ok(typ.type_mark()),
+                    Err(err) => {
+                        diagnostics.push(err.into_non_fatal()?);
+                        Err(EvalError::Unknown)
+                    }
+                }
             }
         }
-        Ok(())
     }
 
     pub fn expr_with_ttyp(
@@ -628,16 +722,10 @@ impl<'a> AnalyzeContext<'a> {
     }
 
     fn implicit_bool_types(&self, scope: &Scope<'a>, pos: &SrcPos) -> FnvHashSet<BaseType<'a>> {
-        if let Ok(NamedEntities::Overloaded(overloaded)) =
-            scope.lookup(pos, &Designator::OperatorSymbol(Operator::QueQue))
-        {
-            overloaded
-                .entities()
-                .filter_map(|ent| ent.formals().nth(0).map(|typ| typ.type_mark().base()))
-                .collect()
-        } else {
-            FnvHashSet::default()
-        }
+        self.call_candidates(scope, pos, &Designator::OperatorSymbol(Operator::QueQue))
+            .into_iter()
+            .filter_map(|ent| ent.formals().nth(0).map(|typ| typ.type_mark().base()))
+            .collect()
     }
 
     /// An expression of any integer type
@@ -867,6 +955,7 @@ impl<'a> AnalyzeContext<'a> {
                 Type::Array {
                     elem_type, indexes, ..
                 } => {
+                    check_others_is_last(assocs, diagnostics);
                     for assoc in assocs.iter_mut() {
                         as_fatal(self.array_assoc_elem(
                             scope,
@@ -877,8 +966,19 @@ impl<'a> AnalyzeContext<'a> {
                             diagnostics,
                         ))?;
                     }
+
+                    if let Some(Some(index_type)) = indexes.first() {
+                        self.check_aggregate_choices(
+                            target_type,
+                            *index_type,
+                            expr_pos,
+                            assocs,
+                            diagnostics,
+                        );
+                    }
                 }
                 Type::Record(record_scope) => {
+                    check_others_is_last(assocs, diagnostics);
                     self.analyze_record_aggregate(
                         scope,
                         target_base,
@@ -897,14 +997,68 @@ impl<'a> AnalyzeContext<'a> {
                     );
                 }
             },
+            Expression::Conditional(ref mut conds) => {
+                for cond in conds.conditionals.iter_mut() {
+                    self.expr_with_ttyp(scope, target_type, &mut cond.item, diagnostics)?;
+                    self.boolean_expr(scope, &mut cond.condition, diagnostics)?;
+                }
+                if let Some(ref mut expr) = conds.else_item {
+                    self.expr_with_ttyp(scope, target_type, expr, diagnostics)?;
+                }
+            }
+            Expression::Selected(ref mut selection) => {
+                self.analyze_selected_expression_choices(scope, selection, diagnostics)?;
+                for alternative in selection.alternatives.iter_mut() {
+                    self.expr_with_ttyp(scope, target_type, &mut alternative.item, diagnostics)?;
+                }
+            }
             Expression::New(ref mut alloc) => {
-                self.analyze_allocation(scope, alloc, diagnostics)?;
+                if let Some(designated) =
+                    as_fatal(self.analyze_allocation(scope, alloc, diagnostics))?
+                {
+                    let is_ok = match target_base.kind() {
+                        Type::Access(subtype) => {
+                            self.can_be_target_type(designated, subtype.base())
+                        }
+                        _ => false,
+                    };
+
+                    if !is_ok {
+                        diagnostics.push(Diagnostic::type_mismatch(
+                            expr_pos,
+                            &format!("allocator of {}", designated.describe()),
+                            target_type,
+                        ));
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Analyze the selector and the choices of a selected expression
+    /// which must cover the selector type as for a case statement
+    fn analyze_selected_expression_choices(
+        &self,
+        scope: &Scope<'a>,
+        selection: &mut Selection<WithPos<Expression>>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let Selection {
+            expression,
+            alternatives,
+        } = selection;
+        let ctyp = as_fatal(self.expr_unambiguous_type(scope, expression, diagnostics))?;
+        for alternative in alternatives.iter_mut() {
+            self.choice_with_ttyp(scope, ctyp, &mut alternative.choices, diagnostics)?;
+        }
+        if let Some(ctyp) = ctyp {
+            self.check_case_choices(expression, ctyp, alternatives, diagnostics);
+        }
+        Ok(())
+    }
+
     pub fn analyze_aggregate(
         &self,
         scope: &Scope<'a>,
@@ -935,6 +1089,35 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// Check the choices of a named array aggregate against the index range of the target
+    fn check_aggregate_choices(
+        &self,
+        target_type: TypeEnt<'a>,
+        index_type: BaseType<'a>,
+        expr_pos: &SrcPos,
+        assocs: &[ElementAssociation],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut choices = Vec::with_capacity(assocs.len());
+        for assoc in assocs.iter() {
+            match assoc {
+                ElementAssociation::Named(named_choices, _) => choices.extend(named_choices),
+                // The index of a positional association depends on the direction of the range
+                ElementAssociation::Positional(_) => return,
+            }
+        }
+
+        self.check_choice_coverage(
+            "Aggregate choice",
+            index_type,
+            choices.into_iter(),
+            target_type.static_range(),
+            expr_pos,
+            &target_type.describe(),
+            diagnostics,
+        );
+    }
+
     pub fn analyze_record_aggregate(
         &self,
         scope: &Scope<'a>,
@@ -1134,6 +1317,7 @@ impl<'a> AnalyzeContext<'a> {
 
         let expr = match assoc {
             ElementAssociation::Named(ref mut choices, ref mut expr) => {
+                let num_choices = choices.len();
                 for choice in choices.iter_mut() {
                     match &mut choice.item {
                         Choice::Expression(index_expr) => {
@@ -1187,7 +1371,12 @@ impl<'a> AnalyzeContext<'a> {
                             }
                         }
                         Choice::Others => {
-                            // @TODO choice must be alone so cannot appear here
+                            if num_choices > 1 {
+                                diagnostics.error(
+                                    &choice.pos,
+                                    "'others' must be the only choice of an element association",
+                                );
+                            }
                             can_be_array = false;
                         }
                     }
@@ -1238,6 +1427,26 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+/// An others choice may only be used in the last element association
+fn check_others_is_last(assocs: &[ElementAssociation], diagnostics: &mut dyn DiagnosticHandler) {
+    let Some((_, init)) = assocs.split_last() else {
+        return;
+    };
+
+    for assoc in init.iter() {
+        if let ElementAssociation::Named(choices, _) = assoc {
+            for choice in choices.iter() {
+                if matches!(choice.item, Choice::Others) {
+                    diagnostics.error(
+                        &choice.pos,
+                        "'others' must be the last choice of an aggregate",
+                    );
+                }
+            }
+        }
+    }
+}
+
 impl Diagnostic {
     fn ambiguous_op<'a>(
         pos: &SrcPos,
@@ -1619,4 +1828,48 @@ function \"+\"(a : integer; b : character) return integer;
             ))
         );
     }
+
+    #[test]
+    fn common_type_of_universal_and_integer() {
+        let test = TestSetup::new();
+        let ctx = test.ctx();
+        let integer = test.lookup_type("integer");
+        let universal = DisambiguatedType::Unambiguous(ctx.universal_integer().into());
+
+        assert_eq!(
+            ctx.common_type(universal, DisambiguatedType::Unambiguous(integer)),
+            Some(integer)
+        );
+        assert_eq!(
+            ctx.common_type(
+                DisambiguatedType::Unambiguous(integer),
+                DisambiguatedType::Ambiguous(FnvHashSet::from_iter([
+                    ctx.universal_integer(),
+                    test.lookup_type("real").base()
+                ]))
+            ),
+            Some(integer)
+        );
+    }
+
+    #[test]
+    fn common_type_of_incompatible_types() {
+        let test = TestSetup::new();
+        let ctx = test.ctx();
+
+        assert_eq!(
+            ctx.common_type(
+                DisambiguatedType::Unambiguous(test.lookup_type("integer")),
+                DisambiguatedType::Unambiguous(test.lookup_type("real"))
+            ),
+            None
+        );
+        assert_eq!(
+            ctx.common_type(
+                DisambiguatedType::Unambiguous(ctx.universal_real().into()),
+                DisambiguatedType::Unambiguous(test.lookup_type("integer"))
+            ),
+            None
+        );
+    }
 }
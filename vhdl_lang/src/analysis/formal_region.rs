@@ -211,8 +211,12 @@ impl<'a> RecordElement<'a> {
     }
 
     pub fn type_mark(&self) -> TypeEnt<'a> {
+        self.subtype().type_mark()
+    }
+
+    pub fn subtype(&self) -> Subtype<'a> {
         match self.ent.kind() {
-            AnyEntKind::ElementDeclaration(subtype) => subtype.type_mark(),
+            AnyEntKind::ElementDeclaration(subtype) => *subtype,
             _ => {
                 unreachable!();
             }
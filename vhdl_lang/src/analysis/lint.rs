@@ -0,0 +1,121 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Project specific checks that run after semantic analysis
+
+use super::named_entity::*;
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::data::*;
+
+/// A custom check of an analyzed design unit
+pub trait LintRule: Send + Sync {
+    /// The name used to identify the rule
+    fn name(&self) -> &str;
+
+    /// Check the named entities declared within a design unit,
+    /// including the design unit itself
+    fn check_unit(&self, declarations: &[EntRef], diagnostics: &mut dyn DiagnosticHandler);
+}
+
+/// The rules that are provided by vhdl_lang, none of them are enabled by default
+pub fn builtin_lint_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(LowercaseSignalNames),
+        Box::new(NoConcurrentVariables),
+    ]
+}
+
+/// Signal names must not contain upper case letters
+pub struct LowercaseSignalNames;
+
+impl LintRule for LowercaseSignalNames {
+    fn name(&self) -> &str {
+        "lowercase_signal_names"
+    }
+
+    fn check_unit(&self, declarations: &[EntRef], diagnostics: &mut dyn DiagnosticHandler) {
+        for ent in declarations.iter() {
+            let AnyEntKind::Object(obj) = ent.kind() else {
+                continue;
+            };
+
+            if obj.class != ObjectClass::Signal {
+                continue;
+            }
+
+            if let Designator::Identifier(ref sym) = ent.designator() {
+                if sym.name().bytes.iter().any(u8::is_ascii_uppercase) {
+                    if let Some(pos) = ent.decl_pos() {
+                        diagnostics.warning(
+                            pos,
+                            format!("Signal name '{}' must be lower case", ent.designator()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Variables must not be declared in a concurrent region
+pub struct NoConcurrentVariables;
+
+impl LintRule for NoConcurrentVariables {
+    fn name(&self) -> &str {
+        "no_concurrent_variables"
+    }
+
+    fn check_unit(&self, declarations: &[EntRef], diagnostics: &mut dyn DiagnosticHandler) {
+        for ent in declarations.iter() {
+            let AnyEntKind::Object(obj) = ent.kind() else {
+                continue;
+            };
+
+            if !matches!(
+                obj.class,
+                ObjectClass::Variable | ObjectClass::SharedVariable
+            ) {
+                continue;
+            }
+
+            let is_concurrent = ent.parent.is_some_and(|parent| {
+                matches!(
+                    parent.kind(),
+                    AnyEntKind::Design(Design::Entity(..) | Design::Architecture(..))
+                        | AnyEntKind::Concurrent(Some(Concurrent::Block | Concurrent::Generate))
+                )
+            });
+
+            if is_concurrent {
+                if let Some(pos) = ent.decl_pos() {
+                    diagnostics.warning(
+                        pos,
+                        format!(
+                            "{} must not be declared in a concurrent region",
+                            ent.describe()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collect the declarations of a design unit for the lint rules
+#[derive(Default)]
+pub(super) struct FindAllDeclarations {
+    pub ids: Vec<EntityId>,
+}
+
+impl Searcher for FindAllDeclarations {
+    fn search_decl(&mut self, decl: FoundDeclaration) -> SearchState {
+        if let Some(id) = decl.ent_id() {
+            self.ids.push(id);
+        }
+        SearchState::NotFinished
+    }
+}
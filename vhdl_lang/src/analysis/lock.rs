@@ -54,19 +54,6 @@ impl<T, R> AnalysisLock<T, R> {
         guard.result = None;
     }
 
-    /// Returns an immmutable reference to the data and result.
-    ///
-    /// Panics if the analysis result is not available.
-    pub fn expect_analyzed(&self) -> ReadGuard<T, R> {
-        let guard = self.state.read();
-
-        if guard.result.is_none() {
-            panic!("Expected analysis to have already been done");
-        }
-
-        ReadGuard { guard }
-    }
-
     /// Creates a view into this lock.
     ///
     /// This view provides:
@@ -13,13 +13,16 @@ use crate::ast::{
 use crate::data::*;
 
 mod types;
-pub use types::{BaseType, Subtype, Type, TypeEnt, TypedSelection, UniversalType};
+pub use types::{
+    BaseType, GenericRange, RangeBound, StaticRange, Subtype, Type, TypeEnt, TypedSelection,
+    UniversalType,
+};
 
 mod overloaded;
 pub use overloaded::{Overloaded, OverloadedEnt, Signature, SignatureKey};
 
 mod object;
-pub use object::{Object, ObjectEnt, ObjectInterface};
+pub use object::{ModeView, ModeViewEnt, Object, ObjectEnt, ObjectInterface};
 
 mod design;
 pub use design::{Design, DesignEnt};
@@ -43,12 +46,13 @@ pub enum AnyEntKind<'a> {
     Overloaded(Overloaded<'a>),
     Type(Type<'a>),
     ElementDeclaration(Subtype<'a>),
-    Concurrent(Option<Concurrent>),
+    Concurrent(Option<Concurrent<'a>>),
     Sequential(Option<Sequential>),
     Object(Object<'a>),
     LoopParameter(Option<BaseType<'a>>),
     PhysicalLiteral(TypeEnt<'a>),
     DeferredConstant(Subtype<'a>),
+    View(ModeView<'a>),
     Library,
     Design(Design<'a>),
 }
@@ -110,6 +114,7 @@ impl<'a> AnyEntKind<'a> {
             Object(object) => object.class.describe(),
             PhysicalLiteral(..) => "physical literal",
             DeferredConstant(..) => "deferred constant",
+            View(..) => "view",
             Library => "library",
             Design(design) => design.describe(),
             Type(typ) => typ.describe(),
@@ -336,6 +341,23 @@ impl<'a> AnyEnt<'a> {
         }
     }
 
+    /// The fully qualified name such as lib.pkg.name
+    /// Anonymous regions such as unlabeled processes are omitted
+    pub fn full_name(&self) -> String {
+        let mut names = Vec::new();
+        let mut ent = Some(self);
+
+        while let Some(next) = ent {
+            if !matches!(next.designator(), Designator::Anonymous(_)) {
+                names.push(next.designator().to_string());
+            }
+            ent = next.parent;
+        }
+
+        names.reverse();
+        names.join(".")
+    }
+
     pub fn kind(&self) -> &AnyEntKind {
         &self.kind
     }
@@ -495,20 +517,21 @@ impl SubprogramDeclaration {
 }
 
 #[derive(Copy, Clone, Debug)]
-pub enum Concurrent {
+pub enum Concurrent<'a> {
     Block,
     Process,
     Generate,
-    Instance,
+    /// The instantiated entity or component when it is known
+    Instance(Option<EntRef<'a>>),
 }
 
-impl Concurrent {
+impl<'a> Concurrent<'a> {
     fn describe(&self) -> &'static str {
         match self {
             Concurrent::Block => "block",
             Concurrent::Process => "process",
             Concurrent::Generate => "generate",
-            Concurrent::Instance => "instance",
+            Concurrent::Instance(_) => "instance",
         }
     }
 }
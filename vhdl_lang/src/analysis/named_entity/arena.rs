@@ -78,6 +78,10 @@ impl LocalArena {
         std::mem::transmute(std::pin::Pin::into_inner(item) as *mut AnyEnt)
     }
 
+    fn iter(&self) -> impl Iterator<Item = &AnyEnt<'static>> {
+        (0..self.items.len()).map(|idx| std::pin::Pin::into_inner(self.items.get(idx).unwrap()))
+    }
+
     fn panic_on_missing(&self, id: LocalId) {
         if (id.0 as usize) < self.items.len() {
             return;
@@ -111,6 +115,13 @@ impl<'a> FinalArena {
         }
     }
 
+    /// Iterate over the entities of all arenas in no particular order
+    pub fn iter(&'a self) -> impl Iterator<Item = EntRef<'a>> {
+        self.refs
+            .values()
+            .flat_map(|arena| arena.iter().map(|ent| ent as EntRef<'a>))
+    }
+
     pub fn link(&mut self, referenced: &FinalArena) {
         for (id, arena) in referenced.refs.iter() {
             self.refs.entry(*id).or_insert_with(|| arena.clone());
@@ -9,6 +9,7 @@ use std::ops::Deref;
 use super::AnyEnt;
 use super::AnyEntKind;
 use super::EntRef;
+use crate::analysis::formal_region::InterfaceEnt;
 use crate::analysis::region::NamedEntities;
 use crate::analysis::region::Region;
 use crate::analysis::visibility::Visibility;
@@ -21,7 +22,7 @@ use crate::SrcPos;
 
 pub enum Design<'a> {
     Entity(Visibility<'a>, Region<'a>),
-    Architecture(DesignEnt<'a>),
+    Architecture(Visibility<'a>, Region<'a>, DesignEnt<'a>),
     Configuration,
     Package(Visibility<'a>, Region<'a>),
     PackageBody,
@@ -87,6 +88,22 @@ impl<'a> DesignEnt<'a> {
             _ => Err(Diagnostic::invalid_selected_name_prefix(self, prefix_pos)),
         }
     }
+
+    /// The generics of an entity in declaration order
+    pub fn generics(&self) -> Vec<InterfaceEnt<'a>> {
+        match self.0.kind() {
+            AnyEntKind::Design(Design::Entity(_, region)) => region.to_entity_formal().0.entities,
+            _ => Vec::new(),
+        }
+    }
+
+    /// The ports of an entity in declaration order
+    pub fn ports(&self) -> Vec<InterfaceEnt<'a>> {
+        match self.0.kind() {
+            AnyEntKind::Design(Design::Entity(_, region)) => region.to_entity_formal().1.entities,
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl<'a> From<DesignEnt<'a>> for EntRef<'a> {
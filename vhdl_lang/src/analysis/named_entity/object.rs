@@ -10,6 +10,7 @@ use crate::analysis::AnyEntKind;
 use crate::ast::InterfaceType;
 use crate::ast::Mode;
 use crate::ast::ObjectClass;
+use crate::ast::SignalKind;
 
 use super::AnyEnt;
 use super::EntRef;
@@ -63,6 +64,17 @@ impl<'a> ObjectEnt<'a> {
                     }
                 }
                 ObjectInterface::Port(mode) => format!("port '{}' : {}", self.designator(), mode),
+                ObjectInterface::ModeView(typ, view) => match typ {
+                    InterfaceType::Port => {
+                        format!("port '{}' : view {}", self.designator(), view.designator())
+                    }
+                    _ => format!(
+                        "{} '{}' : view {}",
+                        self.class(),
+                        self.designator(),
+                        view.designator()
+                    ),
+                },
             }
         } else {
             self.describe_name()
@@ -83,13 +95,15 @@ impl<'a> ObjectEnt<'a> {
 }
 
 #[derive(Copy, Clone)]
-pub enum ObjectInterface {
+pub enum ObjectInterface<'a> {
     Generic,
     Port(Mode),
     Parameter(Mode),
+    /// A VHDL-2019 port or parameter where the mode of each element is given by a mode view
+    ModeView(InterfaceType, ModeViewEnt<'a>),
 }
 
-impl ObjectInterface {
+impl<'a> ObjectInterface<'a> {
     pub fn new(typ: InterfaceType, mode: Mode) -> Self {
         match typ {
             // @TODO error on non-input mode
@@ -103,6 +117,16 @@ impl ObjectInterface {
         match self {
             ObjectInterface::Generic => Mode::In,
             ObjectInterface::Parameter(m) | ObjectInterface::Port(m) => *m,
+            // Each element may have a different mode
+            ObjectInterface::ModeView(..) => Mode::InOut,
+        }
+    }
+
+    pub fn mode_view(&self) -> Option<ModeViewEnt<'a>> {
+        if let ObjectInterface::ModeView(_, view) = self {
+            Some(*view)
+        } else {
+            None
         }
     }
 
@@ -111,6 +135,7 @@ impl ObjectInterface {
             ObjectInterface::Generic => InterfaceType::Generic,
             ObjectInterface::Parameter(..) => InterfaceType::Parameter,
             ObjectInterface::Port(..) => InterfaceType::Port,
+            ObjectInterface::ModeView(typ, _) => *typ,
         }
     }
 }
@@ -121,9 +146,11 @@ impl ObjectInterface {
 #[derive(Clone)]
 pub struct Object<'a> {
     pub class: ObjectClass,
-    pub iface: Option<ObjectInterface>,
+    pub iface: Option<ObjectInterface<'a>>,
     pub subtype: Subtype<'a>,
     pub has_default: bool,
+    /// Register and bus signals are guarded signals
+    pub signal_kind: Option<SignalKind>,
 }
 
 impl<'a> Object<'a> {
@@ -133,6 +160,7 @@ impl<'a> Object<'a> {
             iface: Some(ObjectInterface::Parameter(Mode::In)),
             subtype,
             has_default: false,
+            signal_kind: None,
         }
     }
 
@@ -142,7 +170,7 @@ impl<'a> Object<'a> {
     }
 
     pub fn is_port(&self) -> bool {
-        matches!(self.iface, Some(ObjectInterface::Port(..)))
+        self.iface.map(|i| i.typ()) == Some(InterfaceType::Port)
     }
 
     pub fn is_generic(&self) -> bool {
@@ -150,7 +178,7 @@ impl<'a> Object<'a> {
     }
 
     pub fn is_param(&self) -> bool {
-        matches!(self.iface, Some(ObjectInterface::Parameter(_)))
+        self.iface.map(|i| i.typ()) == Some(InterfaceType::Parameter)
     }
 
     pub fn mode(&self) -> Option<Mode> {
@@ -158,6 +186,54 @@ impl<'a> Object<'a> {
     }
 }
 
+/// A VHDL-2019 mode view of a record type
+#[derive(Clone)]
+pub struct ModeView<'a> {
+    pub typ: TypeEnt<'a>,
+    /// The record elements and their modes
+    pub elements: Vec<(EntRef<'a>, Mode)>,
+}
+
+impl<'a> ModeView<'a> {
+    pub fn element_mode(&self, elem: EntRef<'a>) -> Option<Mode> {
+        self.elements
+            .iter()
+            .find(|(ent, _)| ent.id() == elem.id())
+            .map(|(_, mode)| *mode)
+    }
+}
+
+// A named entity that is known to be a mode view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModeViewEnt<'a> {
+    pub ent: EntRef<'a>,
+}
+
+impl<'a> ModeViewEnt<'a> {
+    pub fn from_any(ent: &'a AnyEnt) -> Option<Self> {
+        if matches!(ent.actual_kind(), AnyEntKind::View(..)) {
+            Some(Self { ent })
+        } else {
+            None
+        }
+    }
+
+    pub fn view(&self) -> &'a ModeView<'a> {
+        if let AnyEntKind::View(view) = self.ent.actual_kind() {
+            view
+        } else {
+            unreachable!("ModeViewEnt type invariant broken")
+        }
+    }
+}
+
+impl<'a> Deref for ModeViewEnt<'a> {
+    type Target = EntRef<'a>;
+    fn deref(&self) -> &Self::Target {
+        &self.ent
+    }
+}
+
 impl ObjectClass {
     pub fn describe(&self) -> &str {
         use ObjectClass::*;
@@ -11,12 +11,12 @@ use crate::analysis::named_entity::{AnyEnt, AnyEntKind, EntityId};
 use crate::analysis::formal_region::{RecordElement, RecordRegion};
 use crate::analysis::region::{NamedEntities, OverloadedName, Region};
 use crate::ast::WithDecl;
-use crate::ast::{Designator, WithRef};
+use crate::ast::{Designator, Direction, WithRef};
 use crate::ast::{HasDesignator, Ident};
 use crate::data::WithPos;
 use crate::{Diagnostic, SrcPos};
 
-use fnv::FnvHashSet;
+use fnv::{FnvHashMap, FnvHashSet};
 
 use super::{Arena, EntRef, Related};
 
@@ -26,10 +26,15 @@ pub enum Type<'a> {
     Array {
         // Indexes are Option<> to handle unknown types
         indexes: Vec<Option<BaseType<'a>>>,
+        // The static ranges of the index subtypes
+        index_ranges: Vec<Option<StaticRange>>,
         elem_type: TypeEnt<'a>,
+        // Declared with an index constraint rather than range <>
+        is_constrained: bool,
     },
     Enum(FnvHashSet<Designator>),
-    Integer,
+    // The static range of the type definition if known
+    Integer(Option<StaticRange>),
     Real,
     Physical,
     Access(Subtype<'a>),
@@ -58,7 +63,7 @@ impl<'a> Type<'a> {
             Type::Record(..) => "record type",
             Type::Array { .. } => "array type",
             Type::Enum(..) => "type",
-            Type::Integer => "integer type",
+            Type::Integer(..) => "integer type",
             Type::Real => "real type",
             Type::Physical => "physical type",
             Type::Access(..) => "access type",
@@ -153,6 +158,13 @@ impl<'a> TypeEnt<'a> {
         BaseType::from(*self)
     }
 
+    /// The predefined operators, functions and enumeration literals declared
+    /// implicitly by the type declaration
+    pub fn implicit_declarations(&self) -> impl Iterator<Item = EntRef<'a>> {
+        let ent: EntRef<'a> = self.base_type().0;
+        ent.implicits.iter().copied()
+    }
+
     pub fn accessed_type(&self) -> Option<TypeEnt<'a>> {
         self.base().accessed_type()
     }
@@ -165,10 +177,53 @@ impl<'a> TypeEnt<'a> {
         self.base().is_scalar()
     }
 
+    pub fn is_unconstrained_array(&self) -> bool {
+        match self.kind() {
+            Type::Array { is_constrained, .. } => !is_constrained,
+            Type::Subtype(subtype) => subtype.is_unconstrained_array(),
+            Type::Alias(alias) => alias.is_unconstrained_array(),
+            _ => false,
+        }
+    }
+
     pub fn sliced_as(&self) -> Option<TypeEnt<'a>> {
         self.base().sliced_as()
     }
 
+    /// The statically known range of a scalar type
+    pub fn static_range(&self) -> Option<StaticRange> {
+        match self.kind() {
+            Type::Subtype(subtype) => subtype.static_range(),
+            Type::Alias(alias) => alias.static_range(),
+            Type::Integer(range) => *range,
+            Type::Enum(literals) => Some(StaticRange::new(
+                0,
+                literals.len() as i64 - 1,
+                Direction::Ascending,
+            )),
+            _ => None,
+        }
+    }
+
+    /// The type whose declarations are selected from a prefix of this type
+    /// Subtypes, aliases and access types select from the type they denote and
+    /// the body of a protected type only selects the methods of its declaration
+    fn selected_from(self) -> TypeEnt<'a> {
+        match self.kind() {
+            Type::Protected(_, true) => match TypeEnt::from_any(self.0.declaration()) {
+                Some(ptype) if ptype.id() != self.id() => ptype.selected_from(),
+                _ => self,
+            },
+            Type::Subtype(subtype) => subtype
+                .elements
+                .unwrap_or(subtype.type_mark())
+                .selected_from(),
+            Type::Access(subtype, ..) => subtype.type_mark().selected_from(),
+            Type::Alias(alias) => alias.selected_from(),
+            _ => self,
+        }
+    }
+
     /// Lookup a selected name prefix.suffix
     /// where prefix has this type
     pub fn selected(
@@ -176,13 +231,14 @@ impl<'a> TypeEnt<'a> {
         prefix_pos: &SrcPos,
         suffix: &WithPos<WithRef<Designator>>,
     ) -> Result<TypedSelection<'a>, Diagnostic> {
-        match self.kind() {
+        let typ = self.selected_from();
+        match typ.kind() {
             Type::Record(ref region) => {
                 if let Some(decl) = region.lookup(suffix.designator()) {
                     Ok(TypedSelection::RecordElement(decl))
                 } else {
                     Err(Diagnostic::no_declaration_within(
-                        &self,
+                        &typ,
                         &suffix.pos,
                         &suffix.item.item,
                     ))
@@ -204,7 +260,7 @@ impl<'a> TypeEnt<'a> {
                     }
                 } else {
                     Err(Diagnostic::no_declaration_within(
-                        &self,
+                        &typ,
                         &suffix.pos,
                         &suffix.item.item,
                     ))
@@ -214,17 +270,34 @@ impl<'a> TypeEnt<'a> {
                 prefix_pos,
                 "Cannot select incomplete type before full type definition",
             )),
-            Type::Subtype(subtype) => subtype.type_mark().selected(prefix_pos, suffix),
-            Type::Access(subtype, ..) => subtype.type_mark().selected(prefix_pos, suffix),
-            Type::Alias(alias) => alias.selected(prefix_pos, suffix),
-            Type::Array { .. }
+            Type::Subtype(..)
+            | Type::Access(..)
+            | Type::Alias(..)
+            | Type::Array { .. }
             | Type::File { .. }
             | Type::Interface { .. }
             | Type::Enum { .. }
             | Type::Physical { .. }
             | Type::Universal { .. }
-            | Type::Integer { .. }
-            | Type::Real { .. } => Err(Diagnostic::invalid_selected_name_prefix(&self, prefix_pos)),
+            | Type::Integer(..)
+            | Type::Real { .. } => Err(Diagnostic::invalid_selected_name_prefix(&typ, prefix_pos)),
+        }
+    }
+
+    /// All the suffixes that may be selected from a prefix of this type
+    /// such as record elements and protected type methods
+    pub fn selectable(self) -> Vec<EntRef<'a>> {
+        match self.selected_from().kind() {
+            Type::Record(ref region) => region.iter().map(|elem| elem.into()).collect(),
+            Type::Protected(region, _) => {
+                let mut methods: Vec<_> = region
+                    .immediates()
+                    .filter(|ent| matches!(ent.kind(), AnyEntKind::Overloaded(..)))
+                    .collect();
+                methods.sort_by_key(|ent| ent.decl_pos());
+                methods
+            }
+            _ => Vec::new(),
         }
     }
 
@@ -286,10 +359,27 @@ impl<'a> BaseType<'a> {
         matches!(self.kind(), Type::Enum { .. })
     }
 
+    /// The enumeration literals in declaration order
+    pub fn enum_literals(&self) -> impl Iterator<Item = EntRef<'a>> {
+        self.0.implicits.iter().copied().filter(|ent| {
+            matches!(
+                ent.kind(),
+                AnyEntKind::Overloaded(super::Overloaded::EnumLiteral(_))
+            )
+        })
+    }
+
+    /// The position of an enumeration literal within this type
+    pub fn enum_literal_pos(&self, literal: EntRef<'a>) -> Option<i64> {
+        self.enum_literals()
+            .position(|ent| ent.id() == literal.id())
+            .map(|pos| pos as i64)
+    }
+
     pub fn is_any_integer(&self) -> bool {
         matches!(
             self.kind(),
-            Type::Integer | Type::Universal(UniversalType::Integer)
+            Type::Integer(..) | Type::Universal(UniversalType::Integer)
         )
     }
 
@@ -307,7 +397,7 @@ impl<'a> BaseType<'a> {
     pub fn is_scalar(&self) -> bool {
         matches!(
             self.kind(),
-            Type::Enum(_) | Type::Integer | Type::Real | Type::Physical | Type::Universal(_)
+            Type::Enum(_) | Type::Integer(..) | Type::Real | Type::Physical | Type::Universal(_)
         )
     }
 
@@ -324,7 +414,7 @@ impl<'a> BaseType<'a> {
 
     pub fn is_universal_of(&self, other: BaseType<'a>) -> bool {
         let i = matches!(self.kind(), Type::Universal(UniversalType::Integer))
-            && matches!(other.kind(), Type::Integer);
+            && matches!(other.kind(), Type::Integer(..));
 
         let r = matches!(self.kind(), Type::Universal(UniversalType::Real))
             && matches!(other.kind(), Type::Real);
@@ -370,7 +460,7 @@ impl<'a> BaseType<'a> {
     pub fn is_discrete(&self) -> bool {
         matches!(
             self.kind(),
-            Type::Integer
+            Type::Integer(..)
                 | Type::Enum(_)
                 | Type::Universal(UniversalType::Integer)
                 | Type::Physical
@@ -393,14 +483,28 @@ impl<'a> BaseType<'a> {
         if let Type::Array {
             indexes: my_indexes,
             elem_type: my_elem_type,
+            ..
         } = self.kind()
         {
             if let Type::Array {
                 indexes: other_indexes,
                 elem_type: other_elem_type,
+                ..
             } = other.kind()
             {
+                // The bounds of the operand are converted to the index types of the target
+                let indexes_related = my_indexes.iter().zip(other_indexes.iter()).all(
+                    |(my_index, other_index)| match (my_index, other_index) {
+                        (Some(my_index), Some(other_index)) => {
+                            my_index.is_closely_related(*other_index)
+                        }
+                        // Unknown index types have already been reported
+                        _ => true,
+                    },
+                );
+
                 return my_indexes.len() == other_indexes.len()
+                    && indexes_related
                     && my_elem_type
                         .base()
                         .is_closely_related(other_elem_type.base());
@@ -430,20 +534,151 @@ impl<'a> Deref for BaseType<'a> {
     }
 }
 
+/// A statically known scalar range
+/// The bounds of an enumeration type are the positions of its literals
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticRange {
+    pub left: i64,
+    pub right: i64,
+    pub direction: Direction,
+}
+
+impl StaticRange {
+    pub fn new(left: i64, right: i64, direction: Direction) -> StaticRange {
+        StaticRange {
+            left,
+            right,
+            direction,
+        }
+    }
+
+    pub fn low(&self) -> i64 {
+        match self.direction {
+            Direction::Ascending => self.left,
+            Direction::Descending => self.right,
+        }
+    }
+
+    pub fn high(&self) -> i64 {
+        match self.direction {
+            Direction::Ascending => self.right,
+            Direction::Descending => self.left,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.low() > self.high()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.low() <= value && value <= self.high()
+    }
+
+    pub fn length(&self) -> i64 {
+        if self.is_null() {
+            0
+        } else {
+            self.high() - self.low() + 1
+        }
+    }
+}
+
+/// A range bound that is either static or a generic constant plus an offset such as N - 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeBound {
+    Static(i64),
+    Generic(EntityId, i64),
+}
+
+impl RangeBound {
+    fn evaluate(&self, values: &FnvHashMap<EntityId, i64>) -> Option<i64> {
+        match self {
+            RangeBound::Static(value) => Some(*value),
+            RangeBound::Generic(id, offset) => values.get(id)?.checked_add(*offset),
+        }
+    }
+}
+
+/// A range that depends on generic constants of a generic package
+/// It becomes static when the package is instantiated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenericRange {
+    pub left: RangeBound,
+    pub right: RangeBound,
+    pub direction: Direction,
+}
+
+impl GenericRange {
+    pub fn evaluate(&self, values: &FnvHashMap<EntityId, i64>) -> Option<StaticRange> {
+        Some(StaticRange::new(
+            self.left.evaluate(values)?,
+            self.right.evaluate(values)?,
+            self.direction,
+        ))
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Subtype<'a> {
     pub(crate) type_mark: TypeEnt<'a>,
+    // The static range constraint of a scalar subtype
+    // or of the first index of an array subtype
+    pub(crate) range: Option<StaticRange>,
+    // The range constraint when it depends on generic constants
+    pub(crate) generic_range: Option<GenericRange>,
+    // An anonymous record type with the element constraints applied
+    pub(crate) elements: Option<TypeEnt<'a>>,
+    // Has an index constraint of an array subtype
+    pub(crate) is_constrained: bool,
 }
 
 impl<'a> Subtype<'a> {
     pub fn new(type_mark: TypeEnt<'a>) -> Subtype<'a> {
-        Subtype { type_mark }
+        Subtype {
+            type_mark,
+            range: None,
+            generic_range: None,
+            elements: None,
+            is_constrained: false,
+        }
+    }
+
+    pub fn with_range(self, range: Option<StaticRange>) -> Subtype<'a> {
+        Subtype { range, ..self }
+    }
+
+    pub fn with_generic_range(self, generic_range: Option<GenericRange>) -> Subtype<'a> {
+        Subtype {
+            generic_range,
+            ..self
+        }
+    }
+
+    pub fn with_elements(self, elements: Option<TypeEnt<'a>>) -> Subtype<'a> {
+        Subtype { elements, ..self }
+    }
+
+    pub fn with_index_constraint(self) -> Subtype<'a> {
+        Subtype {
+            is_constrained: true,
+            ..self
+        }
+    }
+
+    /// An array subtype where the index ranges are not known until an object is created
+    pub fn is_unconstrained_array(&self) -> bool {
+        !self.is_constrained && self.type_mark.is_unconstrained_array()
     }
 
     pub fn type_mark(&self) -> TypeEnt<'a> {
         self.type_mark
     }
 
+    /// The range of the constraint if any otherwise the range of the type mark
+    pub fn static_range(&self) -> Option<StaticRange> {
+        self.range.or_else(|| self.type_mark.static_range())
+    }
+
     pub fn base_type(&self) -> TypeEnt<'a> {
         self.type_mark.base_type()
     }
@@ -13,8 +13,10 @@ use super::overloaded::Disambiguated;
 use super::overloaded::DisambiguatedType;
 use super::overloaded::SubprogramKind;
 use super::region::*;
+use crate::ast::Range;
 use crate::ast::*;
 use crate::data::*;
+use crate::VhdlStandard;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ObjectBase<'a> {
@@ -22,6 +24,8 @@ pub enum ObjectBase<'a> {
     ObjectAlias(ObjectEnt<'a>, EntRef<'a>),
     DeferredConstant(EntRef<'a>),
     ExternalName(ExternalObjectClass),
+    /// An element of a port or parameter with a mode view
+    ViewElement(ObjectEnt<'a>, Mode),
 }
 
 impl<'a> ObjectBase<'a> {
@@ -31,12 +35,13 @@ impl<'a> ObjectBase<'a> {
             ObjectBase::ObjectAlias(object, _) => object.mode(),
             ObjectBase::DeferredConstant(..) => None,
             ObjectBase::ExternalName(_) => None,
+            ObjectBase::ViewElement(_, mode) => Some(*mode),
         }
     }
 
     pub fn class(&self) -> ObjectClass {
         match self {
-            ObjectBase::Object(object) => object.class(),
+            ObjectBase::Object(object) | ObjectBase::ViewElement(object, _) => object.class(),
             ObjectBase::ObjectAlias(object, _) => object.class(),
             ObjectBase::DeferredConstant(..) => ObjectClass::Constant,
             ObjectBase::ExternalName(class) => (*class).into(),
@@ -45,7 +50,13 @@ impl<'a> ObjectBase<'a> {
 
     // Use whenever the class and mode is relevant to the error
     pub fn describe_class(&self) -> String {
-        if let Some(mode) = self.mode() {
+        if let ObjectBase::ViewElement(obj, mode) = self {
+            format!(
+                "element of interface {} of mode {}",
+                obj.describe_name(),
+                mode
+            )
+        } else if let Some(mode) = self.mode() {
             if self.class() == ObjectClass::Constant {
                 format!("interface {}", self.describe())
             } else {
@@ -66,12 +77,13 @@ impl<'a> ObjectBase<'a> {
             ObjectBase::ObjectAlias(_, alias) => {
                 format!("alias '{}' of {}", alias.designator(), self.class())
             }
+            ObjectBase::ViewElement(obj, _) => format!("element of {}", obj.describe_name()),
         }
     }
 
     pub fn is_port(&self) -> bool {
         match self {
-            ObjectBase::Object(obj) => obj.kind().is_port(),
+            ObjectBase::Object(obj) | ObjectBase::ViewElement(obj, _) => obj.kind().is_port(),
             ObjectBase::ObjectAlias(obj, _) => obj.kind().is_port(),
             ObjectBase::DeferredConstant(_) => false,
             ObjectBase::ExternalName(_) => false,
@@ -156,6 +168,10 @@ impl<'a> ResolvedName<'a> {
                 type_mark: Some(subtype.type_mark()),
             }),
             AnyEntKind::Type(_) => ResolvedName::Type(TypeEnt::from_any(ent).unwrap()),
+            // Packages declared or instantiated within a package
+            AnyEntKind::Design(
+                Design::Package(..) | Design::UninstPackage(..) | Design::PackageInstance(_),
+            ) => ResolvedName::Design(DesignEnt::from_any(ent).unwrap()),
             AnyEntKind::Overloaded(_) => {
                 return Err(
                     "Internal error. Unreachable as overloaded is handled outside".to_owned(),
@@ -164,7 +180,8 @@ impl<'a> ResolvedName<'a> {
             AnyEntKind::File(_)
             | AnyEntKind::InterfaceFile(_)
             | AnyEntKind::Component(_)
-            | AnyEntKind::PhysicalLiteral(_) => ResolvedName::Final(ent),
+            | AnyEntKind::PhysicalLiteral(_)
+            | AnyEntKind::View(_) => ResolvedName::Final(ent),
             AnyEntKind::Design(_)
             | AnyEntKind::Library
             | AnyEntKind::Attribute(_)
@@ -223,7 +240,8 @@ impl<'a> ResolvedName<'a> {
             | AnyEntKind::Concurrent(_)
             | AnyEntKind::Sequential(_)
             | AnyEntKind::LoopParameter(_)
-            | AnyEntKind::PhysicalLiteral(_) => ResolvedName::Final(ent),
+            | AnyEntKind::PhysicalLiteral(_)
+            | AnyEntKind::View(_) => ResolvedName::Final(ent),
             AnyEntKind::Attribute(_) | AnyEntKind::ElementDeclaration(_) => {
                 return Err(format!(
                     "{} should never be looked up from the current scope",
@@ -240,6 +258,12 @@ impl<'a> ResolvedName<'a> {
     pub fn describe_type(&self) -> String {
         match self {
             ResolvedName::ObjectName(oname) => oname.describe_type(),
+            ResolvedName::Final(ent) => match ent.actual_kind() {
+                AnyEntKind::LoopParameter(Some(typ)) => {
+                    format!("{} of {}", ent.describe(), typ.describe())
+                }
+                _ => ent.describe(),
+            },
             ResolvedName::Expression(DisambiguatedType::Unambiguous(typ)) => {
                 format!("Expression of {}", typ.describe())
             }
@@ -542,7 +566,10 @@ impl<'a> AnalyzeContext<'a> {
             let resolved = as_fatal(self.name_resolve(scope, expr_pos, name, diagnostics))?;
 
             if let Some(ResolvedName::Type(typ)) = resolved {
-                return if matches!(typ.base_type().kind(), Type::Enum { .. } | Type::Integer) {
+                return if matches!(
+                    typ.base_type().kind(),
+                    Type::Enum { .. } | Type::Integer(..)
+                ) {
                     Ok(Some(typ))
                 } else {
                     Err(Diagnostic::error(
@@ -570,6 +597,10 @@ impl<'a> AnalyzeContext<'a> {
     ) -> AnalysisResult<Option<TypeOrMethod<'a>>> {
         match suffix {
             Suffix::Selected(suffix) => {
+                if prefix_typ.array_type().is_some() {
+                    // Let the caller suggest indexing the array
+                    return Ok(None);
+                }
                 Ok(Some(match prefix_typ.selected(prefix_pos, suffix)? {
                     TypedSelection::RecordElement(elem) => {
                         suffix.set_unique_reference(&elem);
@@ -581,7 +612,20 @@ impl<'a> AnalyzeContext<'a> {
                     ),
                 }))
             }
-            Suffix::All => Ok(prefix_typ.accessed_type().map(TypeOrMethod::Type)),
+            Suffix::All => {
+                if let Some(typ) = prefix_typ.accessed_type() {
+                    Ok(Some(TypeOrMethod::Type(typ)))
+                } else {
+                    Err(Diagnostic::error(
+                        prefix_pos,
+                        format!(
+                            "{} is not an access type and cannot be dereferenced with .all",
+                            prefix_typ.describe()
+                        ),
+                    )
+                    .into())
+                }
+            }
             Suffix::Slice(drange) => Ok(if let Some(typ) = prefix_typ.sliced_as() {
                 if let Type::Array { indexes, .. } = typ.kind() {
                     if let [idx_typ] = indexes.as_slice() {
@@ -687,7 +731,7 @@ impl<'a> AnalyzeContext<'a> {
             1
         };
 
-        if let Some(idx_typ) = indexes.get(idx - 1) {
+        if let Some(idx_typ) = idx.checked_sub(1).and_then(|idx| indexes.get(idx)) {
             if let Some(idx_typ) = idx_typ {
                 Ok(*idx_typ)
             } else {
@@ -754,7 +798,21 @@ impl<'a> AnalyzeContext<'a> {
             AttributeDesignator::Image => {
                 let typ = prefix.as_type_of_attr_prefix(prefix_pos, attr, diagnostics)?;
 
-                if let Some(ref mut expr) = check_single_argument(name_pos, attr, diagnostics) {
+                // Since VHDL-2019 obj'image is short for obj'subtype'image(obj)
+                if matches!(prefix, ResolvedName::ObjectName(_)) && attr.expr.is_none() {
+                    if self.standard() < VhdlStandard::VHDL2019 {
+                        diagnostics.error(
+                            name_pos,
+                            format!(
+                                "'image of an object requires VHDL-2019, use {}'image({}) instead",
+                                typ.designator(),
+                                prefix_pos.source.contents().text(prefix_pos.range())
+                            ),
+                        );
+                    }
+                } else if let Some(ref mut expr) =
+                    check_single_argument(name_pos, attr, diagnostics)
+                {
                     self.expr_with_ttyp(scope, typ, expr, diagnostics)?;
                 }
 
@@ -804,6 +862,23 @@ impl<'a> AnalyzeContext<'a> {
                 if typ.base().is_discrete() {
                     if let Some(ref mut expr) = check_single_argument(name_pos, attr, diagnostics) {
                         self.integer_expr(scope, expr, diagnostics)?;
+
+                        // A locally static position must be within the range of the prefix type
+                        if let (Some(range), Some(value)) = (
+                            typ.static_range(),
+                            self.static_discrete_value(self.universal_integer(), &expr.item),
+                        ) {
+                            if !range.contains(value) {
+                                diagnostics.warning(
+                                    &expr.pos,
+                                    format!(
+                                        "Position {} is outside of the range of {}",
+                                        value,
+                                        typ.describe()
+                                    ),
+                                );
+                            }
+                        }
                     }
                     Ok(typ.base())
                 } else {
@@ -919,6 +994,41 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// The type denoted by a 'subtype or 'element attribute
+    fn type_attribute_suffix(
+        &self,
+        prefix_pos: &SrcPos,
+        prefix: &ResolvedName<'a>,
+        tattr: TypeAttribute,
+        attr: &AttributeSuffix,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<TypeEnt<'a>> {
+        let typ = match prefix {
+            ResolvedName::ObjectName(oname) => oname.type_mark(),
+            ResolvedName::Type(typ) if tattr == TypeAttribute::Element => *typ,
+            _ => {
+                diagnostics.push(Diagnostic::cannot_be_prefix_of_attribute(
+                    prefix_pos, prefix, attr,
+                ));
+                return Err(EvalError::Unknown);
+            }
+        };
+
+        match tattr {
+            TypeAttribute::Subtype => Ok(typ),
+            TypeAttribute::Element => {
+                if let Some((elem_type, _)) = typ.array_type() {
+                    Ok(elem_type)
+                } else {
+                    diagnostics.push(Diagnostic::cannot_be_prefix_of_attribute(
+                        prefix_pos, prefix, attr,
+                    ));
+                    Err(EvalError::Unknown)
+                }
+            }
+        }
+    }
+
     pub fn name_resolve(
         &self,
         scope: &Scope<'a>,
@@ -942,8 +1052,12 @@ impl<'a> AnalyzeContext<'a> {
         let prefix;
         let mut resolved = match SplitName::from_name(name) {
             SplitName::Designator(designator) => {
-                let name =
-                    catch_diagnostic(scope.lookup(name_pos, designator.designator()), diagnostics)?;
+                let name = catch_diagnostic(
+                    scope
+                        .lookup(name_pos, designator.designator())
+                        .map_err(|err| self.with_use_clause_hint(err, designator.designator())),
+                    diagnostics,
+                )?;
                 return Ok(match name {
                     NamedEntities::Single(ent) => {
                         designator.set_unique_reference(ent);
@@ -961,7 +1075,14 @@ impl<'a> AnalyzeContext<'a> {
                 });
             }
             SplitName::External(ename) => {
-                let ExternalName { subtype, class, .. } = ename;
+                let ExternalName {
+                    subtype,
+                    class,
+                    path,
+                } = ename;
+                if let ExternalPath::Relative(ref mut path_name, 0) = path.item {
+                    self.resolve_relative_external_path(scope, path_name);
+                }
                 let subtype = catch_analysis_err(
                     self.resolve_subtype_indication(scope, subtype, diagnostics),
                     diagnostics,
@@ -1035,6 +1156,15 @@ impl<'a> AnalyzeContext<'a> {
             }
         }
 
+        // Type attributes yield a type that may be the prefix of further attributes
+        if let Suffix::Attribute(ref mut attr) = suffix {
+            if let AttributeDesignator::Type(tattr) = attr.attr.item {
+                let typ =
+                    self.type_attribute_suffix(&prefix.pos, &resolved, tattr, attr, diagnostics)?;
+                return Ok(ResolvedName::Type(typ));
+            }
+        }
+
         // Attributes for non-types not handled yet
         if let Suffix::Attribute(ref mut attr) = suffix {
             let typ =
@@ -1127,7 +1257,14 @@ impl<'a> AnalyzeContext<'a> {
                     diagnostics,
                 )? {
                     Some(TypeOrMethod::Type(typ)) => {
-                        resolved = ResolvedName::ObjectName(oname.with_suffix(typ));
+                        if let Suffix::Slice(ref drange) = suffix {
+                            self.check_slice_direction(&oname, drange, diagnostics);
+                        }
+                        let mut oname = oname.with_suffix(typ);
+                        if let Some(base) = self.view_element_base(&oname, &suffix) {
+                            oname.base = base;
+                        }
+                        resolved = ResolvedName::ObjectName(oname);
                     }
                     Some(TypeOrMethod::Method(des, name)) => {
                         resolved = ResolvedName::Overloaded(des, name);
@@ -1314,12 +1451,18 @@ impl<'a> AnalyzeContext<'a> {
             match types {
                 ExpressionType::Unambiguous(ctyp) => {
                     if !typ.base().is_closely_related(ctyp.base()) {
+                        let reason = if typ.base().is_enum() && ctyp.base().is_enum() {
+                            ", conversion is not allowed between enumeration types"
+                        } else {
+                            ""
+                        };
                         diagnostics.error(
                             pos,
                             format!(
-                                "{} cannot be converted to {}",
+                                "{} cannot be converted to {}{}",
                                 ctyp.describe(),
-                                typ.describe()
+                                typ.describe(),
+                                reason
                             ),
                         )
                     }
@@ -1367,6 +1510,20 @@ impl<'a> AnalyzeContext<'a> {
         name: &mut Name,
         ttyp: TypeEnt<'a>,
         diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        self.name_with_ttyp(scope, expr_pos, name, ttyp, true, diagnostics)
+    }
+
+    /// Analyze a name that must be unambiguous
+    /// The name is not read when it is the actual of an output
+    pub fn name_with_ttyp(
+        &self,
+        scope: &Scope<'a>,
+        expr_pos: &SrcPos,
+        name: &mut Name,
+        ttyp: TypeEnt<'a>,
+        is_read: bool,
+        diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         if let Some(resolved) = as_fatal(self.name_resolve_with_suffixes(
             scope,
@@ -1376,6 +1533,10 @@ impl<'a> AnalyzeContext<'a> {
             false,
             diagnostics,
         ))? {
+            if is_read {
+                self.check_name_is_readable(expr_pos, &resolved, diagnostics);
+            }
+
             // @TODO target_type already used above, functions could probably be simplified
             match self.name_to_unambiguous_type(
                 expr_pos,
@@ -1401,6 +1562,77 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// A slice with a direction opposite to the array index range is either null or an error
+    fn check_slice_direction(
+        &self,
+        oname: &ObjectName<'a>,
+        drange: &DiscreteRange,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let index_range = match (oname.type_mark, &oname.base) {
+            (None, ObjectBase::Object(obj)) => obj.object().subtype.static_range(),
+            _ => oname.type_mark().static_range(),
+        };
+
+        let slice_direction = match drange {
+            DiscreteRange::Discrete(_, Some(Range::Range(constraint)))
+            | DiscreteRange::Range(Range::Range(constraint)) => constraint.direction,
+            _ => return,
+        };
+
+        if let Some(index_range) = index_range {
+            if index_range.direction != slice_direction {
+                diagnostics.warning(
+                    drange.pos(),
+                    format!(
+                        "Slice direction '{}' does not match the '{}' index range of {}",
+                        slice_direction,
+                        index_range.direction,
+                        oname.base.describe()
+                    ),
+                );
+            }
+        }
+    }
+
+    // A selected element of a port with a mode view gets the mode of the element
+    fn view_element_base(&self, oname: &ObjectName<'a>, suffix: &Suffix) -> Option<ObjectBase<'a>> {
+        let ObjectBase::Object(obj) = oname.base else {
+            return None;
+        };
+        let Suffix::Selected(designator) = suffix else {
+            return None;
+        };
+        let view = obj.object().iface?.mode_view()?;
+        let elem = self.arena.get(designator.item.reference?);
+        let mode = view.view().element_mode(elem)?;
+        Some(ObjectBase::ViewElement(obj, mode))
+    }
+
+    /// Before VHDL-2008 an object of mode out may not be read
+    fn check_name_is_readable(
+        &self,
+        pos: &SrcPos,
+        name: &ResolvedName<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if self.standard() >= VhdlStandard::VHDL2008 {
+            return;
+        }
+
+        if let ResolvedName::ObjectName(oname) = name {
+            if oname.base.mode() == Some(Mode::Out) {
+                diagnostics.error(
+                    pos,
+                    format!(
+                        "{} cannot be read before VHDL-2008",
+                        capitalize(&oname.base.describe_class())
+                    ),
+                );
+            }
+        }
+    }
+
     /// Analyze an indexed name where the prefix entity is already known
     /// Returns the type of the array element
     pub fn analyze_indexed_name(
@@ -1521,15 +1753,136 @@ impl<'a> AnalyzeContext<'a> {
                 )))
             }
             SelectedName::Designator(ref mut designator) => {
-                let visible = scope.lookup(&name.pos, designator.designator())?;
+                let visible = scope
+                    .lookup(&name.pos, designator.designator())
+                    .map_err(|err| self.with_use_clause_hint(err, designator.designator()))?;
                 designator.set_reference(&visible);
                 Ok(visible)
             }
         }
     }
+
+    /// Point out the packages that declare a name which is not visible
+    fn with_use_clause_hint(
+        &self,
+        mut diagnostic: Diagnostic,
+        designator: &Designator,
+    ) -> Diagnostic {
+        let Designator::Identifier(name) = designator else {
+            return diagnostic;
+        };
+        // A name hidden by conflicting use clauses already lists the conflicts
+        if !diagnostic.related.is_empty() {
+            return diagnostic;
+        }
+        let work = self.work_library_name();
+        let current = self.current_unit_id().primary_name();
+
+        // Basic identifiers are case insensitive and commonly written in lower case
+        let lower = |symbol: &Symbol| {
+            let name = symbol.name_utf8();
+            if name.starts_with('\\') {
+                name
+            } else {
+                name.to_lowercase()
+            }
+        };
+
+        let mut hinted = Vec::new();
+        for (library, package, pos) in self.root.packages_declaring(name) {
+            // Overloaded subprograms only need one hint per package
+            if (library == work && package == current) || hinted.contains(&(library, package)) {
+                continue;
+            }
+            hinted.push((library, package));
+            let library = if library == work {
+                "work".to_owned()
+            } else {
+                lower(library)
+            };
+            let package = lower(package);
+            diagnostic.add_related(
+                pos,
+                format!(
+                    "'{name}' is declared in package '{library}.{package}', add 'use {library}.{package}.all;'"
+                ),
+            );
+        }
+        diagnostic
+    }
+
+    /// Follow a relative external path through instance labels to the ports and generics
+    /// of the instantiated unit. Parts of the path that cannot be followed, such as
+    /// declarations within an architecture, are left unresolved
+    fn resolve_relative_external_path(
+        &self,
+        scope: &Scope<'a>,
+        name: &mut WithPos<Name>,
+    ) -> Option<EntRef<'a>> {
+        match name.item {
+            Name::Designator(ref mut designator) => {
+                let ent = scope
+                    .lookup(&name.pos, &designator.item)
+                    .ok()?
+                    .into_non_overloaded()
+                    .ok()?;
+                if !matches!(
+                    ent.kind(),
+                    AnyEntKind::Concurrent(_) | AnyEntKind::Object(_)
+                ) {
+                    return None;
+                }
+                designator.set_unique_reference(ent);
+                Some(ent)
+            }
+            Name::Selected(ref mut prefix, ref mut suffix) => {
+                let prefix_ent = self.resolve_relative_external_path(scope, prefix)?;
+                let AnyEntKind::Concurrent(Some(Concurrent::Instance(Some(unit)))) =
+                    prefix_ent.kind()
+                else {
+                    return None;
+                };
+                let region = match unit.kind() {
+                    AnyEntKind::Design(Design::Entity(_, region))
+                    | AnyEntKind::Component(region) => region,
+                    _ => return None,
+                };
+                let ent = region
+                    .lookup_immediate(&suffix.item.item)?
+                    .clone()
+                    .into_non_overloaded()
+                    .ok()?;
+                suffix.set_unique_reference(ent);
+                Some(ent)
+            }
+            _ => None,
+        }
+    }
 }
 
-fn plural(singular: &'static str, plural: &'static str, count: usize) -> &'static str {
+/// Suggest a likely fix when a record is indexed or an array is selected
+fn cannot_be_prefix_hint(resolved: &ResolvedName, suffix: &Suffix) -> Option<String> {
+    let typ = match resolved {
+        ResolvedName::ObjectName(_) | ResolvedName::Expression(_) => resolved.type_mark()?,
+        _ => return None,
+    };
+
+    match (typ.base_type().kind(), suffix) {
+        (Type::Record(_), Suffix::CallOrIndexed(ref assoc)) if could_be_indexed_name(assoc) => {
+            Some(format!(
+                "Elements of {} are selected with '.' rather than indexed",
+                typ.describe()
+            ))
+        }
+        (Type::Array { .. }, Suffix::Selected(_)) => Some(format!(
+            "Index {} with '(...)' before selecting from an element",
+            typ.describe()
+        )),
+        _ => None,
+    }
+}
+
+pub(super) fn plural(singular: &'static str, plural: &'static str, count: usize) -> &'static str {
     if count == 1 {
         singular
     } else {
@@ -1537,8 +1890,32 @@ fn plural(singular: &'static str, plural: &'static str, count: usize) -> &'stati
     }
 }
 
+/// The position of an open actual when an array is indexed with it such as arr(open)
+fn open_index_pos<'s>(resolved: &ResolvedName, suffix: &'s Suffix) -> Option<&'s SrcPos> {
+    let Suffix::CallOrIndexed(ref assocs) = suffix else {
+        return None;
+    };
+    let typ = match resolved {
+        ResolvedName::ObjectName(_) | ResolvedName::Expression(_) => resolved.type_mark()?,
+        _ => return None,
+    };
+    if !matches!(typ.base_type().kind(), Type::Array { .. })
+        || assocs.iter().any(|assoc| assoc.formal.is_some())
+    {
+        return None;
+    }
+    assocs
+        .iter()
+        .find(|assoc| matches!(assoc.actual.item, ActualPart::Open))
+        .map(|assoc| &assoc.actual.pos)
+}
+
 impl Diagnostic {
     fn cannot_be_prefix(prefix_pos: &SrcPos, resolved: ResolvedName, suffix: Suffix) -> Diagnostic {
+        if let Some(open_pos) = open_index_pos(&resolved, &suffix) {
+            return Diagnostic::error(open_pos, "open is not allowed as an index");
+        }
+
         let suffix_desc = match suffix {
             Suffix::Selected(_) => "selected",
             Suffix::All => "accessed with .all",
@@ -1561,7 +1938,12 @@ impl Diagnostic {
             resolved.describe_type()
         };
 
-        Diagnostic::error(prefix_pos, format!("{name_desc} cannot be {suffix_desc}"))
+        let mut diagnostic =
+            Diagnostic::error(prefix_pos, format!("{name_desc} cannot be {suffix_desc}"));
+        if let Some(hint) = cannot_be_prefix_hint(&resolved, &suffix) {
+            diagnostic.add_related(prefix_pos, hint);
+        }
+        diagnostic
     }
 
     fn cannot_be_prefix_of_attribute(
@@ -1681,6 +2063,7 @@ mod test {
     use super::*;
     use assert_matches::assert_matches;
 
+    use crate::analysis::standard::StdType;
     use crate::analysis::tests::TestSetup;
     use crate::syntax::test::check_diagnostics;
     use crate::syntax::test::Code;
@@ -1747,6 +2130,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn std_type_matches_lookup() {
+        let test = TestSetup::new();
+        let ctx = test.ctx();
+        assert_eq!(ctx.std_type(StdType::Natural), test.lookup_type("natural"));
+        assert_eq!(ctx.std_type(StdType::String), test.lookup_type("string"));
+        assert_eq!(
+            ctx.std_type(StdType::SeverityLevel),
+            test.lookup_type("severity_level")
+        );
+    }
+
     #[test]
     fn selected_object_name() {
         let test = TestSetup::new();
@@ -1814,7 +2209,7 @@ variable c0 : integer_vector(0 to 1);
         let test = TestSetup::new();
         test.declarative_part(
             "
-variable c0 : integer_vector(0 to 1);
+variable c0 : integer;
 ",
         );
         let code = test.snippet("c0(open)");
@@ -1833,6 +2228,30 @@ variable c0 : integer_vector(0 to 1);
         );
     }
 
+    #[test]
+    fn open_is_not_allowed_as_index() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+variable c0 : integer_vector(0 to 1);
+",
+        );
+        let code = test.snippet("c0(open)");
+        let mut diagnostics = Vec::new();
+        assert_eq!(
+            test.name_resolve(&code, None, &mut diagnostics),
+            Err(EvalError::Unknown)
+        );
+
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("open"),
+                "open is not allowed as an index",
+            )],
+        );
+    }
+
     #[test]
     fn overloaded_name() {
         let test = TestSetup::new();
@@ -2335,6 +2754,46 @@ constant c0 : arr_t := (others => 0);
         );
     }
 
+    #[test]
+    fn val_attribute_checks_static_position() {
+        let test = TestSetup::new();
+
+        let code = test.snippet("bit'val(1)");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.ctx().bit()
+            )))
+        );
+
+        let code = test.snippet("bit'val(5)");
+        let mut diagnostics = Vec::new();
+        assert_eq!(
+            test.name_resolve(&code, None, &mut diagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.ctx().bit()
+            )))
+        );
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::warning(
+                code.s1("5"),
+                "Position 5 is outside of the range of type 'BIT'",
+            )],
+        );
+
+        let code = test.snippet("natural'val(-1)");
+        let mut diagnostics = Vec::new();
+        let _ = test.name_resolve(&code, None, &mut diagnostics);
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::warning(
+                code.s1("-1"),
+                "Position -1 is outside of the range of subtype 'NATURAL'",
+            )],
+        );
+    }
+
     #[test]
     fn discrete_attributes() {
         let test = TestSetup::new();
@@ -2404,6 +2863,58 @@ constant c0 : arr_t := (others => 0);
         );
     }
 
+    #[test]
+    fn physical_type_discrete_attributes() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+type distance_t is range 0 to 1000
+  units
+    um;
+    mm = 1000 um;
+  end units;
+        ",
+        );
+
+        for snippet in [
+            "time'pos(1 ns)",
+            "time'pos(ns)",
+            "time'pos(std.standard.ns)",
+            "distance_t'pos(2 mm)",
+        ] {
+            let code = test.snippet(snippet);
+            assert_eq!(
+                test.name_resolve(&code, None, &mut NoDiagnostics),
+                Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                    test.ctx().universal_integer().into()
+                ))),
+                "{snippet}"
+            );
+        }
+
+        for snippet in ["time'val(1000)", "time'succ(ns)", "time'leftof(1 ps)"] {
+            let code = test.snippet(snippet);
+            assert_eq!(
+                test.name_resolve(&code, None, &mut NoDiagnostics),
+                Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                    test.ctx().time()
+                ))),
+                "{snippet}"
+            );
+        }
+
+        let code = test.snippet("distance_t'val(mm)");
+        let mut diagnostics = Vec::new();
+        let _ = test.name_resolve(&code, None, &mut diagnostics);
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("mm"),
+                "Expected integer type, got physical type 'distance_t'",
+            )],
+        );
+    }
+
     #[test]
     fn signal_attributes_on_non_signal() {
         let test = TestSetup::new();
@@ -2588,18 +3099,10 @@ variable thevar : integer_vector(0 to 1);
         ",
         );
         let code = test.snippet("thevar'subtype");
-        let mut diagnostics = Vec::new();
-        assert_eq!(
-            test.name_resolve(&code, None, &mut diagnostics),
-            Err(EvalError::Unknown)
+        assert_matches!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Type(typ)) if typ == test.lookup_type("integer_vector")
         );
-        check_diagnostics(
-            diagnostics,
-            vec![Diagnostic::error(
-                code,
-                "Type cannot be used as an expression",
-            )],
-        )
     }
 
     #[test]
@@ -2691,6 +3194,40 @@ signal thesig : integer;
         );
     }
 
+    #[test]
+    fn enum_type_conversion() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+type enum_t is (alpha, beta);
+        ",
+        );
+
+        let code = test.snippet("boolean(true)");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.ctx().boolean()
+            )))
+        );
+
+        let code = test.snippet("enum_t(false)");
+        let mut diagnostics = Vec::new();
+        assert_eq!(
+            test.name_resolve(&code, None, &mut diagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.lookup_type("enum_t")
+            )))
+        );
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("false"),
+                "type 'BOOLEAN' cannot be converted to type 'enum_t', conversion is not allowed between enumeration types",
+            )],
+        );
+    }
+
     #[test]
     fn array_type_conversion() {
         let test = TestSetup::new();
@@ -2774,6 +3311,47 @@ constant c0 : character_matrix1 := (others => (others => 'a'));
         );
     }
 
+    #[test]
+    fn array_type_conversion_of_index_types() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+type enum_t is (alpha, beta);
+type idx_t is range 0 to 1;
+type enum_bits_t is array (enum_t) of bit;
+type idx_bits_t is array (idx_t) of bit;
+
+constant c0 : enum_bits_t := (others => '0');
+constant c1 : idx_bits_t := (others => '0');
+            ",
+        );
+
+        // Integer index types are closely related
+        let code = test.snippet("bit_vector(c1)");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.lookup_type("bit_vector")
+            )))
+        );
+
+        let code = test.snippet("bit_vector(c0)");
+        let mut diagnostics = Vec::new();
+        assert_eq!(
+            test.name_resolve(&code, None, &mut diagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.lookup_type("bit_vector")
+            )))
+        );
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("c0"),
+                "array type 'enum_bits_t' cannot be converted to array type 'BIT_VECTOR'",
+            )],
+        );
+    }
+
     #[test]
     fn identical_type_conversion() {
         let test = TestSetup::new();
@@ -2884,4 +3462,29 @@ type enum_t is (alpha, beta);
             ],
         )
     }
+
+    #[test]
+    fn assignable_names() {
+        let test = TestSetup::new();
+
+        test.declarative_part(
+            "
+signal sig : natural;
+constant c0 : natural := 0;
+function fun return natural;
+        ",
+        );
+
+        let is_assignable = |snippet: &str| {
+            let resolved = test
+                .name_resolve(&test.snippet(snippet), None, &mut NoDiagnostics)
+                .unwrap();
+            test.ctx().is_assignable(&resolved)
+        };
+
+        assert!(is_assignable("sig"));
+        assert!(!is_assignable("c0"));
+        assert!(!is_assignable("fun"));
+        assert!(!is_assignable("sig'delayed"));
+    }
 }
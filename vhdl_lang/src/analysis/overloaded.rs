@@ -184,6 +184,20 @@ impl<'a> AsRef<OverloadedEnt<'a>> for ResolvedCall<'a> {
 }
 
 impl<'a> AnalyzeContext<'a> {
+    /// The visible overloaded subprograms of a call before disambiguation
+    /// Used to show the signatures of all candidates such as for signature help
+    pub fn call_candidates(
+        &self,
+        scope: &Scope<'a>,
+        call_name_pos: &SrcPos,
+        des: &Designator,
+    ) -> Vec<OverloadedEnt<'a>> {
+        match scope.lookup(call_name_pos, des) {
+            Ok(NamedEntities::Overloaded(overloaded)) => overloaded.sorted_entities(),
+            Ok(NamedEntities::Single(_)) | Err(_) => Vec::new(),
+        }
+    }
+
     /// Typecheck one overloaded call where the exact subprogram is known
     pub fn check_call(
         &self,
@@ -248,7 +262,7 @@ impl<'a> AnalyzeContext<'a> {
     ) -> EvalResult<Vec<Option<ExpressionType<'a>>>> {
         let mut actual_types = Vec::with_capacity(assocs.len());
 
-        for assoc in assocs.iter_mut() {
+        for assoc in assocs.iter_mut().filter(|assoc| !assoc.is_others()) {
             match &mut assoc.actual.item {
                 ActualPart::Expression(expr) => {
                     let actual_type =
@@ -474,6 +488,27 @@ function myfun return integer;
         );
     }
 
+    #[test]
+    fn call_candidates_of_overloaded_name() {
+        let test = TestSetup::new();
+        let decl = test.declarative_part(
+            "
+function myfun(arg : integer) return integer;
+function myfun(arg : character) return integer;
+        ",
+        );
+
+        let call = test.snippet("myfun");
+        let des = call.designator();
+        assert_eq!(
+            test.ctx().call_candidates(&test.scope, &des.pos, &des.item),
+            vec![
+                test.lookup_overloaded(decl.s1("myfun(arg : integer").s1("myfun")),
+                test.lookup_overloaded(decl.s1("myfun(arg : character").s1("myfun")),
+            ]
+        );
+    }
+
     #[test]
     fn single_fcall_bad_type() {
         let test = TestSetup::new();
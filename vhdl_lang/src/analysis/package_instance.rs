@@ -14,6 +14,7 @@ use super::formal_region::InterfaceEnt;
 use super::formal_region::RecordElement;
 use super::formal_region::RecordRegion;
 use super::named_entity::Design;
+use super::named_entity::ModeView;
 use super::named_entity::Object;
 use super::named_entity::ObjectEnt;
 use super::named_entity::Overloaded;
@@ -30,15 +31,25 @@ use super::EntityId;
 use super::Related;
 use crate::ast::ActualPart;
 use crate::ast::AssociationElement;
+use crate::ast::Designator;
 use crate::ast::Expression;
 use crate::ast::Literal;
 use crate::ast::Name;
 use crate::ast::Operator;
 use crate::ast::PackageInstantiation;
 use crate::data::DiagnosticHandler;
+use crate::data::SrcPos;
 use crate::Diagnostic;
 use crate::NullDiagnostics;
 
+/// The actuals of a package instance by the id of their generic
+#[derive(Default)]
+struct GenericMapping<'a> {
+    types: FnvHashMap<EntityId, TypeEnt<'a>>,
+    // The static values of generic constants
+    values: FnvHashMap<EntityId, i64>,
+}
+
 impl<'a> AnalyzeContext<'a> {
     fn package_generic_map(
         &self,
@@ -46,8 +57,8 @@ impl<'a> AnalyzeContext<'a> {
         generics: GpkgRegion<'a>,
         generic_map: &mut [AssociationElement],
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> EvalResult<FnvHashMap<EntityId, TypeEnt<'a>>> {
-        let mut mapping = FnvHashMap::default();
+    ) -> EvalResult<GenericMapping<'a>> {
+        let mut mapping = GenericMapping::default();
 
         // @TODO check missing associations
         for (idx, assoc) in generic_map.iter_mut().enumerate() {
@@ -128,45 +139,31 @@ impl<'a> AnalyzeContext<'a> {
                             continue;
                         };
 
-                        mapping.insert(uninst_typ.id(), typ);
+                        mapping.types.insert(uninst_typ.id(), typ);
+                    }
+                    GpkgInterfaceEnt::Constant(obj) => {
+                        let ttyp = self.map_type_ent(&mapping, obj.type_mark());
+                        self.expr_pos_with_ttyp(scope, ttyp, &assoc.actual.pos, expr, diagnostics)?;
+
+                        // Constraints that depend on the generic become static when the actual is
+                        if let Some(value) = self.static_discrete_value(ttyp.base(), expr) {
+                            mapping.values.insert(obj.id(), value);
+                        }
                     }
-                    GpkgInterfaceEnt::Constant(obj) => self.expr_pos_with_ttyp(
-                        scope,
-                        self.map_type_ent(&mapping, obj.type_mark()),
-                        &assoc.actual.pos,
-                        expr,
-                        diagnostics,
-                    )?,
                     GpkgInterfaceEnt::Subprogram(target) => match expr {
                         Expression::Name(name) => {
                             let resolved =
                                 self.name_resolve(scope, &assoc.actual.pos, name, diagnostics)?;
                             if let ResolvedName::Overloaded(des, overloaded) = resolved {
-                                let signature = target.signature().key().map(|base_type| {
-                                    mapping
-                                        .get(&base_type.id())
-                                        .map(|ent| ent.base())
-                                        .unwrap_or(base_type)
-                                });
-                                if let Some(ent) = overloaded.get(&signature) {
+                                if let Some(ent) = self.subprogram_generic_actual(
+                                    &assoc.actual.pos,
+                                    &des.item,
+                                    &overloaded,
+                                    target,
+                                    &mapping,
+                                    diagnostics,
+                                ) {
                                     name.set_unique_reference(&ent);
-                                } else {
-                                    let mut diag = Diagnostic::error(
-                                        &assoc.actual.pos,
-                                        format!(
-                                            "Cannot map '{}' to subprogram generic {}{}",
-                                            des,
-                                            target.designator(),
-                                            signature.describe()
-                                        ),
-                                    );
-
-                                    diag.add_subprogram_candidates(
-                                        "Does not match",
-                                        overloaded.entities(),
-                                    );
-
-                                    diagnostics.push(diag)
                                 }
                             } else {
                                 diagnostics.error(
@@ -179,7 +176,30 @@ impl<'a> AnalyzeContext<'a> {
                             }
                         }
                         Expression::Literal(Literal::String(string)) => {
-                            if Operator::from_latin1(string.clone()).is_none() {
+                            if let Some(op) = Operator::from_latin1(string.clone()) {
+                                // The operator symbol denotes all visible overloads of the operator
+                                let des = Designator::OperatorSymbol(op);
+                                match scope.lookup(&assoc.actual.pos, &des) {
+                                    Ok(NamedEntities::Overloaded(overloaded)) => {
+                                        self.subprogram_generic_actual(
+                                            &assoc.actual.pos,
+                                            &des,
+                                            &overloaded,
+                                            target,
+                                            &mapping,
+                                            diagnostics,
+                                        );
+                                    }
+                                    Ok(NamedEntities::Single(ent)) => diagnostics.error(
+                                        &assoc.actual.pos,
+                                        format!(
+                                            "Cannot map {} to subprogram generic",
+                                            ent.describe()
+                                        ),
+                                    ),
+                                    Err(err) => diagnostics.push(err),
+                                }
+                            } else {
                                 diagnostics.error(&assoc.actual.pos, "Invalid operator symbol");
                             }
                         }
@@ -206,6 +226,42 @@ impl<'a> AnalyzeContext<'a> {
         Ok(mapping)
     }
 
+    /// Resolve the overload of the actual that matches the signature of the subprogram generic
+    /// where interface types are replaced by their actual types
+    fn subprogram_generic_actual(
+        &self,
+        pos: &SrcPos,
+        des: &Designator,
+        overloaded: &OverloadedName<'a>,
+        target: OverloadedEnt<'a>,
+        mapping: &GenericMapping<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> Option<OverloadedEnt<'a>> {
+        let signature = target.signature().key().map(|base_type| {
+            mapping
+                .types
+                .get(&base_type.id())
+                .map(|ent| ent.base())
+                .unwrap_or(base_type)
+        });
+
+        let ent = overloaded.get(&signature);
+        if ent.is_none() {
+            let mut diag = Diagnostic::error(
+                pos,
+                format!(
+                    "Cannot map '{}' to subprogram generic {}{}",
+                    des,
+                    target.designator(),
+                    signature.describe()
+                ),
+            );
+            diag.add_subprogram_candidates("Does not match", overloaded.entities());
+            diagnostics.push(diag)
+        }
+        ent
+    }
+
     pub fn generic_package_instance(
         &self,
         scope: &Scope<'a>,
@@ -227,7 +283,7 @@ impl<'a> AnalyzeContext<'a> {
                 let mapping = if let Some(generic_map) = generic_map {
                     self.package_generic_map(&nested, generics, generic_map, diagnostics)?
                 } else {
-                    FnvHashMap::default()
+                    GenericMapping::default()
                 };
 
                 for uninst in other {
@@ -259,7 +315,7 @@ impl<'a> AnalyzeContext<'a> {
     fn instantiate(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         uninst: EntRef<'a>,
     ) -> Result<EntRef<'a>, String> {
         let designator = uninst.designator().clone();
@@ -293,7 +349,7 @@ impl<'a> AnalyzeContext<'a> {
     fn map_kind(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         kind: &'a AnyEntKind<'a>,
     ) -> Result<AnyEntKind<'a>, String> {
         Ok(match kind {
@@ -343,6 +399,10 @@ impl<'a> AnalyzeContext<'a> {
             AnyEntKind::DeferredConstant(subtype) => {
                 AnyEntKind::DeferredConstant(self.map_subtype(mapping, *subtype)?)
             }
+            AnyEntKind::View(view) => AnyEntKind::View(ModeView {
+                typ: self.map_type_ent(mapping, view.typ),
+                elements: view.elements.clone(),
+            }),
             AnyEntKind::Library => AnyEntKind::Library,
             AnyEntKind::Design(design) => match design {
                 Design::PackageInstance(region) => AnyEntKind::Design(Design::PackageInstance(
@@ -361,7 +421,7 @@ impl<'a> AnalyzeContext<'a> {
     fn map_overloaded(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         overloaded: &'a Overloaded<'a>,
     ) -> Result<Overloaded<'a>, String> {
         Ok(match overloaded {
@@ -395,7 +455,7 @@ impl<'a> AnalyzeContext<'a> {
     fn map_signature(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         signature: &'a Signature<'a>,
     ) -> Result<Signature<'a>, String> {
         let Signature {
@@ -433,7 +493,7 @@ impl<'a> AnalyzeContext<'a> {
     fn map_region(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         region: &'a Region<'a>,
     ) -> Result<Region<'a>, String> {
         let Region {
@@ -466,11 +526,16 @@ impl<'a> AnalyzeContext<'a> {
     fn map_type(
         &self,
         parent: Option<EntRef<'a>>,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         typ: &'a Type<'a>,
     ) -> Result<Type<'a>, String> {
         Ok(match typ {
-            Type::Array { indexes, elem_type } => {
+            Type::Array {
+                indexes,
+                index_ranges,
+                elem_type,
+                is_constrained,
+            } => {
                 let mut mapped_indexes = Vec::with_capacity(indexes.len());
                 for index_typ in indexes.iter() {
                     mapped_indexes.push(
@@ -481,11 +546,13 @@ impl<'a> AnalyzeContext<'a> {
 
                 Type::Array {
                     indexes: mapped_indexes,
+                    index_ranges: index_ranges.clone(),
                     elem_type: self.map_type_ent(mapping, *elem_type),
+                    is_constrained: *is_constrained,
                 }
             }
             Type::Enum(symbols) => Type::Enum(symbols.clone()),
-            Type::Integer => Type::Integer,
+            Type::Integer(range) => Type::Integer(*range),
             Type::Real => Type::Real,
             Type::Physical => Type::Physical,
             Type::Access(subtype) => Type::Access(self.map_subtype(mapping, *subtype)?),
@@ -516,7 +583,7 @@ impl<'a> AnalyzeContext<'a> {
 
     fn map_object(
         &self,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         obj: &Object<'a>,
     ) -> Result<Object<'a>, String> {
         let Object {
@@ -524,6 +591,7 @@ impl<'a> AnalyzeContext<'a> {
             iface,
             subtype,
             has_default,
+            signal_kind,
         } = obj;
 
         Ok(Object {
@@ -531,26 +599,39 @@ impl<'a> AnalyzeContext<'a> {
             iface: *iface,
             subtype: self.map_subtype(mapping, *subtype)?,
             has_default: *has_default,
+            signal_kind: *signal_kind,
         })
     }
 
-    fn map_type_ent(
-        &self,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
-        typ: TypeEnt<'a>,
-    ) -> TypeEnt<'a> {
-        mapping.get(&typ.id()).cloned().unwrap_or(typ)
+    fn map_type_ent(&self, mapping: &GenericMapping<'a>, typ: TypeEnt<'a>) -> TypeEnt<'a> {
+        mapping.types.get(&typ.id()).cloned().unwrap_or(typ)
     }
 
     fn map_subtype(
         &self,
-        mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
+        mapping: &GenericMapping<'a>,
         subtype: Subtype<'a>,
     ) -> Result<Subtype<'a>, String> {
-        let Subtype { type_mark } = subtype;
+        let Subtype {
+            type_mark,
+            range,
+            generic_range,
+            elements,
+            is_constrained,
+        } = subtype;
+
+        let inst_range = generic_range.and_then(|range| range.evaluate(&mapping.values));
 
         Ok(Subtype {
             type_mark: self.map_type_ent(mapping, type_mark),
+            range: range.or(inst_range),
+            generic_range: if inst_range.is_some() {
+                None
+            } else {
+                generic_range
+            },
+            elements,
+            is_constrained,
         })
     }
 }
@@ -117,12 +117,12 @@ impl<'a> AnalyzeContext<'a> {
         };
 
         if let Some((_, indexes)) = typ.array_type() {
-            if let Some(first) = indexes.first().unwrap() {
-                Ok(*first)
-            } else {
-                // There was probably an error in the type definition of this signal/variable
-                Err(EvalError::Unknown)
-            }
+            // The optional argument selects the dimension such as arr'range(2)
+            self.array_index_expression_in_attribute(
+                indexes,
+                attr.expr.as_mut().map(|expr| expr.as_mut()),
+                diagnostics,
+            )
         } else {
             diagnostics.error(
                 &attr.name.pos,
@@ -153,7 +153,7 @@ impl<'a> AnalyzeContext<'a> {
 
                 let types = match (left_types, right_types) {
                     (DisambiguatedType::Unambiguous(l), DisambiguatedType::Unambiguous(r)) => {
-                        if let Some(typ) = self.common_type(l.base(), r.base()) {
+                        if let Some(typ) = self.common_base_type(l.base(), r.base()) {
                             return Ok(typ);
                         } else {
                             diagnostics.error(
@@ -255,6 +255,22 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// The type of the parameter of a for loop or for generate statement
+    /// A range with universal_integer bounds is implicitly converted to INTEGER
+    pub fn drange_loop_parameter_type(
+        &self,
+        scope: &Scope<'a>,
+        drange: &mut DiscreteRange,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<BaseType<'a>> {
+        let typ = self.drange_type(scope, drange, diagnostics)?;
+        if typ == self.universal_integer() {
+            Ok(self.integer().base())
+        } else {
+            Ok(typ)
+        }
+    }
+
     pub fn range_with_ttyp(
         &self,
         scope: &Scope<'a>,
@@ -0,0 +1,260 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Detection of process variables that are read before they are assigned
+
+use super::analyze::*;
+use super::named_entity::*;
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::data::*;
+use fnv::FnvHashSet;
+
+impl<'a> AnalyzeContext<'a> {
+    /// Warn on variables without an initial value that are read before any assignment
+    /// The check is conservative, an assignment within a branch of an if or case statement
+    /// counts as assigned after the statement and an assignment within a loop counts
+    /// as assigned throughout the loop
+    pub fn check_read_before_write(
+        &self,
+        decl: &[Declaration],
+        statements: &mut [LabeledSequentialStatement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        // Local subprograms may read or assign the variables of the process
+        if decl
+            .iter()
+            .any(|decl| matches!(decl, Declaration::SubprogramBody(..)))
+        {
+            return;
+        }
+
+        let candidates: Vec<EntityId> = decl
+            .iter()
+            .filter_map(|decl| match decl {
+                Declaration::Object(obj)
+                    if obj.class == ObjectClass::Variable && obj.expression.is_none() =>
+                {
+                    obj.ident.decl
+                }
+                _ => None,
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return;
+        }
+
+        let mut assigned = FnvHashSet::default();
+        let mut reads = Vec::new();
+        sequential_flow(&candidates, statements, &mut assigned, &mut reads);
+
+        let mut reported = FnvHashSet::default();
+        for (pos, id) in reads {
+            if reported.insert(id) {
+                diagnostics.warning(
+                    &pos,
+                    format!(
+                        "{} is read before it is assigned",
+                        self.arena.get(id).describe()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Walk the statements in order, adding the assigned variables to `assigned`
+/// and the reads of variables which are not yet assigned to `reads`
+fn sequential_flow(
+    candidates: &[EntityId],
+    statements: &mut [LabeledSequentialStatement],
+    assigned: &mut FnvHashSet<EntityId>,
+    reads: &mut Vec<(SrcPos, EntityId)>,
+) {
+    for statement in statements.iter_mut() {
+        match statement.statement.item {
+            SequentialStatement::VariableAssignment(ref mut assign) => {
+                let mut found = FindVariables::new(candidates);
+                match assign.rhs {
+                    AssignmentRightHand::Simple(ref mut expr) => {
+                        let _ = expr.search(&mut found);
+                    }
+                    AssignmentRightHand::Conditional(ref mut conditionals) => {
+                        for cond in conditionals.conditionals.iter_mut() {
+                            let _ = cond.condition.search(&mut found);
+                            let _ = cond.item.search(&mut found);
+                        }
+                        if let Some(ref mut expr) = conditionals.else_item {
+                            let _ = expr.search(&mut found);
+                        }
+                    }
+                    AssignmentRightHand::Selected(ref mut selection) => {
+                        let _ = selection.expression.search(&mut found);
+                        for alternative in selection.alternatives.iter_mut() {
+                            let _ = alternative.choices.search(&mut found);
+                            let _ = alternative.item.search(&mut found);
+                        }
+                    }
+                }
+
+                match assign.target.item {
+                    Target::Name(ref name) => {
+                        // Index expressions of the target are read
+                        if let Some((pos, reference)) = assigned_base(&assign.target.pos, name) {
+                            found.ignore.push(pos.clone());
+                            let _ = assign.target.search(&mut found);
+                            add_reads(found, assigned, reads);
+                            assigned.extend(reference.filter(|id| candidates.contains(id)));
+                        } else {
+                            let _ = assign.target.search(&mut found);
+                            add_reads(found, assigned, reads);
+                        }
+                    }
+                    Target::Aggregate(..) => {
+                        add_reads(found, assigned, reads);
+                        let mut targets = FindVariables::new(candidates);
+                        let _ = assign.target.search(&mut targets);
+                        assigned.extend(targets.found.into_iter().map(|(_, id)| id));
+                    }
+                }
+            }
+            SequentialStatement::ProcedureCall(ref mut call) => {
+                // The variables may be associated with out or inout parameters
+                let mut found = FindVariables::new(candidates);
+                let _ = call.item.search(&mut found);
+                assigned.extend(found.found.into_iter().map(|(_, id)| id));
+            }
+            SequentialStatement::If(ref mut ifstmt) => {
+                let mut after = assigned.clone();
+                for cond in ifstmt.conds.conditionals.iter_mut() {
+                    let mut found = FindVariables::new(candidates);
+                    let _ = cond.condition.search(&mut found);
+                    add_reads(found, assigned, reads);
+
+                    let mut branch = assigned.clone();
+                    sequential_flow(candidates, &mut cond.item, &mut branch, reads);
+                    after.extend(branch);
+                }
+                if let Some(ref mut statements) = ifstmt.conds.else_item {
+                    let mut branch = assigned.clone();
+                    sequential_flow(candidates, statements, &mut branch, reads);
+                    after.extend(branch);
+                }
+                *assigned = after;
+            }
+            SequentialStatement::Case(ref mut case) => {
+                let mut found = FindVariables::new(candidates);
+                let _ = case.expression.search(&mut found);
+                add_reads(found, assigned, reads);
+
+                let mut after = assigned.clone();
+                for alternative in case.alternatives.iter_mut() {
+                    let mut branch = assigned.clone();
+                    sequential_flow(candidates, &mut alternative.item, &mut branch, reads);
+                    after.extend(branch);
+                }
+                *assigned = after;
+            }
+            SequentialStatement::Loop(ref mut loop_stmt) => {
+                let mut found = FindVariables::new(candidates);
+                match loop_stmt.iteration_scheme {
+                    Some(IterationScheme::While(ref mut cond)) => {
+                        let _ = cond.search(&mut found);
+                    }
+                    // The prefix of a range attribute is not read
+                    Some(IterationScheme::For(
+                        _,
+                        DiscreteRange::Range(crate::ast::Range::Attribute(..)),
+                    )) => {}
+                    Some(IterationScheme::For(_, ref mut drange)) => {
+                        let _ = drange.search(&mut found);
+                    }
+                    None => {}
+                }
+                add_reads(found, assigned, reads);
+
+                // A later iteration reads the values assigned by an earlier one
+                sequential_flow(
+                    candidates,
+                    &mut loop_stmt.statements,
+                    assigned,
+                    &mut Vec::new(),
+                );
+                sequential_flow(candidates, &mut loop_stmt.statements, assigned, reads);
+            }
+            _ => {
+                let mut found = FindVariables::new(candidates);
+                let _ = statement.search(&mut found);
+                add_reads(found, assigned, reads);
+            }
+        }
+    }
+}
+
+fn add_reads(
+    found: FindVariables,
+    assigned: &FnvHashSet<EntityId>,
+    reads: &mut Vec<(SrcPos, EntityId)>,
+) {
+    reads.extend(
+        found
+            .found
+            .into_iter()
+            .filter(|(_, id)| !assigned.contains(id)),
+    );
+}
+
+/// The name of the object that is assigned by a target name such as rec.field(0)
+pub(super) fn assigned_base<'n>(
+    pos: &'n SrcPos,
+    name: &'n Name,
+) -> Option<(&'n SrcPos, Reference)> {
+    match name {
+        Name::Designator(designator) => Some((pos, designator.reference)),
+        Name::Selected(prefix, _) | Name::Slice(prefix, _) => {
+            assigned_base(&prefix.pos, &prefix.item)
+        }
+        Name::CallOrIndexed(call) => assigned_base(&call.name.pos, &call.name.item),
+        Name::SelectedAll(..) | Name::Attribute(..) | Name::External(..) => None,
+    }
+}
+
+/// Collect the references to the candidate variables
+/// The prefix of an attribute name does not read the value of the variable
+struct FindVariables<'c> {
+    candidates: &'c [EntityId],
+    ignore: Vec<SrcPos>,
+    found: Vec<(SrcPos, EntityId)>,
+}
+
+impl<'c> FindVariables<'c> {
+    fn new(candidates: &'c [EntityId]) -> Self {
+        FindVariables {
+            candidates,
+            ignore: Vec::new(),
+            found: Vec::new(),
+        }
+    }
+}
+
+impl Searcher for FindVariables<'_> {
+    fn search_name(&mut self, _pos: &SrcPos, name: &Name) -> SearchState {
+        if let Name::Attribute(ref attr) = name {
+            self.ignore.push(attr.name.pos.clone());
+        }
+        SearchState::NotFinished
+    }
+
+    fn search_pos_with_ref(&mut self, pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        if let Some(id) = *reference {
+            if self.candidates.contains(&id) && !self.ignore.contains(pos) {
+                self.found.push((pos.clone(), id));
+            }
+        }
+        SearchState::NotFinished
+    }
+}
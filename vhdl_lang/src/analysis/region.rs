@@ -12,7 +12,7 @@ use super::{named_entity, visibility::*};
 use crate::ast::*;
 use crate::data::*;
 
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use std::cell::RefCell;
 use std::collections::hash_map::Entry;
 use std::rc::Rc;
@@ -372,6 +372,7 @@ impl<'a> ScopeInner<'a> {
                         format!("No declaration of '{chr}'")
                     }
                     Designator::Anonymous(_) => "No declaration of <anonymous>".to_owned(),
+                    Designator::Others => "No declaration of others".to_owned(),
                 },
             )),
         }
@@ -531,6 +532,42 @@ impl<'a> Scope<'a> {
             .add_context_visibility(visible_pos, region)
     }
 
+    /// The named entities that are visible within the scope
+    /// An inner declaration hides outer ones with the same designator unless all are overloaded
+    pub fn visible_names(&self) -> Vec<EntRef<'a>> {
+        let mut names = Vec::new();
+        let mut ids = FnvHashSet::default();
+        let mut designators: FnvHashMap<Designator, bool> = FnvHashMap::default();
+
+        let mut scope = Some(self.clone());
+        while let Some(current) = scope {
+            let inner = current.0.borrow();
+            let mut region_designators: FnvHashMap<Designator, bool> = FnvHashMap::default();
+
+            for ent in inner
+                .region
+                .immediates()
+                .chain(inner.region.visibility.visible_entities())
+            {
+                let is_hidden = designators
+                    .get(ent.designator())
+                    .is_some_and(|is_overloaded| !(*is_overloaded && ent.is_overloaded()));
+
+                if !is_hidden && ids.insert(ent.id()) {
+                    names.push(ent);
+                    region_designators
+                        .entry(ent.designator().clone())
+                        .and_modify(|is_overloaded| *is_overloaded &= ent.is_overloaded())
+                        .or_insert(ent.is_overloaded());
+                }
+            }
+
+            designators.extend(region_designators);
+            scope = inner.parent.clone();
+        }
+        names
+    }
+
     pub fn next_anonymous(&self) -> usize {
         let mut inner = self.0.borrow_mut();
         let idx = inner.anon_idx;
@@ -5,6 +5,7 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use super::analyze::*;
+use super::lint::{FindAllDeclarations, LintRule};
 use super::lock::*;
 use super::named_entity::*;
 use super::region::NamedEntities;
@@ -18,10 +19,13 @@ use crate::ast::search::*;
 use crate::ast::*;
 use crate::data::*;
 use crate::syntax::Symbols;
+use crate::VhdlStandard;
 use fnv::{FnvHashMap, FnvHashSet};
 use parking_lot::RwLock;
 use std::collections::hash_map::Entry;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
+use std::sync::mpsc::TryRecvError;
 use std::sync::Arc;
 
 /// A design unit with design unit data
@@ -29,6 +33,20 @@ pub(super) struct AnalysisData {
     pub diagnostics: Vec<Diagnostic>,
     pub has_circular_dependency: bool,
     pub arena: FinalArena,
+    pub scopes: Vec<(SrcPos, Vec<EntityId>)>,
+}
+
+/// The names that are visible within a process, block or subprogram body
+/// as recorded during analysis
+pub struct ScopeSnapshot<'a> {
+    pub pos: SrcPos,
+    visible: Vec<EntRef<'a>>,
+}
+
+impl<'a> ScopeSnapshot<'a> {
+    pub fn visible_names(&self) -> &[EntRef<'a>] {
+        &self.visible
+    }
 }
 
 pub(super) type UnitReadGuard<'a> = ReadGuard<'a, AnyDesignUnit, AnalysisData>;
@@ -262,8 +280,22 @@ pub struct DesignRoot {
     pub(super) universal: Option<UniversalTypes>,
     pub(super) standard_types: Option<StandardTypes>,
     pub(super) std_ulogic: Option<EntityId>,
+    pub(super) standard: VhdlStandard,
+    // Warn on alternatives that can never be selected
+    pub(super) unreachable_warnings: bool,
+    // Warn on loops among concurrent signal assignments
+    pub(super) combinational_loop_warnings: bool,
+    // Warn on process variables that are read before they are assigned
+    pub(super) read_before_write_warnings: bool,
+    // Suppress further diagnostics after this many errors
+    max_errors: Option<NonZeroUsize>,
+    // Record the visible names within processes, blocks and subprogram bodies
+    pub(super) record_scopes: bool,
     libraries: FnvHashMap<Symbol, Library>,
 
+    // Enabled lint rules that run after analysis
+    lint_rules: Vec<Box<dyn LintRule>>,
+
     // Arena storage of all declaration in the design
     pub(super) arenas: FinalArena,
 
@@ -278,6 +310,11 @@ pub struct DesignRoot {
     // Tracks which units have a "use library.all;" clause.
     // library name  =>  set(affected)
     users_of_library_all: RwLock<FnvHashMap<Symbol, FnvHashSet<UnitId>>>,
+
+    // Names declared within package declarations, used to hint at a missing use clause
+    // name  =>  list(library name, package name, declaration position)
+    #[allow(clippy::type_complexity)]
+    package_declarations: FnvHashMap<Symbol, Vec<(Symbol, Symbol, SrcPos)>>,
 }
 
 impl DesignRoot {
@@ -288,12 +325,20 @@ impl DesignRoot {
             standard_arena: None,
             standard_types: None,
             std_ulogic: None,
+            standard: VhdlStandard::default(),
+            unreachable_warnings: false,
+            combinational_loop_warnings: false,
+            read_before_write_warnings: false,
+            max_errors: None,
+            record_scopes: false,
             symbols,
+            lint_rules: Vec::new(),
             arenas: FinalArena::default(),
             libraries: FnvHashMap::default(),
             users_of: RwLock::new(FnvHashMap::default()),
             missing_unit: RwLock::new(FnvHashMap::default()),
             users_of_library_all: RwLock::new(FnvHashMap::default()),
+            package_declarations: FnvHashMap::default(),
         }
     }
 
@@ -309,6 +354,141 @@ impl DesignRoot {
         }
     }
 
+    /// The VHDL standard to analyze against
+    pub fn standard(&self) -> VhdlStandard {
+        self.standard
+    }
+
+    /// Set the VHDL standard to analyze against
+    /// All design units are re-analyzed when the standard changes
+    pub fn set_standard(&mut self, standard: VhdlStandard) {
+        if self.standard != standard {
+            self.standard = standard;
+            self.reset_non_standard_units();
+        }
+    }
+
+    /// Whether statically unreachable alternatives of signal assignments are warned on
+    pub fn unreachable_warnings(&self) -> bool {
+        self.unreachable_warnings
+    }
+
+    /// Enable warnings on statically unreachable alternatives of signal assignments
+    /// All design units are re-analyzed when the setting changes
+    pub fn set_unreachable_warnings(&mut self, enable: bool) {
+        if self.unreachable_warnings != enable {
+            self.unreachable_warnings = enable;
+            self.reset_non_standard_units();
+        }
+    }
+
+    /// Whether combinational loops among concurrent signal assignments are warned on
+    pub fn combinational_loop_warnings(&self) -> bool {
+        self.combinational_loop_warnings
+    }
+
+    /// Enable warnings on combinational loops among concurrent signal assignments
+    /// All design units are re-analyzed when the setting changes
+    pub fn set_combinational_loop_warnings(&mut self, enable: bool) {
+        if self.combinational_loop_warnings != enable {
+            self.combinational_loop_warnings = enable;
+            self.reset_non_standard_units();
+        }
+    }
+
+    /// Whether process variables that are read before they are assigned are warned on
+    pub fn read_before_write_warnings(&self) -> bool {
+        self.read_before_write_warnings
+    }
+
+    /// Enable warnings on process variables that are read before they are assigned
+    /// All design units are re-analyzed when the setting changes
+    pub fn set_read_before_write_warnings(&mut self, enable: bool) {
+        if self.read_before_write_warnings != enable {
+            self.read_before_write_warnings = enable;
+            self.reset_non_standard_units();
+        }
+    }
+
+    /// The number of errors after which further diagnostics are suppressed
+    pub fn max_errors(&self) -> Option<NonZeroUsize> {
+        self.max_errors
+    }
+
+    /// Stop reporting diagnostics once this many errors have been reported
+    /// All design units are still analyzed and the errors are counted in the order
+    /// the units are reported such that the result does not depend on the analysis order
+    pub fn set_max_errors(&mut self, max_errors: Option<NonZeroUsize>) {
+        self.max_errors = max_errors;
+    }
+
+    /// Whether the names visible within processes, blocks and subprogram bodies are recorded
+    pub fn record_scopes(&self) -> bool {
+        self.record_scopes
+    }
+
+    /// Record the names visible within processes, blocks and subprogram bodies
+    /// such that they can be queried by position using scope_at
+    /// All design units are re-analyzed when the setting changes
+    pub fn set_record_scopes(&mut self, enable: bool) {
+        if self.record_scopes != enable {
+            self.record_scopes = enable;
+            self.reset_non_standard_units();
+        }
+    }
+
+    fn reset_non_standard_units(&self) {
+        let std_lib_name = self.symbol_utf8("std");
+        // The standard library does not depend on the analysis settings and
+        // the standard package cannot be re-analyzed without being parsed again
+        for library in self.libraries.values() {
+            if library.name() == &std_lib_name {
+                continue;
+            }
+            for unit in library.units.values() {
+                unit.unit.reset();
+            }
+        }
+    }
+
+    /// Enable a lint rule, a rule with the same name is replaced
+    pub fn add_lint_rule(&mut self, rule: Box<dyn LintRule>) {
+        self.lint_rules.retain(|other| other.name() != rule.name());
+        self.lint_rules.push(rule);
+    }
+
+    pub fn remove_lint_rule(&mut self, name: &str) {
+        self.lint_rules.retain(|rule| rule.name() != name);
+    }
+
+    pub fn take_lint_rules(&mut self) -> Vec<Box<dyn LintRule>> {
+        std::mem::take(&mut self.lint_rules)
+    }
+
+    fn lint_unit(&self, unit: &LockedUnit, diagnostics: &mut dyn DiagnosticHandler) {
+        if self.lint_rules.is_empty() {
+            return;
+        }
+
+        // The predefined libraries are not subject to project specific rules
+        let library_name = unit.unit_id().library_name();
+        if library_name == &self.symbol_utf8("std") || library_name == &self.symbol_utf8("ieee") {
+            return;
+        }
+
+        let mut searcher = FindAllDeclarations::default();
+        let _ = unit.unit.write().search(&mut searcher);
+        let declarations: Vec<_> = searcher
+            .ids
+            .into_iter()
+            .map(|id| self.arenas.get(id))
+            .collect();
+
+        for rule in self.lint_rules.iter() {
+            rule.check_unit(&declarations, diagnostics);
+        }
+    }
+
     pub fn ensure_library(&mut self, name: Symbol) {
         self.get_or_create_library(name);
     }
@@ -381,6 +561,51 @@ impl DesignRoot {
         Some((pos, ent))
     }
 
+    /// Find the innermost declaration such as a statement, subprogram body or design unit
+    /// whose source range contains the position
+    pub fn enclosing_declaration<'a>(&'a self, pos: &SrcPos) -> Option<EntRef<'a>> {
+        let mut searcher = FindEnclosingDeclaration::new(pos);
+        let _ = self.search(&mut searcher);
+        Some(self.get_ent(searcher.result()?))
+    }
+
+    /// The names visible at the position as recorded for the innermost enclosing
+    /// process, block or subprogram body, requires set_record_scopes before analysis
+    pub fn scope_at<'a>(&'a self, pos: &SrcPos) -> Option<ScopeSnapshot<'a>> {
+        let mut result: Option<ScopeSnapshot<'a>> = None;
+        for library in self.libraries.values() {
+            for unit in library.units.values() {
+                let Some(data) = unit.unit.get() else {
+                    continue;
+                };
+
+                for (scope_pos, visible) in data.result().scopes.iter() {
+                    let contains = scope_pos.source == pos.source
+                        && scope_pos.start() <= pos.start()
+                        && pos.end() <= scope_pos.end();
+                    let is_inner = result
+                        .as_ref()
+                        .is_none_or(|snapshot| snapshot.pos.start() <= scope_pos.start());
+
+                    if contains && is_inner {
+                        result = Some(ScopeSnapshot {
+                            pos: scope_pos.clone(),
+                            visible: visible.iter().map(|id| self.get_ent(*id)).collect(),
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Find the smallest name or expression whose source range contains the position
+    pub fn node_at(&self, pos: &SrcPos) -> Option<AstNode> {
+        let mut searcher = FindNodeAt::new(pos);
+        let _ = self.search(&mut searcher);
+        searcher.result
+    }
+
     pub fn search_reference<'a>(&'a self, source: &Source, cursor: Position) -> Option<EntRef<'a>> {
         let (_, ent) = self.item_at_cursor(source, cursor)?;
         Some(ent)
@@ -431,11 +656,29 @@ impl DesignRoot {
         Vec::default()
     }
 
+    /// The record elements or protected type methods that may be selected
+    /// from an object, such as for completion after a dot
+    pub fn selectable_members<'a>(&'a self, ent: EntRef<'a>) -> Vec<EntRef<'a>> {
+        match ent.actual_kind() {
+            AnyEntKind::Object(obj) => obj.subtype.type_mark().selectable(),
+            AnyEntKind::ObjectAlias { type_mark, .. } => type_mark.selectable(),
+            AnyEntKind::DeferredConstant(subtype) => subtype.type_mark().selectable(),
+            _ => Vec::new(),
+        }
+    }
+
     #[cfg(test)]
     pub fn search_reference_pos(&self, source: &Source, cursor: Position) -> Option<SrcPos> {
         self.search_reference(source, cursor)
             .and_then(|ent| ent.decl_pos().cloned())
     }
+
+    /// The declaration position and id of the entity referenced by the name at `pos`
+    pub fn reference_at(&self, pos: &SrcPos) -> Option<(SrcPos, EntityId)> {
+        let ent = self.search_reference(&pos.source, pos.start())?;
+        Some((ent.decl_pos()?.clone(), ent.id()))
+    }
+
     /// Search for the declaration at decl_pos and format it
     pub fn format_declaration(&self, ent: &AnyEnt) -> Option<String> {
         if let AnyEntKind::Library = ent.kind() {
@@ -460,6 +703,36 @@ impl DesignRoot {
         searcher.references
     }
 
+    /// The subprograms that are called from within the body of a subprogram
+    pub fn callees_of(&self, subprogram: EntityId) -> Vec<EntityId> {
+        let subprogram = self.get_ent(subprogram).declaration().id();
+        let mut callees = Vec::new();
+        for (caller, callee) in self.find_all_calls() {
+            if caller.id() == subprogram && !callees.contains(&callee.id()) {
+                callees.push(callee.id());
+            }
+        }
+        callees
+    }
+
+    /// The subprograms whose bodies call a subprogram
+    pub fn callers_of(&self, subprogram: EntityId) -> Vec<EntityId> {
+        let subprogram = self.get_ent(subprogram).declaration().id();
+        let mut callers = Vec::new();
+        for (caller, callee) in self.find_all_calls() {
+            if callee.id() == subprogram && !callers.contains(&caller.id()) {
+                callers.push(caller.id());
+            }
+        }
+        callers
+    }
+
+    fn find_all_calls(&self) -> Vec<(EntRef<'_>, EntRef<'_>)> {
+        let mut searcher = FindAllCalls::new(self);
+        let _ = self.search(&mut searcher);
+        searcher.calls
+    }
+
     pub fn public_symbols<'a>(&'a self) -> Box<dyn Iterator<Item = EntRef<'a>> + 'a> {
         Box::new(self.libraries.values().flat_map(|library| {
             std::iter::once(self.arenas.get(library.id)).chain(library.units.values().flat_map(
@@ -507,18 +780,31 @@ impl DesignRoot {
         source: &Source,
     ) -> Vec<EntHierarchy<'a>> {
         let mut searcher = FindAllEnt::new(self, |ent| ent.is_explicit());
+        self.search_source(library_name, source, &mut searcher);
+        searcher.result.sort_by_key(|ent| ent.decl_pos());
+        EntHierarchy::from_vec(searcher.result)
+    }
+
+    /// All resolved references within the design units of a source file
+    pub fn references_in_source<'a>(
+        &'a self,
+        library_name: &Symbol,
+        source: &Source,
+    ) -> Vec<(SrcPos, EntRef<'a>)> {
+        let mut searcher = FindAllResolved::new(self);
+        self.search_source(library_name, source, &mut searcher);
+        searcher.result
+    }
 
+    fn search_source(&self, library_name: &Symbol, source: &Source, searcher: &mut impl Searcher) {
         if let Some(library) = self.libraries.get(library_name) {
             if let Some(unit_ids) = library.units_by_source.get(source) {
                 for unit_id in unit_ids {
                     let unit = library.units.get(unit_id.key()).unwrap();
-                    let _ = unit.unit.write().search(&mut searcher);
+                    let _ = unit.unit.write().search(searcher);
                 }
             }
         }
-
-        searcher.result.sort_by_key(|ent| ent.decl_pos());
-        EntHierarchy::from_vec(searcher.result)
     }
 
     pub fn find_all_unresolved(&self) -> (usize, Vec<SrcPos>) {
@@ -592,6 +878,13 @@ impl DesignRoot {
     }
 
     fn analyze_unit(&self, arena_id: ArenaId, unit_id: &UnitId, unit: &mut UnitWriteGuard) {
+        let _span = enter_span!(
+            INFO,
+            "analyze_unit",
+            library = %unit_id.library_name(),
+            primary = %unit_id.primary_name(),
+            secondary = ?unit_id.secondary_name().map(|name| name.name_utf8()),
+        );
         // All units reference the standard arena
         // @TODO keep the same ArenaId when re-using unit
         let arena = Arena::new(arena_id);
@@ -612,6 +905,7 @@ impl DesignRoot {
                 };
 
                 AnalysisData {
+                    scopes: context.scopes.take(),
                     arena: arena.finalize(),
                     diagnostics,
                     has_circular_dependency,
@@ -627,6 +921,7 @@ impl DesignRoot {
                 };
 
                 AnalysisData {
+                    scopes: context.scopes.take(),
                     arena: arena.finalize(),
                     diagnostics,
                     has_circular_dependency,
@@ -692,6 +987,103 @@ impl DesignRoot {
         }
     }
 
+    /// All design units ordered such that each unit comes after the units it depends on
+    /// The dependencies are those found by the last analysis
+    pub fn dependency_order(&self) -> Result<Vec<UnitId>, CircularDependencyError> {
+        let mut unit_ids = Vec::new();
+        for library in self.libraries.values() {
+            unit_ids.extend(library.units.values().map(|unit| unit.unit_id().clone()));
+        }
+        // Order independent units by library and position for a stable result
+        let order_key = |unit_id: &UnitId| {
+            let pos = self.get_unit(unit_id).map(|unit| unit.ident().pos.clone());
+            (
+                unit_id.library_name().name_utf8(),
+                pos.as_ref()
+                    .map(|pos| pos.source.file_name().to_owned())
+                    .unwrap_or_default(),
+                pos.map(|pos| pos.start()),
+            )
+        };
+        unit_ids.sort_by_cached_key(order_key);
+
+        // unit  =>  list(dependencies)
+        let mut dependencies: FnvHashMap<&UnitId, Vec<&UnitId>> = FnvHashMap::default();
+        let users_of = self.users_of.read();
+        for (unit_id, users) in users_of.iter() {
+            for user in users.iter() {
+                dependencies.entry(user).or_default().push(unit_id);
+            }
+        }
+        // A secondary unit always depends on its primary unit
+        let primary_ids: Vec<_> = unit_ids
+            .iter()
+            .filter_map(|unit_id| {
+                let AnyKind::Secondary(kind) = unit_id.kind() else {
+                    return None;
+                };
+                let primary_kind = match kind {
+                    SecondaryKind::Architecture => PrimaryKind::Entity,
+                    SecondaryKind::PackageBody => PrimaryKind::Package,
+                };
+                Some((
+                    unit_id,
+                    UnitId::primary(unit_id.library_name(), primary_kind, unit_id.primary_name()),
+                ))
+            })
+            .collect();
+        for (unit_id, primary_id) in primary_ids.iter() {
+            dependencies.entry(unit_id).or_default().push(primary_id);
+        }
+        for unit_dependencies in dependencies.values_mut() {
+            unit_dependencies.sort_by_cached_key(|unit_id| order_key(unit_id));
+            unit_dependencies.dedup();
+        }
+
+        let mut visiting = FnvHashSet::default();
+        let mut visited = FnvHashSet::default();
+        let mut order = Vec::with_capacity(unit_ids.len());
+        for unit_id in unit_ids.iter() {
+            self.visit_dependencies(
+                unit_id,
+                &dependencies,
+                &mut visiting,
+                &mut visited,
+                &mut order,
+            )?;
+        }
+        Ok(order)
+    }
+
+    fn visit_dependencies<'u>(
+        &self,
+        unit_id: &'u UnitId,
+        dependencies: &FnvHashMap<&'u UnitId, Vec<&'u UnitId>>,
+        visiting: &mut FnvHashSet<&'u UnitId>,
+        visited: &mut FnvHashSet<&'u UnitId>,
+        order: &mut Vec<UnitId>,
+    ) -> FatalResult {
+        // The dependency of a unit which no longer exists is ignored
+        let Some(unit) = self.get_unit(unit_id) else {
+            return Ok(());
+        };
+        if visited.contains(unit_id) {
+            return Ok(());
+        }
+        if !visiting.insert(unit_id) {
+            return Err(CircularDependencyError::new(Some(&unit.ident().pos)));
+        }
+        if let Some(unit_dependencies) = dependencies.get(unit_id) {
+            for dependency in unit_dependencies.iter() {
+                self.visit_dependencies(dependency, dependencies, visiting, visited, order)?;
+            }
+        }
+        visiting.remove(unit_id);
+        visited.insert(unit_id);
+        order.push(unit_id.clone());
+        Ok(())
+    }
+
     /// Register a dependency of library unit for everything within library since .all was used
     pub(super) fn make_use_of_library_all(&self, user: &UnitId, library_name: &Symbol) {
         match self
@@ -941,6 +1333,7 @@ impl DesignRoot {
                         arena,
                         diagnostics,
                         has_circular_dependency: false,
+                        scopes: Vec::new(),
                     };
 
                     unit.finish(result);
@@ -978,11 +1371,25 @@ impl DesignRoot {
     }
 
     pub fn analyze(&mut self, diagnostics: &mut dyn DiagnosticHandler) {
+        for (_, unit_diagnostics) in self.analyze_by_unit(diagnostics) {
+            diagnostics.append(unit_diagnostics);
+        }
+    }
+
+    /// Analyze the design and return the diagnostics of each design unit
+    /// sorted by library and within a file
+    /// Diagnostics which do not belong to a design unit are pushed to `diagnostics`
+    pub fn analyze_by_unit(
+        &mut self,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> Vec<(UnitId, Vec<Diagnostic>)> {
+        let _span = enter_span!(INFO, "analyze");
         self.reset();
 
         for library in self.libraries.values_mut() {
             library.refresh(diagnostics);
         }
+        self.index_package_declarations();
 
         // Rebuild declaration arenas of named entities
         self.arenas.clear();
@@ -1007,8 +1414,41 @@ impl DesignRoot {
         }
 
         // @TODO compute the best order to process the units in parallel
-        units.par_iter().for_each(|unit| {
-            self.get_analysis(unit);
+        // The handler is not thread safe, units are sent to it as their analysis finishes
+        // The analysis stays in the thread pool of the caller and the handler is called
+        // on the current thread which helps with the analysis while it waits
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let (root, units) = (&*self, &units);
+        rayon::in_place_scope(|scope| {
+            scope.spawn(move |_| {
+                units.par_iter().for_each_with(sender, |sender, unit| {
+                    let analysis = root.get_analysis(unit);
+                    let unit_diagnostics = &analysis.result().diagnostics;
+                    // The receiver outlives all senders
+                    let _ = sender.send((unit.unit_id().clone(), unit_diagnostics.clone()));
+                });
+            });
+
+            loop {
+                let received = if rayon::current_thread_index().is_some() {
+                    match receiver.try_recv() {
+                        Err(TryRecvError::Empty) => {
+                            if rayon::yield_now() != Some(rayon::Yield::Executed) {
+                                std::thread::yield_now();
+                            }
+                            continue;
+                        }
+                        received => received.ok(),
+                    }
+                } else {
+                    receiver.recv().ok()
+                };
+
+                let Some((unit_id, unit_diagnostics)) = received else {
+                    break;
+                };
+                diagnostics.on_unit_analyzed(unit_id, &unit_diagnostics);
+            }
         });
 
         for library in self.libraries.values() {
@@ -1021,18 +1461,131 @@ impl DesignRoot {
         }
 
         // Emit diagnostics sorted within a file
+        let mut result = Vec::new();
+        let mut num_errors = 0;
         for library in self.libraries.values() {
             for unit_id in library.sorted_unit_ids() {
                 let unit = library.units.get(unit_id.key()).unwrap();
-                diagnostics.append(unit.unit.expect_analyzed().result().diagnostics.clone());
+                let Some(mut unit_diagnostics) = unit
+                    .unit
+                    .get()
+                    .map(|analysis| analysis.result().diagnostics.clone())
+                else {
+                    continue;
+                };
+                self.lint_unit(unit, &mut unit_diagnostics);
+
+                if let Some(max_errors) = self.max_errors {
+                    if let Some(idx) = nth_error(&unit_diagnostics, max_errors.get() - num_errors) {
+                        unit_diagnostics.truncate(idx + 1);
+                        let pos = unit_diagnostics[idx].pos.clone();
+                        unit_diagnostics.push(Diagnostic::info(
+                            pos,
+                            format!(
+                                "Too many errors, further diagnostics suppressed after {max_errors} errors"
+                            ),
+                        ));
+                        result.push((unit_id, unit_diagnostics));
+                        return result;
+                    }
+                    num_errors += count_errors(&unit_diagnostics);
+                }
+                result.push((unit_id, unit_diagnostics));
             }
         }
+        result
+    }
+
+    /// Index the names declared by packages before analysis such that the result
+    /// does not depend on the order in which units are analyzed
+    fn index_package_declarations(&mut self) {
+        let mut declarations: FnvHashMap<Symbol, Vec<(Symbol, Symbol, SrcPos)>> =
+            FnvHashMap::default();
+
+        for library in self.libraries.values() {
+            for unit in library.units.values() {
+                if unit.kind() != AnyKind::Primary(PrimaryKind::Package) {
+                    continue;
+                }
+                let data = unit.unit.write();
+                let AnyDesignUnit::Primary(AnyPrimaryUnit::Package(ref package)) = *data else {
+                    continue;
+                };
+                // An uninstantiated package cannot be made visible by a use clause
+                if package.generic_clause.is_some() {
+                    continue;
+                }
+                for decl in package.decl.iter() {
+                    if let Some((name, pos)) = declared_name(decl) {
+                        declarations.entry(name.clone()).or_default().push((
+                            library.name.clone(),
+                            package.ident.tree.item.clone(),
+                            pos.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for packages in declarations.values_mut() {
+            packages
+                .sort_by_key(|(library, package, _)| (library.name_utf8(), package.name_utf8()));
+        }
+        self.package_declarations = declarations;
+    }
+
+    /// The packages that declare a name, sorted by library and package name
+    pub(super) fn packages_declaring(&self, name: &Symbol) -> &[(Symbol, Symbol, SrcPos)] {
+        self.package_declarations
+            .get(name)
+            .map(|packages| packages.as_slice())
+            .unwrap_or_default()
     }
 
     /// Get the named entity
     pub fn get_ent(&self, id: EntityId) -> &AnyEnt {
         self.arenas.get(id)
     }
+
+    /// All named entities of the analyzed design, including implicit declarations
+    pub fn entities(&self) -> impl Iterator<Item = EntRef<'_>> {
+        self.arenas.iter()
+    }
+}
+
+fn count_errors(diagnostics: &[Diagnostic]) -> usize {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count()
+}
+
+/// The index of the n:th error where n starts at one
+fn nth_error(diagnostics: &[Diagnostic], n: usize) -> Option<usize> {
+    diagnostics
+        .iter()
+        .enumerate()
+        .filter(|(_, diagnostic)| diagnostic.severity == Severity::Error)
+        .nth(n.checked_sub(1)?)
+        .map(|(idx, _)| idx)
+}
+
+fn declared_name(decl: &Declaration) -> Option<(&Symbol, &SrcPos)> {
+    let ident = match decl {
+        Declaration::Object(object) => &object.ident.tree,
+        Declaration::File(file) => &file.ident.tree,
+        Declaration::Type(typ) => &typ.ident.tree,
+        Declaration::Component(component) => &component.ident.tree,
+        Declaration::SubprogramDeclaration(subprogram) => {
+            let designator = subprogram.subpgm_designator();
+            return match designator.item {
+                SubprogramDesignator::Identifier(ref name) => Some((name, &designator.pos)),
+                SubprogramDesignator::OperatorSymbol(_) => None,
+            };
+        }
+        _ => return None,
+    };
+    Some((&ident.item, &ident.pos))
 }
 
 fn get_all_affected(
@@ -1114,7 +1667,8 @@ impl<'a> EntHierarchy<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::syntax::test::{check_diagnostics, Code};
+    use crate::analysis::tests::{add_standard_library, LibraryBuilder};
+    use crate::syntax::test::{check_diagnostics, check_no_diagnostics, Code};
 
     fn new_library_with_diagnostics(code: &Code, name: &str) -> (Library, Vec<Diagnostic>) {
         let mut diagnostics = Vec::new();
@@ -1264,6 +1818,293 @@ end configuration;
         assert_eq!(library.units.len(), 2);
         assert_eq!(library.duplicates.len(), 1);
     }
+
+    #[derive(Default)]
+    struct UnitRecorder {
+        diagnostics: Vec<Diagnostic>,
+        units: Vec<(UnitId, usize)>,
+    }
+
+    impl DiagnosticHandler for UnitRecorder {
+        fn push(&mut self, diagnostic: Diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+
+        fn on_unit_analyzed(&mut self, unit: UnitId, diagnostics: &[Diagnostic]) {
+            // Units are reported during analysis before any diagnostic is pushed
+            assert!(self.diagnostics.is_empty());
+            self.units.push((unit, diagnostics.len()));
+        }
+    }
+
+    #[test]
+    fn on_unit_analyzed_is_called_per_unit() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+package pkg is
+  constant c : missing := 0;
+end package;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+",
+        );
+
+        let symbols = builder.symbols();
+        let mut root = DesignRoot::new(symbols.clone());
+        add_standard_library(symbols.clone(), &mut root);
+        for (library_name, code) in builder.take_code() {
+            root.add_design_file(library_name, code.design_file());
+        }
+
+        let mut recorder = UnitRecorder::default();
+        root.analyze(&mut recorder);
+
+        let libname = symbols.symtab().insert_utf8("libname");
+        let mut units: Vec<_> = recorder
+            .units
+            .into_iter()
+            .filter(|(unit_id, _)| unit_id.library_name() == &libname)
+            .map(|(unit_id, num_diagnostics)| {
+                (
+                    unit_id.primary_name().name_utf8(),
+                    unit_id.secondary_name().map(|name| name.name_utf8()),
+                    num_diagnostics,
+                )
+            })
+            .collect();
+        units.sort();
+
+        assert_eq!(
+            units,
+            vec![
+                ("ent".to_owned(), None, 0),
+                ("ent".to_owned(), Some("a".to_owned()), 0),
+                ("pkg".to_owned(), None, 1),
+            ]
+        );
+        assert_eq!(recorder.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn diagnostics_are_suppressed_after_max_errors() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  constant c0 : missing0 := 0;
+  constant c1 : missing1 := 0;
+  constant c2 : missing2 := 0;
+end package;
+",
+        );
+
+        let symbols = builder.symbols();
+        let mut root = DesignRoot::new(symbols.clone());
+        add_standard_library(symbols.clone(), &mut root);
+        for (library_name, code) in builder.take_code() {
+            root.add_design_file(library_name, code.design_file());
+        }
+
+        root.set_max_errors(NonZeroUsize::new(2));
+        let mut diagnostics = Vec::new();
+        root.analyze(&mut diagnostics);
+        check_diagnostics(
+            diagnostics,
+            vec![
+                Diagnostic::error(code.s1("missing0"), "No declaration of 'missing0'"),
+                Diagnostic::error(code.s1("missing1"), "No declaration of 'missing1'"),
+                Diagnostic::info(
+                    code.s1("missing1"),
+                    "Too many errors, further diagnostics suppressed after 2 errors",
+                ),
+            ],
+        );
+
+        root.set_max_errors(None);
+        let mut diagnostics = Vec::new();
+        root.analyze(&mut diagnostics);
+        assert_eq!(diagnostics.len(), 3);
+    }
+
+    #[test]
+    fn max_errors_cutoff_does_not_depend_on_analysis_order() {
+        let mut builder = LibraryBuilder::new();
+        for idx in 0..8 {
+            builder.code(
+                "libname",
+                &format!(
+                    "
+package pkg{idx} is
+  constant c0 : missing0 := 0;
+  constant c1 : missing1 := 0;
+end package;
+"
+                ),
+            );
+        }
+
+        let symbols = builder.symbols();
+        let codes = builder.take_code();
+        let analyze = || {
+            let mut root = DesignRoot::new(symbols.clone());
+            add_standard_library(symbols.clone(), &mut root);
+            for (library_name, code) in codes.iter() {
+                root.add_design_file(library_name.clone(), code.design_file());
+            }
+            root.set_max_errors(NonZeroUsize::new(5));
+            let mut diagnostics = Vec::new();
+            root.analyze(&mut diagnostics);
+            diagnostics
+        };
+
+        let diagnostics = analyze();
+        assert_eq!(count_errors(&diagnostics), 5);
+        for _ in 0..4 {
+            assert_eq!(analyze(), diagnostics);
+        }
+    }
+
+    #[test]
+    fn scope_at_finds_process_local_variable() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  process
+    variable local : natural;
+  begin
+    local := sig;
+    wait;
+  end process;
+end architecture;
+",
+        );
+
+        let symbols = builder.symbols();
+        let mut root = DesignRoot::new(symbols.clone());
+        add_standard_library(symbols.clone(), &mut root);
+        for (library_name, code) in builder.take_code() {
+            root.add_design_file(library_name, code.design_file());
+        }
+
+        root.set_record_scopes(true);
+        let mut diagnostics = Vec::new();
+        root.analyze(&mut diagnostics);
+        check_no_diagnostics(&diagnostics);
+
+        let scope = root.scope_at(&code.s1("local := sig").pos()).unwrap();
+        let names: Vec<String> = scope
+            .visible_names()
+            .iter()
+            .map(|ent| ent.designator().to_string().to_lowercase())
+            .collect();
+        assert!(names.contains(&"local".to_owned()));
+        assert!(names.contains(&"sig".to_owned()));
+        assert!(names.contains(&"natural".to_owned()));
+
+        // Outside of the process there is no recorded scope
+        assert!(root.scope_at(&code.s1("signal sig").pos()).is_none());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn analysis_emits_tracing_spans() {
+        use parking_lot::Mutex;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        // Records the name and fields of every span
+        #[derive(Default)]
+        struct SpanRecorder {
+            next_id: AtomicU64,
+            spans: Mutex<Vec<String>>,
+        }
+
+        struct FieldsVisitor(String);
+
+        impl Visit for FieldsVisitor {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl tracing::Subscriber for &'static SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut visitor = FieldsVisitor(span.metadata().name().to_owned());
+                span.record(&mut visitor);
+                self.spans.lock().push(visitor.0);
+                Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+            fn event(&self, _event: &Event<'_>) {}
+            fn enter(&self, _span: &Id) {}
+            fn exit(&self, _span: &Id) {}
+        }
+
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+end architecture;
+",
+        );
+
+        let recorder: &'static SpanRecorder = Box::leak(Box::default());
+        // A single thread such that the units analyzed in parallel use the same subscriber
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            tracing::subscriber::with_default(recorder, || {
+                let (_, diagnostics) = builder.get_analyzed_root();
+                check_no_diagnostics(&diagnostics);
+            })
+        });
+
+        let spans = recorder.spans.lock();
+        for expected in [
+            "analyze",
+            "analyze_unit library=libname primary=ent secondary=None",
+            "analyze_unit library=libname primary=ent secondary=Some(\"a\")",
+            "context_clause",
+            "declarative_part parent=a",
+            "concurrent_part parent=a",
+        ] {
+            assert!(
+                spans.iter().any(|span| span == expected),
+                "Missing span {expected}"
+            );
+        }
+    }
 }
 
 fn public_symbols<'a>(ent: EntRef<'a>) -> Box<dyn Iterator<Item = EntRef<'a>> + 'a> {
@@ -5,7 +5,7 @@
 //! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 use super::analyze::*;
 use super::named_entity::*;
-use super::names::ResolvedName;
+use super::names::{plural, ResolvedName};
 use super::overloaded::Disambiguated;
 use super::overloaded::SubprogramKind;
 use super::region::*;
@@ -51,6 +51,20 @@ impl<'a> AnalyzeContext<'a> {
         TypeEnt::from_any(ent).ok_or_else(|| AnalysisError::NotFatal(ent.kind_error(pos, expected)))
     }
 
+    pub fn resolve_mode_view(
+        &self,
+        scope: &Scope<'a>,
+        name: &mut WithPos<SelectedName>,
+    ) -> AnalysisResult<ModeViewEnt<'a>> {
+        let entities = self.resolve_selected_name(scope, name)?;
+
+        let pos = name.suffix_pos();
+        let expected = "view";
+        let ent = self.resolve_non_overloaded(entities, pos, expected)?;
+        ModeViewEnt::from_any(ent)
+            .ok_or_else(|| AnalysisError::NotFatal(ent.kind_error(pos, expected)))
+    }
+
     pub fn resolve_type_mark(
         &self,
         scope: &Scope<'a>,
@@ -131,6 +145,287 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// Check that the choices of a case statement cover all values of the selector
+    /// without overlap. Only done when all choices are locally static.
+    pub fn check_case_choices<T>(
+        &self,
+        selector: &WithPos<Expression>,
+        ctyp: TypeEnt<'a>,
+        alternatives: &[Alternative<T>],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let base = ctyp.base();
+        if !base.is_discrete() {
+            return;
+        }
+
+        let selector_range = self.selector_range(selector, ctyp);
+
+        self.check_choice_coverage(
+            "Case choice",
+            base,
+            alternatives.iter().flat_map(|alt| alt.choices.iter()),
+            selector_range,
+            &selector.pos,
+            &ctyp.describe(),
+            diagnostics,
+        );
+    }
+
+    /// Check that static choices of a discrete type do not overlap and that they cover the range
+    /// Without a range only the gaps between the choices are reported
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_choice_coverage<'c>(
+        &self,
+        kind: &str,
+        base: BaseType<'a>,
+        choices: impl Iterator<Item = &'c WithPos<Choice>>,
+        range: Option<StaticRange>,
+        missing_pos: &SrcPos,
+        target: &str,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut has_others = false;
+        let mut covered: Vec<(i64, i64, &SrcPos)> = Vec::new();
+        for choice in choices {
+            let choice_range = match choice.item {
+                Choice::Expression(ref expr) => self.static_choice_range(base, expr),
+                Choice::DiscreteRange(ref drange) => self.static_drange(base, drange),
+                Choice::Others => {
+                    has_others = true;
+                    continue;
+                }
+            };
+
+            let Some(choice_range) = choice_range else {
+                // Not locally static, nothing more can be said
+                return;
+            };
+
+            if choice_range.is_null() {
+                continue;
+            }
+
+            let (low, high) = (choice_range.low(), choice_range.high());
+            if let Some((_, _, prev_pos)) = covered
+                .iter()
+                .find(|(prev_low, prev_high, _)| low <= *prev_high && *prev_low <= high)
+            {
+                diagnostics.push(
+                    Diagnostic::error(
+                        &choice.pos,
+                        format!("{kind} overlaps with a previous choice"),
+                    )
+                    .related(*prev_pos, "Previously covered here"),
+                );
+            }
+            covered.push((low, high, &choice.pos));
+        }
+
+        if has_others || covered.is_empty() {
+            return;
+        }
+
+        let mut intervals: Vec<(i64, i64)> = covered.iter().map(|(l, h, _)| (*l, *h)).collect();
+        intervals.sort();
+
+        // Without a static range only gaps between choices can be known
+        let (low, high) = if let Some(range) = range.filter(|range| !range.is_null()) {
+            (range.low(), range.high())
+        } else {
+            (
+                intervals[0].0,
+                intervals.iter().map(|(_, h)| *h).max().unwrap(),
+            )
+        };
+
+        let mut missing = Vec::new();
+        let mut next = low;
+        for (l, h) in intervals {
+            if l > next {
+                missing.push((next, (l - 1).min(high)));
+            }
+            next = next.max(h.saturating_add(1));
+            if next > high {
+                break;
+            }
+        }
+        if next <= high {
+            missing.push((next, high));
+        }
+
+        if missing.is_empty() {
+            return;
+        }
+
+        let describe = |value: i64| -> String {
+            if base.is_enum() {
+                base.enum_literals()
+                    .nth(value as usize)
+                    .map(|ent| ent.designator().to_string())
+                    .unwrap_or_else(|| value.to_string())
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut items = Vec::new();
+        for (l, h) in missing {
+            if base.is_enum() {
+                items.extend((l..=h).map(describe));
+            } else if l == h {
+                items.push(describe(l));
+            } else {
+                items.push(format!("{} to {}", describe(l), describe(h)));
+            }
+        }
+
+        const MAX_ITEMS: usize = 10;
+        let num_items = items.len();
+        let mut listing = items
+            .into_iter()
+            .take(MAX_ITEMS)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if num_items > MAX_ITEMS {
+            listing.push_str(&format!(" and {} more", num_items - MAX_ITEMS));
+        }
+
+        diagnostics.error(
+            missing_pos,
+            format!(
+                "Missing {} for {}: {}",
+                plural("choice", "choices", num_items),
+                target,
+                listing
+            ),
+        );
+    }
+
+    /// The static range of values of a case selector
+    pub fn selector_range(
+        &self,
+        selector: &WithPos<Expression>,
+        ctyp: TypeEnt<'a>,
+    ) -> Option<StaticRange> {
+        let base = ctyp.base();
+        if !base.is_discrete() {
+            return None;
+        }
+
+        let selector_subtype = self
+            .selector_subtype(selector)
+            .filter(|subtype| subtype.base() == base);
+        if base.is_enum() {
+            selector_subtype
+                .and_then(|subtype| subtype.static_range())
+                .or_else(|| TypeEnt::from(base).static_range())
+        } else {
+            selector_subtype.and_then(|subtype| subtype.static_range())
+        }
+    }
+
+    /// True if the static choices other than others cover all values of the range
+    pub fn choices_cover_range<'c>(
+        &self,
+        base: BaseType<'a>,
+        choices: impl Iterator<Item = &'c WithPos<Choice>>,
+        range: StaticRange,
+    ) -> bool {
+        let mut intervals = Vec::new();
+        for choice in choices {
+            let choice_range = match choice.item {
+                Choice::Expression(ref expr) => self.static_choice_range(base, expr),
+                Choice::DiscreteRange(ref drange) => self.static_drange(base, drange),
+                Choice::Others => continue,
+            };
+
+            let Some(choice_range) = choice_range else {
+                return false;
+            };
+
+            if !choice_range.is_null() {
+                intervals.push((choice_range.low(), choice_range.high()));
+            }
+        }
+        intervals.sort();
+
+        let mut next = range.low();
+        for (low, high) in intervals {
+            if low > next {
+                return false;
+            }
+            next = next.max(high.saturating_add(1));
+        }
+        next > range.high()
+    }
+
+    // A choice expression may also be the name of a subtype
+    fn static_choice_range(&self, base: BaseType<'a>, expr: &Expression) -> Option<StaticRange> {
+        if let Expression::Name(name) = expr {
+            if let Some(typ) = name
+                .get_suffix_reference()
+                .and_then(|id| TypeEnt::from_any(self.arena.get(id)))
+            {
+                return typ.static_range();
+            }
+        }
+
+        let value = self.static_discrete_value(base, expr)?;
+        Some(StaticRange::new(value, value, Direction::Ascending))
+    }
+
+    // The subtype whose values must be covered by the choices as given by LRM 10.9,
+    // the subtype of an object or the type mark of a qualified expression, type conversion
+    // or function call. The choices of any other selector must cover the base type
+    fn selector_subtype(&self, selector: &WithPos<Expression>) -> Option<Subtype<'a>> {
+        match selector.item {
+            Expression::Name(ref name) => self.selector_name_subtype(name),
+            Expression::Qualified(ref qexpr) => {
+                if qexpr.type_mark.item.attr.is_some() {
+                    return None;
+                }
+                let id = qexpr.type_mark.item.name.item.get_suffix_reference()?;
+                TypeEnt::from_any(self.arena.get(id)).map(Subtype::new)
+            }
+            _ => None,
+        }
+    }
+
+    fn selector_name_subtype(&self, name: &Name) -> Option<Subtype<'a>> {
+        match name {
+            Name::Designator(_) | Name::Selected(..) => {
+                let ent = self.arena.get(name.get_suffix_reference()?);
+                match ent.kind() {
+                    AnyEntKind::Object(obj) => Some(obj.subtype),
+                    // The subtype of a record element is given by the record type declaration
+                    AnyEntKind::ElementDeclaration(subtype) => Some(*subtype),
+                    // A function call without arguments
+                    AnyEntKind::Overloaded(_) => OverloadedEnt::from_any(ent)?
+                        .return_type()
+                        .map(Subtype::new),
+                    _ => None,
+                }
+            }
+            Name::CallOrIndexed(call) => {
+                let ent = self.arena.get(call.name.item.get_suffix_reference()?);
+                match ent.kind() {
+                    AnyEntKind::Type(_) => TypeEnt::from_any(ent).map(Subtype::new),
+                    AnyEntKind::Overloaded(_) => OverloadedEnt::from_any(ent)?
+                        .return_type()
+                        .map(Subtype::new),
+                    // An element of an array object has the element subtype of the array
+                    _ => {
+                        let subtype = self.selector_name_subtype(&call.name.item)?;
+                        let (elem_type, _) = subtype.type_mark().array_type()?;
+                        Some(Subtype::new(elem_type))
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
     pub fn analyze_assoc_elems(
         &self,
         scope: &Scope<'a>,
@@ -0,0 +1,179 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! The implicit sensitivity list of process(all)
+
+use super::analyze::*;
+use super::combinational_loop::is_signal;
+use super::named_entity::*;
+use super::read_before_write::assigned_base;
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::data::*;
+use fnv::FnvHashSet;
+
+impl<'a> AnalyzeContext<'a> {
+    /// A process with a sensitivity list, including process(all), has an implicit
+    /// wait statement and may not contain an explicit one
+    pub fn check_no_wait_statement(
+        &self,
+        statements: &mut [LabeledSequentialStatement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        for_each_statement(statements, &mut |statement| {
+            if matches!(statement.statement.item, SequentialStatement::Wait(..)) {
+                diagnostics.error(
+                    &statement.statement.pos,
+                    "A process with a sensitivity list cannot contain a wait statement",
+                );
+            }
+        });
+    }
+
+    /// The signals read by the statements of a process in order of first occurrence
+    /// This is the implicit sensitivity list of process(all)
+    pub fn implicit_sensitivity_set(
+        &self,
+        statements: &mut [LabeledSequentialStatement],
+    ) -> Vec<EntRef<'a>> {
+        // The target of a signal assignment is not read
+        let mut targets = Vec::new();
+        for_each_statement(statements, &mut |statement| {
+            let target = match statement.statement.item {
+                SequentialStatement::SignalAssignment(ref mut assign) => &mut assign.target,
+                SequentialStatement::SignalForceAssignment(ref mut assign) => &mut assign.target,
+                SequentialStatement::SignalReleaseAssignment(ref mut assign) => &mut assign.target,
+                _ => return,
+            };
+            match target.item {
+                Target::Name(ref name) => {
+                    if let Some((pos, _)) = assigned_base(&target.pos, name) {
+                        targets.push(pos.clone());
+                    }
+                }
+                Target::Aggregate(..) => {
+                    let mut found = FindReadSignals::new(self, Vec::new());
+                    let _ = target.search(&mut found);
+                    targets.extend(found.found.into_iter().map(|(pos, _)| pos));
+                }
+            }
+        });
+
+        let mut found = FindReadSignals::new(self, targets);
+        for statement in statements.iter_mut() {
+            let _ = statement.search(&mut found);
+        }
+
+        let mut seen = FnvHashSet::default();
+        found
+            .found
+            .into_iter()
+            .filter(|(_, ent)| seen.insert(ent.id()))
+            .map(|(_, ent)| ent)
+            .collect()
+    }
+}
+
+/// Call `f` on each statement including the statements nested within if, case and loop statements
+pub(super) fn for_each_statement(
+    statements: &mut [LabeledSequentialStatement],
+    f: &mut impl FnMut(&mut LabeledSequentialStatement),
+) {
+    for statement in statements.iter_mut() {
+        f(statement);
+        match statement.statement.item {
+            SequentialStatement::If(ref mut ifstmt) => {
+                for cond in ifstmt.conds.conditionals.iter_mut() {
+                    for_each_statement(&mut cond.item, f);
+                }
+                if let Some(ref mut statements) = ifstmt.conds.else_item {
+                    for_each_statement(statements, f);
+                }
+            }
+            SequentialStatement::Case(ref mut case) => {
+                for alternative in case.alternatives.iter_mut() {
+                    for_each_statement(&mut alternative.item, f);
+                }
+            }
+            SequentialStatement::Loop(ref mut loop_stmt) => {
+                for_each_statement(&mut loop_stmt.statements, f);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collect the references to signals except at the positions of assignment targets
+struct FindReadSignals<'c, 'a> {
+    context: &'c AnalyzeContext<'a>,
+    ignore: Vec<SrcPos>,
+    found: Vec<(SrcPos, EntRef<'a>)>,
+}
+
+impl<'c, 'a> FindReadSignals<'c, 'a> {
+    fn new(context: &'c AnalyzeContext<'a>, ignore: Vec<SrcPos>) -> Self {
+        FindReadSignals {
+            context,
+            ignore,
+            found: Vec::new(),
+        }
+    }
+}
+
+impl Searcher for FindReadSignals<'_, '_> {
+    fn search_pos_with_ref(&mut self, pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        if let Some(id) = *reference {
+            let ent = self.context.arena.get(id);
+            if is_signal(ent) && !self.ignore.contains(pos) {
+                self.found.push((pos.clone(), ent));
+            }
+        }
+        SearchState::NotFinished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::tests::TestSetup;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn implicit_sensitivity_set_has_the_read_signals() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+signal a, b, c, d : natural;
+signal arr : integer_vector(0 to 3);
+        ",
+        );
+        let mut statement = test.concurrent_statement(
+            "
+process(all)
+  variable v : natural;
+begin
+  v := a;
+  if b = 0 then
+    c <= v;
+    arr(d) <= 0;
+  end if;
+end process;
+        ",
+        );
+        let process = assert_matches!(
+            statement.statement.item,
+            ConcurrentStatement::Process(ref mut process) => process
+        );
+
+        let signals: Vec<_> = test
+            .ctx()
+            .implicit_sensitivity_set(&mut process.statements)
+            .into_iter()
+            .map(|ent| ent.designator().to_string())
+            .collect();
+        assert_eq!(signals, vec!["a", "b", "d"]);
+    }
+}
@@ -151,25 +151,11 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             SequentialStatement::Assert(ref mut assert_stmt) => {
-                let AssertStatement {
-                    condition,
-                    report,
-                    severity,
-                } = assert_stmt;
-                self.boolean_expr(scope, condition, diagnostics)?;
-                if let Some(expr) = report {
-                    self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
-                }
-                if let Some(expr) = severity {
-                    self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
-                }
+                self.analyze_assert_statement(scope, assert_stmt, diagnostics)?;
             }
             SequentialStatement::Report(ref mut report_stmt) => {
                 let ReportStatement { report, severity } = report_stmt;
-                self.expr_with_ttyp(scope, self.string(), report, diagnostics)?;
-                if let Some(expr) = severity {
-                    self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
-                }
+                self.analyze_report(scope, Some(report), severity, diagnostics)?;
             }
             SequentialStatement::Exit(ref mut exit_stmt) => {
                 let ExitStatement {
@@ -227,7 +213,7 @@ impl<'a> AnalyzeContext<'a> {
             }
             SequentialStatement::Case(ref mut case_stmt) => {
                 let CaseStatement {
-                    is_matching: _,
+                    is_matching,
                     expression,
                     alternatives,
                     end_label_pos: _,
@@ -238,6 +224,13 @@ impl<'a> AnalyzeContext<'a> {
                     self.choice_with_ttyp(scope, ctyp, choices, diagnostics)?;
                     self.analyze_sequential_part(scope, parent, item, diagnostics)?;
                 }
+
+                // Matching case statements may have overlapping don't care choices
+                if let Some(ctyp) = ctyp {
+                    if !*is_matching {
+                        self.check_case_choices(expression, ctyp, alternatives, diagnostics);
+                    }
+                }
             }
             SequentialStatement::Loop(ref mut loop_stmt) => {
                 let LoopStatement {
@@ -247,7 +240,8 @@ impl<'a> AnalyzeContext<'a> {
                 } = loop_stmt;
                 match iteration_scheme {
                     Some(IterationScheme::For(ref mut index, ref mut drange)) => {
-                        let typ = as_fatal(self.drange_type(scope, drange, diagnostics))?;
+                        let typ =
+                            as_fatal(self.drange_loop_parameter_type(scope, drange, diagnostics))?;
                         let region = scope.nested();
                         region.add(
                             self.arena
@@ -292,29 +286,63 @@ impl<'a> AnalyzeContext<'a> {
             SequentialStatement::SignalForceAssignment(ref mut assign) => {
                 let SignalForceAssignment {
                     target,
-                    force_mode: _,
+                    force_mode,
                     rhs,
                 } = assign;
                 self.analyze_expr_assignment(
                     scope,
                     target,
-                    AssignmentType::Signal,
+                    AssignmentType::Force(*force_mode),
                     rhs,
                     diagnostics,
                 )?;
             }
             SequentialStatement::SignalReleaseAssignment(ref mut assign) => {
-                let SignalReleaseAssignment {
+                let SignalReleaseAssignment { target, force_mode } = assign;
+                as_fatal(self.resolve_target(
+                    scope,
                     target,
-                    force_mode: _,
-                } = assign;
-                as_fatal(self.resolve_target(scope, target, AssignmentType::Signal, diagnostics))?;
+                    AssignmentType::Release(*force_mode),
+                    diagnostics,
+                ))?;
             }
             SequentialStatement::Null => {}
         }
         Ok(())
     }
 
+    /// The condition must be boolean, the report a string and the severity a severity_level
+    pub fn analyze_assert_statement(
+        &self,
+        scope: &Scope<'a>,
+        assert_stmt: &mut AssertStatement,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let AssertStatement {
+            condition,
+            report,
+            severity,
+        } = assert_stmt;
+        self.boolean_expr(scope, condition, diagnostics)?;
+        self.analyze_report(scope, report.as_mut(), severity, diagnostics)
+    }
+
+    fn analyze_report(
+        &self,
+        scope: &Scope<'a>,
+        report: Option<&mut WithPos<Expression>>,
+        severity: &mut Option<WithPos<Expression>>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        if let Some(expr) = report {
+            self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
+        }
+        if let Some(expr) = severity {
+            self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
+        }
+        Ok(())
+    }
+
     fn check_loop_label(
         &self,
         scope: &Scope<'a>,
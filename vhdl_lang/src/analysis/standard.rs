@@ -47,6 +47,24 @@ impl UniversalTypes {
     }
 }
 
+/// The predefined types of the standard package
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum StdType {
+    Boolean,
+    BooleanVector,
+    Bit,
+    BitVector,
+    Character,
+    String,
+    Integer,
+    Natural,
+    Real,
+    Time,
+    FileOpenKind,
+    FileOpenStatus,
+    SeverityLevel,
+}
+
 pub(crate) struct StandardTypes {
     pub boolean: EntityId,
     pub boolean_vector: EntityId,
@@ -158,6 +176,26 @@ impl StandardTypes {
     }
 }
 
+impl StandardTypes {
+    pub fn get(&self, typ: StdType) -> EntityId {
+        match typ {
+            StdType::Boolean => self.boolean,
+            StdType::BooleanVector => self.boolean_vector,
+            StdType::Bit => self.bit,
+            StdType::BitVector => self.bit_vector,
+            StdType::Character => self.character,
+            StdType::String => self.string,
+            StdType::Integer => self.integer,
+            StdType::Natural => self.natural,
+            StdType::Real => self.real,
+            StdType::Time => self.time,
+            StdType::FileOpenKind => self.file_open_kind,
+            StdType::FileOpenStatus => self.file_open_status,
+            StdType::SeverityLevel => self.severity_level,
+        }
+    }
+}
+
 impl<'a> AnalyzeContext<'a> {
     fn ident(&self, name: &str) -> Designator {
         Designator::Identifier(self.root.symbol_utf8(name))
@@ -167,33 +205,37 @@ impl<'a> AnalyzeContext<'a> {
         self.root.standard_types.as_ref().unwrap()
     }
 
+    pub(crate) fn std_type(&self, typ: StdType) -> TypeEnt<'a> {
+        self.arena.get_type(self.standard_types().get(typ))
+    }
+
     pub(crate) fn string(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().string)
+        self.std_type(StdType::String)
     }
 
     pub(crate) fn boolean(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().boolean)
+        self.std_type(StdType::Boolean)
     }
 
     pub(crate) fn boolean_vector(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().boolean_vector)
+        self.std_type(StdType::BooleanVector)
     }
 
     pub(crate) fn bit(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().bit)
+        self.std_type(StdType::Bit)
     }
 
     pub(crate) fn bit_vector(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().bit_vector)
+        self.std_type(StdType::BitVector)
     }
 
     pub(crate) fn natural(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().natural)
+        self.std_type(StdType::Natural)
     }
 
     #[allow(dead_code)]
     pub(crate) fn character(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().character)
+        self.std_type(StdType::Character)
     }
 
     pub(crate) fn universal_integer(&self) -> BaseType<'a> {
@@ -209,27 +251,27 @@ impl<'a> AnalyzeContext<'a> {
     }
 
     pub(crate) fn integer(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().integer)
+        self.std_type(StdType::Integer)
     }
 
     pub(crate) fn real(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().real)
+        self.std_type(StdType::Real)
     }
 
     pub(crate) fn time(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().time)
+        self.std_type(StdType::Time)
     }
 
-    fn file_open_kind(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().file_open_kind)
+    pub(crate) fn file_open_kind(&self) -> TypeEnt<'a> {
+        self.std_type(StdType::FileOpenKind)
     }
 
     fn file_open_status(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().file_open_status)
+        self.std_type(StdType::FileOpenStatus)
     }
 
     pub(crate) fn severity_level(&self) -> TypeEnt<'a> {
-        self.arena.get_type(self.standard_types().severity_level)
+        self.std_type(StdType::SeverityLevel)
     }
 
     /// Create implicit MAXIMUM/MINIMUM
@@ -453,6 +495,7 @@ impl<'a> AnalyzeContext<'a> {
                             iface: Some(ObjectInterface::Parameter(Mode::Out)),
                             subtype: Subtype::new(type_mark),
                             has_default: false,
+                            signal_kind: None,
                         }),
                     ),
                 ],
@@ -537,6 +580,7 @@ impl<'a> AnalyzeContext<'a> {
                     iface: Some(ObjectInterface::Parameter(Mode::InOut)),
                     subtype: Subtype::new(type_ent.to_owned()),
                     has_default: false,
+                    signal_kind: None,
                 }),
             )],
             None,
@@ -763,7 +807,7 @@ impl<'a> AnalyzeContext<'a> {
 
         let is_scalar = matches!(
             elem_type.base().kind(),
-            Type::Integer | Type::Real | Type::Physical | Type::Enum(_)
+            Type::Integer(..) | Type::Real | Type::Physical | Type::Enum(_)
         );
 
         let is_one_dimensional = indexes.len() == 1;
@@ -908,6 +952,22 @@ impl<'a> AnalyzeContext<'a> {
                 .into_iter()
             });
 
+            let shift_ops = [
+                Operator::SLL,
+                Operator::SRL,
+                Operator::SLA,
+                Operator::SRA,
+                Operator::ROL,
+                Operator::ROR,
+            ];
+
+            // A op INTEGER -> A
+            let implicits = implicits.chain(
+                shift_ops
+                    .into_iter()
+                    .map(|op| self.binary(op, atyp, atyp, self.integer(), atyp)),
+            );
+
             for ent in implicits {
                 // This is safe because the standard package is analyzed in a single thread
                 unsafe {
@@ -1,5 +1,11 @@
+use super::analyze::AnalyzeContext;
+use super::named_entity::*;
 use crate::analysis::static_expression::BitStringConversionError::EmptySignedExpansion;
-use crate::ast::{BaseSpecifier, BitString};
+use crate::ast::{
+    AbstractLiteral, AttributeDesignator, AttributeName, BaseSpecifier, BitString, Designator,
+    Direction, DiscreteRange, Expression, Literal, Name, ObjectClass, Operator, PhysicalLiteral,
+    Range, RangeAttribute, TypeAttribute,
+};
 use crate::Latin1String;
 use itertools::Itertools;
 use std::cmp::Ordering;
@@ -295,6 +301,383 @@ pub(crate) fn bit_string_to_string(
     }
 }
 
+impl<'a> AnalyzeContext<'a> {
+    /// Evaluate a locally static expression of a discrete type that has already been analyzed
+    /// Returns the integer value or the position of an enumeration literal
+    pub fn static_discrete_value(&self, typ: BaseType<'a>, expr: &Expression) -> Option<i64> {
+        match expr {
+            Expression::Literal(Literal::AbstractLiteral(AbstractLiteral::Integer(value)))
+                if typ.is_any_integer() =>
+            {
+                i64::try_from(*value).ok()
+            }
+            Expression::Literal(Literal::Character(chr)) => typ
+                .enum_literals()
+                .position(|ent| ent.designator() == &Designator::Character(*chr))
+                .map(|pos| pos as i64),
+            Expression::Name(name) => self.static_name_value(typ, name),
+            Expression::Qualified(qexpr) => self.static_discrete_value(typ, &qexpr.expr.item),
+            Expression::Unary(op, inner) if typ.is_any_integer() => {
+                let value = self.static_discrete_value(typ, &inner.item)?;
+                match op.item.item {
+                    Operator::Plus => Some(value),
+                    Operator::Minus => value.checked_neg(),
+                    Operator::Abs => value.checked_abs(),
+                    _ => None,
+                }
+            }
+            Expression::Binary(op, left, right) if typ.is_any_integer() => {
+                let left = self.static_discrete_value(typ, &left.item)?;
+                let right = self.static_discrete_value(typ, &right.item)?;
+                match op.item.item {
+                    Operator::Plus => left.checked_add(right),
+                    Operator::Minus => left.checked_sub(right),
+                    Operator::Times => left.checked_mul(right),
+                    Operator::Div => left.checked_div(right),
+                    Operator::Rem => left.checked_rem(right),
+                    Operator::Mod => {
+                        // The sign of the result follows the right operand
+                        let rem = left.checked_rem(right)?;
+                        if rem != 0 && (rem < 0) != (right < 0) {
+                            Some(rem + right)
+                        } else {
+                            Some(rem)
+                        }
+                    }
+                    Operator::Pow => left.checked_pow(u32::try_from(right).ok()?),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluate a static time expression such as 2 ns in femtoseconds
+    pub fn static_time_value(&self, expr: &Expression) -> Option<i64> {
+        match expr {
+            Expression::Literal(Literal::Physical(PhysicalLiteral { value, unit })) => {
+                let fs = self.time_unit_value(unit.reference?)?;
+                match value {
+                    AbstractLiteral::Integer(value) => i64::try_from(*value).ok()?.checked_mul(fs),
+                    AbstractLiteral::Real(value) => Some((value * fs as f64) as i64),
+                }
+            }
+            // A unit name on its own denotes one unit
+            Expression::Name(name) => self.time_unit_value(name.get_suffix_reference()?),
+            _ => None,
+        }
+    }
+
+    // The value of a unit of the predefined type TIME in femtoseconds
+    fn time_unit_value(&self, unit: EntityId) -> Option<i64> {
+        let ent = self.arena.get(unit);
+        let AnyEntKind::PhysicalLiteral(typ) = ent.kind() else {
+            return None;
+        };
+        if typ.base() != self.time().base() {
+            return None;
+        }
+        let Designator::Identifier(name) = ent.designator() else {
+            return None;
+        };
+
+        let value = match name.name_utf8().to_lowercase().as_str() {
+            "fs" => 1,
+            "ps" => 1_000,
+            "ns" => 1_000_000,
+            "us" => 1_000_000_000,
+            "ms" => 1_000_000_000_000,
+            "sec" => 1_000_000_000_000_000,
+            "min" => 60_000_000_000_000_000,
+            "hr" => 3_600_000_000_000_000_000,
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn static_name_value(&self, typ: BaseType<'a>, name: &Name) -> Option<i64> {
+        if let Name::Attribute(attr) = name {
+            return self.static_attribute_value(typ, attr);
+        }
+
+        let ent = self.arena.get(name.get_suffix_reference()?);
+        if let AnyEntKind::Overloaded(Overloaded::EnumLiteral(_)) = ent.kind() {
+            let base = OverloadedEnt::from_any(ent)?.return_type()?.base();
+            if base != typ {
+                return None;
+            }
+            base.enum_literal_pos(ent)
+        } else {
+            None
+        }
+    }
+
+    // A bound of a scalar type with a static range such as integer'high
+    // or a function attribute of a discrete type such as state_t'succ(idle)
+    fn static_attribute_value(&self, typ: BaseType<'a>, attr: &AttributeName) -> Option<i64> {
+        if attr.signature.is_some() {
+            return None;
+        }
+
+        let prefix = self.static_attribute_prefix(&attr.name.item)?;
+        let range = prefix.static_range()?;
+
+        let Some(ref expr) = attr.expr else {
+            // The range of an array subtype is the range of its first index
+            let index = prefix
+                .base()
+                .array_type()
+                .map(|(_, indexes)| indexes.first());
+            let range_typ = match index {
+                Some(index) => (*index?)?,
+                None => prefix.base(),
+            };
+            let bounds_match =
+                range_typ == typ || (range_typ.is_any_integer() && typ.is_any_integer());
+
+            return match attr.attr.item {
+                AttributeDesignator::Left if bounds_match => Some(range.left),
+                AttributeDesignator::Right if bounds_match => Some(range.right),
+                AttributeDesignator::High if bounds_match => Some(range.high()),
+                AttributeDesignator::Low if bounds_match => Some(range.low()),
+                AttributeDesignator::Length if index.is_some() && typ.is_any_integer() => {
+                    Some(range.length())
+                }
+                _ => None,
+            };
+        };
+
+        // The position of an enum literal is also its value
+        let value = match attr.attr.item {
+            AttributeDesignator::Pos if typ.is_any_integer() => {
+                self.static_discrete_value(prefix.base(), &expr.item)?
+            }
+            AttributeDesignator::Val if prefix.base() == typ => {
+                self.static_discrete_value(self.universal_integer(), &expr.item)?
+            }
+            AttributeDesignator::Succ | AttributeDesignator::Pred if prefix.base() == typ => {
+                let value = self.static_discrete_value(typ, &expr.item)?;
+                if attr.attr.item == AttributeDesignator::Succ {
+                    value.checked_add(1)?
+                } else {
+                    value.checked_sub(1)?
+                }
+            }
+            AttributeDesignator::LeftOf | AttributeDesignator::RightOf if prefix.base() == typ => {
+                let value = self.static_discrete_value(typ, &expr.item)?;
+                let step = match range.direction {
+                    Direction::Ascending => 1,
+                    Direction::Descending => -1,
+                };
+                if attr.attr.item == AttributeDesignator::RightOf {
+                    value.checked_add(step)?
+                } else {
+                    value.checked_sub(step)?
+                }
+            }
+            _ => return None,
+        };
+
+        // The argument or result must be within the range of the prefix
+        range.contains(value).then_some(value)
+    }
+
+    // The subtype denoted by a type mark or by the 'subtype attribute of an object
+    fn static_attribute_prefix(&self, name: &Name) -> Option<Subtype<'a>> {
+        if let Name::Attribute(attr) = name {
+            if attr.attr.item != AttributeDesignator::Type(TypeAttribute::Subtype)
+                || attr.signature.is_some()
+                || attr.expr.is_some()
+            {
+                return None;
+            }
+            return self.static_object_subtype(&attr.name.item);
+        }
+
+        if let Some(typ) = name
+            .get_suffix_reference()
+            .and_then(|id| TypeEnt::from_any(self.arena.get(id)))
+        {
+            return Some(Subtype::new(typ));
+        }
+
+        // The bounds of an array object such as arr(0 to 3)'length
+        self.static_object_subtype(name)
+            .filter(|subtype| subtype.base().array_type().is_some())
+    }
+
+    /// The subtype of an object name including the constraint of a slice
+    pub(crate) fn static_object_subtype(&self, name: &Name) -> Option<Subtype<'a>> {
+        match name {
+            Name::Designator(_) | Name::Selected(..) => {
+                match self.arena.get(name.get_suffix_reference()?).kind() {
+                    AnyEntKind::Object(obj) => Some(obj.subtype),
+                    AnyEntKind::ElementDeclaration(subtype) => Some(*subtype),
+                    _ => None,
+                }
+            }
+            Name::Slice(prefix, drange) => {
+                let subtype = self.static_object_subtype(&prefix.item)?;
+                let (_, indexes) = subtype.base().array_type()?;
+                let index_typ = (*indexes.first()?)?;
+                Some(
+                    Subtype::new(subtype.type_mark)
+                        .with_index_constraint()
+                        .with_range(self.static_drange(index_typ, drange)),
+                )
+            }
+            Name::CallOrIndexed(call) => {
+                let subtype = self.static_object_subtype(&call.name.item)?;
+                let (elem_type, indexes) = subtype.base().array_type()?;
+                (call.parameters.len() == indexes.len()
+                    && call.parameters.iter().all(|assoc| assoc.formal.is_none()))
+                .then(|| Subtype::new(elem_type))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn static_range(&self, typ: BaseType<'a>, range: &Range) -> Option<StaticRange> {
+        match range {
+            Range::Range(constraint) => Some(StaticRange::new(
+                self.static_discrete_value(typ, &constraint.left_expr.item)?,
+                self.static_discrete_value(typ, &constraint.right_expr.item)?,
+                constraint.direction,
+            )),
+            Range::Attribute(attr) => self.static_range_attribute(typ, attr),
+        }
+    }
+
+    // The range of an array such as data'range or data'reverse_range
+    // Only the range of the first index is known statically
+    fn static_range_attribute(
+        &self,
+        typ: BaseType<'a>,
+        attr: &AttributeName,
+    ) -> Option<StaticRange> {
+        let AttributeDesignator::Range(range_attr) = attr.attr.item else {
+            return None;
+        };
+        if attr.signature.is_some() {
+            return None;
+        }
+        if let Some(ref expr) = attr.expr {
+            if self.static_discrete_value(self.universal_integer(), &expr.item)? != 1 {
+                return None;
+            }
+        }
+
+        let prefix = self.static_attribute_prefix(&attr.name.item)?;
+        let (_, indexes) = prefix.base().array_type()?;
+        let index_typ = (*indexes.first()?)?;
+        if index_typ != typ && !(index_typ.is_any_integer() && typ.is_any_integer()) {
+            return None;
+        }
+
+        let range = prefix.static_range()?;
+        Some(match range_attr {
+            RangeAttribute::Range => range,
+            RangeAttribute::ReverseRange => StaticRange::new(
+                range.right,
+                range.left,
+                match range.direction {
+                    Direction::Ascending => Direction::Descending,
+                    Direction::Descending => Direction::Ascending,
+                },
+            ),
+        })
+    }
+
+    /// A range with bounds that depend on generic constants such as 0 to N - 1
+    pub fn generic_range(&self, typ: BaseType<'a>, range: &Range) -> Option<GenericRange> {
+        let Range::Range(constraint) = range else {
+            return None;
+        };
+
+        let left = self.range_bound(typ, &constraint.left_expr.item)?;
+        let right = self.range_bound(typ, &constraint.right_expr.item)?;
+        if matches!(
+            (left, right),
+            (RangeBound::Static(_), RangeBound::Static(_))
+        ) {
+            return None;
+        }
+
+        Some(GenericRange {
+            left,
+            right,
+            direction: constraint.direction,
+        })
+    }
+
+    pub fn generic_drange(
+        &self,
+        typ: BaseType<'a>,
+        drange: &DiscreteRange,
+    ) -> Option<GenericRange> {
+        match drange {
+            DiscreteRange::Discrete(_, Some(range)) | DiscreteRange::Range(range) => {
+                self.generic_range(typ, range)
+            }
+            DiscreteRange::Discrete(_, None) => None,
+        }
+    }
+
+    fn range_bound(&self, typ: BaseType<'a>, expr: &Expression) -> Option<RangeBound> {
+        if let Some(value) = self.static_discrete_value(typ, expr) {
+            return Some(RangeBound::Static(value));
+        }
+
+        match expr {
+            Expression::Name(name) => {
+                let ent = self.arena.get(name.get_suffix_reference()?);
+                let obj = ObjectEnt::from_any(ent)?;
+                (obj.class() == ObjectClass::Constant && obj.object().is_generic())
+                    .then_some(RangeBound::Generic(ent.id(), 0))
+            }
+            Expression::Binary(op, left, right) if typ.is_any_integer() => {
+                let left = self.range_bound(typ, &left.item)?;
+                let right = self.range_bound(typ, &right.item)?;
+                match (op.item.item, left, right) {
+                    (
+                        Operator::Plus,
+                        RangeBound::Generic(id, offset),
+                        RangeBound::Static(value),
+                    )
+                    | (
+                        Operator::Plus,
+                        RangeBound::Static(value),
+                        RangeBound::Generic(id, offset),
+                    ) => Some(RangeBound::Generic(id, offset.checked_add(value)?)),
+                    (
+                        Operator::Minus,
+                        RangeBound::Generic(id, offset),
+                        RangeBound::Static(value),
+                    ) => Some(RangeBound::Generic(id, offset.checked_sub(value)?)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn static_drange(&self, typ: BaseType<'a>, drange: &DiscreteRange) -> Option<StaticRange> {
+        match drange {
+            DiscreteRange::Discrete(type_mark, range) => {
+                if let Some(range) = range {
+                    self.static_range(typ, range)
+                } else {
+                    let ent = self
+                        .arena
+                        .get(type_mark.item.name.item.get_suffix_reference()?);
+                    TypeEnt::from_any(ent)?.static_range()
+                }
+            }
+            DiscreteRange::Range(range) => self.static_range(typ, range),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_mod {
     use crate::analysis::static_expression::{bit_string_to_string, BitStringConversionError};
@@ -38,14 +38,23 @@ impl<'a> AnalyzeContext<'a> {
         assignment_type: AssignmentType,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> EvalResult<TypeEnt<'a>> {
-        let object_name = self.resolve_object_name(
-            scope,
-            target_pos,
-            target,
-            "may not be the target of an assignment",
-            diagnostics,
-        )?;
-        if !is_valid_assignment_target(&object_name.base) {
+        let resolved = self.name_resolve(scope, target_pos, target, diagnostics)?;
+        let ResolvedName::ObjectName(ref object_name) = resolved else {
+            diagnostics.error(
+                target_pos,
+                format!(
+                    "{} may not be the target of an assignment",
+                    resolved.describe()
+                ),
+            );
+            return Err(EvalError::Unknown);
+        };
+        // A port of mode in may still be forced
+        let is_forced_input = matches!(
+            assignment_type,
+            AssignmentType::Force(..) | AssignmentType::Release(..)
+        ) && object_name.base.class() != ObjectClass::Constant;
+        if !(self.is_assignable(&resolved) || is_forced_input) {
             diagnostics.push(Diagnostic::error(
                 target_pos,
                 format!(
@@ -65,6 +74,15 @@ impl<'a> AnalyzeContext<'a> {
         }
         Ok(object_name.type_mark())
     }
+
+    /// Whether the resolved name denotes an object that may be the target of an assignment
+    /// The result of a function call is never assignable
+    pub fn is_assignable(&self, resolved: &ResolvedName<'a>) -> bool {
+        match resolved {
+            ResolvedName::ObjectName(object_name) => is_writable(&object_name.base),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -73,6 +91,10 @@ pub enum AssignmentType {
     Signal,
     // Assignment with :=
     Variable,
+    // Assignment with <= force
+    Force(Option<ForceMode>),
+    // Assignment with <= release
+    Release(Option<ForceMode>),
 }
 
 impl AssignmentType {
@@ -80,12 +102,18 @@ impl AssignmentType {
         match self {
             AssignmentType::Signal => "signal",
             AssignmentType::Variable => "variable",
+            AssignmentType::Force(Some(ForceMode::In)) => "force in",
+            AssignmentType::Force(Some(ForceMode::Out)) => "force out",
+            AssignmentType::Force(None) => "force",
+            AssignmentType::Release(Some(ForceMode::In)) => "release in",
+            AssignmentType::Release(Some(ForceMode::Out)) => "release out",
+            AssignmentType::Release(None) => "release",
         }
     }
 }
 
-/// Check that the assignment target is a writable object and not constant or input only
-fn is_valid_assignment_target(base: &ObjectBase) -> bool {
+/// An object is writable unless it is a constant or input only
+fn is_writable(base: &ObjectBase) -> bool {
     base.class() != ObjectClass::Constant && !matches!(base.mode(), Some(Mode::In))
 }
 
@@ -97,5 +125,10 @@ fn is_valid_assignment_type(base: &ObjectBase, assignment_type: AssignmentType)
         AssignmentType::Variable => {
             matches!(class, ObjectClass::Variable | ObjectClass::SharedVariable)
         }
+        // The driving value of a port of mode in cannot be forced
+        AssignmentType::Force(force_mode) | AssignmentType::Release(force_mode) => {
+            matches!(class, ObjectClass::Signal)
+                && !(force_mode == Some(ForceMode::Out) && matches!(base.mode(), Some(Mode::In)))
+        }
     }
 }
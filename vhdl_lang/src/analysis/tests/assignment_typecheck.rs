@@ -5,6 +5,7 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::VhdlStandard;
 
 #[test]
 fn overloaded_name_may_not_be_assignment_target() {
@@ -304,6 +305,90 @@ end architecture;
     check_diagnostics(diagnostics, expected);
 }
 
+#[test]
+fn mode_view_elements_as_assignment_target() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t is record
+    req : boolean;
+    ack : boolean;
+  end record;
+
+  view rec_view of rec_t is
+    req : in;
+    ack : out;
+  end view;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+  port (
+    bus_if : view rec_view of rec_t
+  );
+end entity;
+
+architecture a of ent is
+begin
+  bus_if.ack <= bus_if.req;
+  bus_if.req <= true;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("bus_if.req", 2),
+            "element of interface signal 'bus_if' of mode in may not be the target of an assignment",
+        )],
+    );
+}
+
+#[test]
+fn mode_view_must_cover_record_elements() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t is record
+    req : boolean;
+    ack : boolean;
+  end record;
+
+  view rec_view of rec_t is
+    req : in;
+    missing : out;
+  end view;
+
+  view bad_view of boolean is
+  end view;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("missing"),
+                "No declaration of 'missing' within record type 'rec_t'",
+            ),
+            Diagnostic::error(code.s1("rec_view"), "Missing mode for record element 'ack'"),
+            Diagnostic::error(
+                code.s("boolean", 3),
+                "A mode view must be of a record type, got type 'BOOLEAN'",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn checks_signal_vs_variable_assignment_target() {
     let mut builder = LibraryBuilder::new();
@@ -543,7 +628,7 @@ end architecture;
             ),
             Diagnostic::error(
                 code.s1("vptr.all.all").s1("vptr.all"),
-                "record type 'rec_t' cannot be accessed with .all",
+                "record type 'rec_t' is not an access type and cannot be dereferenced with .all",
             ),
         ],
     );
@@ -580,3 +665,374 @@ end package body Test;",
         )],
     )
 }
+
+#[test]
+fn assignment_of_matching_static_length() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal s8 : bit_vector(7 downto 0);
+  signal s16 : bit_vector(15 downto 0);
+  signal s : bit_vector(0 to 7);
+begin
+  s8 <= s16(7 downto 0);
+  s16(15 downto 8) <= s8;
+  s <= s8;
+  s8 <= \"01010101\";
+
+  process
+    variable v : bit_vector(1 to 8);
+  begin
+    v := s8;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn assignment_of_mismatching_static_length() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal s8 : bit_vector(7 downto 0);
+  signal s16 : bit_vector(15 downto 0);
+begin
+  s8 <= s16;
+  s16(15 downto 4) <= s8;
+  s8 <= \"0101\";
+
+  process
+    variable v : bit_vector(1 to 4);
+  begin
+    v := s8;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("s8 <= s16").s1("s16"),
+                "Length mismatch, target has length 8 but value has length 16",
+            ),
+            Diagnostic::error(
+                code.s1("s16(15 downto 4) <= s8").s1("s8"),
+                "Length mismatch, target has length 12 but value has length 8",
+            ),
+            Diagnostic::error(
+                code.s1("\"0101\""),
+                "Length mismatch, target has length 8 but value has length 4",
+            ),
+            Diagnostic::error(
+                code.s1("v := s8").s1("s8"),
+                "Length mismatch, target has length 4 but value has length 8",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn actual_is_checked_against_constrained_formal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+end package;
+
+package body pkg is
+  function last(s : string) return character is
+  begin
+    return s(s'low + s'length - 1);
+  end function;
+
+  procedure proc3(s : string(1 to 3)) is
+  begin
+  end procedure;
+
+  procedure proc is
+    constant c : string := \"hello\";
+    variable v : string(1 to c'length);
+    variable ch : character;
+  begin
+    v := c;
+    -- The unconstrained formal takes the index range of the actual
+    ch := last(c);
+    ch := last(v(1 to 2));
+    proc3(v(1 to 3));
+    proc3(c);
+    proc3(s => \"ab\");
+  end procedure;
+end package body;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("proc3(c)").s1("(c").s1("c"),
+                "Length mismatch, formal has length 3 but actual has length 5",
+            ),
+            Diagnostic::error(
+                code.s1("\"ab\""),
+                "Length mismatch, formal has length 3 but actual has length 2",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn unconstrained_constant_is_constrained_by_initial_value() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  subtype idx_t is integer range 0 to 15;
+  type word_t is array (idx_t range <>) of bit;
+
+  constant c : string(1 to 5) := \"hello\";
+  constant c2 : string := c;
+  constant w8 : word_t := \"00001111\";
+begin
+  process
+    variable v : string(1 to 3);
+    variable w : word_t(0 to 3);
+  begin
+    v := c2;
+    v := c2(1 to 3);
+    w := w8;
+    w := w8(4 to 7);
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("v := c2;").s1("c2"),
+                "Length mismatch, target has length 3 but value has length 5",
+            ),
+            Diagnostic::error(
+                code.s1("w := w8;").s1("w8"),
+                "Length mismatch, target has length 4 but value has length 8",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn force_and_release_assignment() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    inp : in bit;
+    outp : out bit);
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process
+  begin
+    sig <= force '1';
+    sig <= force out '0';
+    inp <= force in '1';
+    outp <= force out '1';
+    wait;
+    sig <= release;
+    inp <= release;
+    outp <= release out;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn force_assignment_type_and_mode_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    inp : in bit);
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process
+    variable var : bit;
+  begin
+    sig <= force 1;
+    inp <= force out '1';
+    inp <= release out;
+    var <= force '1';
+    var <= release;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("force 1").s1("1"),
+                "integer literal does not match type 'BIT'",
+            ),
+            Diagnostic::error(
+                code.s1("inp <= force out").s1("inp"),
+                "interface signal 'inp' of mode in may not be the target of a force out assignment",
+            ),
+            Diagnostic::error(
+                code.s1("inp <= release out").s1("inp"),
+                "interface signal 'inp' of mode in may not be the target of a release out assignment",
+            ),
+            Diagnostic::error(
+                code.s1("var <= force").s1("var"),
+                "variable 'var' may not be the target of a force assignment",
+            ),
+            Diagnostic::error(
+                code.s1("var <= release").s1("var"),
+                "variable 'var' may not be the target of a release assignment",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn waveform_with_multiple_elements() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+  constant delay : time := 5 ns;
+begin
+  sig <= '0', '1' after 1 ns, '0' after 1500 ps, '1' after delay;
+
+  process
+  begin
+    sig <= '1' after 1 ns, '0' after 2 ns, '1' after 1.5 us;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn waveform_element_after_must_be_time() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  sig <= '1' after 1 ns, '0' after 2;
+  sig <= true after 1 ns;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("after 2").s1("2"),
+                "integer literal does not match physical type 'TIME'",
+            ),
+            Diagnostic::error(code.s1("true"), "'true' does not match type 'BIT'"),
+        ],
+    );
+}
+
+#[test]
+fn waveform_element_times_must_be_ascending() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  sig <= '1' after 2 ns, '0' after 1000 ps;
+  sig <= '1' after 1 ns, '0' after 1 ns;
+  sig <= '1', '0';
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("1000 ps"),
+                "Waveform element times must be in ascending order",
+            ),
+            Diagnostic::warning(
+                code.s("1 ns", 2),
+                "Waveform element times must be in ascending order",
+            ),
+            Diagnostic::warning(
+                code.s1("'1', '0'").s1("'0'"),
+                "Waveform element times must be in ascending order",
+            ),
+        ],
+    );
+}
@@ -455,3 +455,225 @@ end architecture;
         .search_reference(code.source(), code.s1("inport => sig").s1("sig").start())
         .is_some())
 }
+
+#[test]
+fn others_associates_remaining_formals_with_open() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent2 is
+port (
+    signal clk: in bit;
+    signal inport: in natural := 0;
+    signal outport: out natural  );
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal clk : bit;
+begin
+    inst: entity work.ent2
+        port map (
+        clk => clk,
+        others => open
+        );
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn others_may_not_leave_required_input_open() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+port (
+    signal clk: in bit;
+    signal inport: in natural;
+    signal outport: out natural  );
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal clk : bit;
+begin
+    inst: entity work.ent2
+        port map (
+        clk => clk,
+        others => open
+        );
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("others"),
+            "port 'inport' : in cannot be left open by others",
+        )
+        .related(code.s1("inport"), "Defined here")],
+    );
+}
+
+#[test]
+fn unconnected_formals_use_their_default_value() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent2 is
+generic (
+    width : natural := 8;
+    flag : boolean := width > 4);
+port (
+    signal clk: in bit;
+    signal inport: in natural := width;
+    signal outport: out natural  );
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal clk : bit;
+
+    procedure proc(a : natural := 1; b : natural := 2) is
+    begin
+    end procedure;
+begin
+    inst: entity work.ent2
+        generic map (
+        flag => open
+        )
+        port map (
+        clk => clk,
+        inport => open
+        );
+
+    main : process
+    begin
+        proc(b => 3);
+        proc(a => open);
+        wait;
+    end process;
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn default_value_is_typechecked() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+generic (
+    flag : boolean := 0);
+end entity;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("0"),
+            "integer literal does not match type 'BOOLEAN'",
+        )],
+    );
+}
+
+#[test]
+fn formals_without_default_may_not_be_left_open() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+generic (
+    width : natural);
+port (
+    signal inport: in natural;
+    signal outport: out natural  );
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+    procedure proc(a : natural) is
+    begin
+    end procedure;
+begin
+    inst: entity work.ent2
+        generic map (
+        width => open
+        )
+        port map (
+        inport => open,
+        outport => open
+        );
+
+    main : process
+    begin
+        proc(a => open);
+        wait;
+    end process;
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("width => open").s1("open"),
+                "generic 'width' without a default value cannot be left open",
+            )
+            .related(code.s1("width"), "Defined here"),
+            Diagnostic::error(
+                code.s1("inport => open").s1("open"),
+                "port 'inport' : in without a default value cannot be left open",
+            )
+            .related(code.s1("inport"), "Defined here"),
+            Diagnostic::error(
+                code.s1("a => open").s1("open"),
+                "parameter 'a' without a default value cannot be left open",
+            )
+            .related(code.s1("a : natural").s1("a"), "Defined here"),
+        ],
+    );
+}
@@ -0,0 +1,282 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn exhaustive_case_statement() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+subtype small_t is integer range 0 to 3;
+
+procedure proc(state : state_t; small : small_t; num : integer) is
+begin
+  case state is
+    when idle => null;
+    when busy | done => null;
+  end case;
+
+  case small is
+    when 0 => null;
+    when 1 to 3 => null;
+  end case;
+
+  case num is
+    when 0 => null;
+    when others => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn missing_choices_without_others() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+subtype small_t is integer range 0 to 7;
+
+procedure proc(state : state_t; small : small_t; num : integer) is
+begin
+  case state is
+    when busy => null;
+  end case;
+
+  case small is
+    when 0 => null;
+    when 2 to 5 => null;
+  end case;
+
+  case num is
+    when 0 => null;
+    when 3 => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("case state").s1("state"),
+                "Missing choices for type 'state_t': idle, done",
+            ),
+            Diagnostic::error(
+                code.s1("case small").s1("small"),
+                "Missing choices for subtype 'small_t': 1, 6 to 7",
+            ),
+            Diagnostic::error(
+                code.s1("case num").s1("num"),
+                "Missing choices for integer type 'INTEGER': -2147483647 to -1, 1 to 2, 4 to 2147483647",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn overlapping_choices() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+
+procedure proc(state : state_t; num : integer) is
+begin
+  case state is
+    when idle to busy => null;
+    when busy => null;
+    when others => null;
+  end case;
+
+  case num is
+    when 0 to 9 => null;
+    when 5 to 12 => null;
+    when others => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("busy", 3),
+                "Case choice overlaps with a previous choice",
+            )
+            .related(code.s1("idle to busy"), "Previously covered here"),
+            Diagnostic::error(
+                code.s1("5 to 12"),
+                "Case choice overlaps with a previous choice",
+            )
+            .related(code.s1("0 to 9"), "Previously covered here"),
+        ],
+    );
+}
+
+#[test]
+fn overlapping_choices_written_differently() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+
+procedure proc(state : state_t; num : integer) is
+begin
+  case state is
+    when busy => null;
+    when state_t'val(1) => null;
+    when state_t'succ(busy) => null;
+    when state_t'leftof(busy) => null;
+  end case;
+
+  case num is
+    when 2 => null;
+    when state_t'pos(done) => null;
+    when others => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("state_t'val(1)"),
+                "Case choice overlaps with a previous choice",
+            )
+            .related(code.s("busy", 2), "Previously covered here"),
+            Diagnostic::error(
+                code.s1("state_t'pos(done)"),
+                "Case choice overlaps with a previous choice",
+            )
+            .related(code.s1("when 2").s1("2"), "Previously covered here"),
+        ],
+    );
+}
+
+#[test]
+fn no_coverage_check_for_non_static_choices() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+procedure proc(num : integer; other : integer) is
+begin
+  case num is
+    when 0 => null;
+    when other => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn case_on_record_element_covers_element_subtype() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done, error);
+subtype active_t is state_t range busy to done;
+
+type rec_t is record
+  state : active_t;
+end record;
+
+procedure proc(rec : rec_t) is
+begin
+  case rec.state is
+    when busy => null;
+    when done => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn case_selector_subtype_of_element_qualified_expression_and_call() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done, error);
+subtype active_t is state_t range busy to done;
+type arr_t is array (0 to 1) of active_t;
+
+function f return active_t is
+begin
+  return busy;
+end function;
+
+procedure proc(arr : arr_t; state : state_t) is
+begin
+  case arr(0) is
+    when busy => null;
+    when done => null;
+  end case;
+
+  case active_t'(state) is
+    when busy => null;
+    when done => null;
+  end case;
+
+  case f is
+    when busy => null;
+    when done => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn case_on_other_expression_covers_base_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done, error);
+subtype active_t is state_t range busy to done;
+
+procedure proc(state : active_t) is
+begin
+  case active_t'succ(state) is
+    when busy => null;
+    when done => null;
+  end case;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("active_t'succ(state)"),
+            "Missing choices for type 'state_t': idle, error",
+        )],
+    );
+}
@@ -5,6 +5,7 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::analysis::CircularDependencyError;
 
 #[test]
 fn context() {
@@ -238,3 +239,27 @@ end package;",
         ],
     );
 }
+
+#[test]
+fn dependency_order_reports_cycle() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+context ctx1 is
+  library libname;
+  context libname.ctx2;
+end context;
+
+context ctx2 is
+  library libname;
+  context libname.ctx1;
+end context;
+",
+    );
+    let (root, _) = builder.get_analyzed_root();
+    assert_eq!(
+        root.dependency_order(),
+        Err(CircularDependencyError::new(Some(&code.s1("ctx1").pos())))
+    );
+}
@@ -935,7 +935,7 @@ end package body;
 -- Configuration context clause reference
 use work.pkg.all;
 configuration cfg of ename1 is
-for rtl
+for a
 end for;
 end configuration;
 
@@ -982,6 +982,45 @@ end package;
     );
 }
 
+#[test]
+fn context_reference_makes_used_package_visible() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant const : natural := 0;
+end package;
+
+context inner is
+  library libname;
+  use libname.pkg.all;
+end context;
+
+context outer is
+  library libname;
+  context libname.inner;
+end context;
+
+context work.outer;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant c0 : natural := const;
+begin
+end architecture;
+        ",
+    );
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(":= const").s1("const").start()),
+        Some(code.s1("const :").s1("const").pos())
+    );
+}
+
 #[test]
 fn adds_enum_variants_implicitly() {
     check_missing(
@@ -1038,3 +1077,83 @@ end package;
 ",
     );
 }
+
+#[test]
+fn use_work_all_makes_package_directly_visible() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant const : natural := 0;
+end package;
+
+use work.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant c0 : natural := pkg.const;
+begin
+end architecture;
+        ",
+    );
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("pkg.const", 1).start()),
+        Some(code.s1("pkg").pos())
+    );
+}
+
+#[test]
+fn context_reference_from_other_library() {
+    let mut builder = LibraryBuilder::new();
+    let code1 = builder.code(
+        "lib1",
+        "
+package pkg is
+  constant const : natural := 0;
+end package;
+
+context ctx is
+  library lib1;
+  use lib1.pkg.all;
+end context;
+        ",
+    );
+
+    let code2 = builder.code(
+        "lib2",
+        "
+package pkg is
+  constant other : natural := 0;
+end package;
+
+library lib1;
+context lib1.ctx;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant c0 : natural := const;
+  constant c1 : natural := lib1.pkg.const;
+  constant c2 : natural := work.pkg.other;
+begin
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code2.source(), code2.s1(":= const").s1("const").start()),
+        Some(code1.s1("const :").s1("const").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code2.source(), code2.s1("lib1.pkg.const").s1("pkg").start()),
+        Some(code1.s1("pkg").pos())
+    );
+}
@@ -5,6 +5,7 @@
 //! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::ast::Mode;
 use crate::EntHierarchy;
 use crate::Source;
 use pretty_assertions::assert_eq;
@@ -317,6 +318,236 @@ end architecture;
     assert_eq!(root.find_implementation(comp), vec![ent]);
 }
 
+#[test]
+fn enclosing_declaration_of_position() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+  main: process
+    variable v0 : natural;
+  begin
+    v0 := 1;
+  end process;
+end architecture;
+      ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent = root
+        .enclosing_declaration(&code.s1("v0 := 1").pos())
+        .unwrap();
+    assert_eq!(ent.decl_pos(), Some(&code.s1("main").pos()));
+    assert!(matches!(
+        ent.kind(),
+        AnyEntKind::Concurrent(Some(Concurrent::Process))
+    ));
+
+    // Design units enclose their declarative part
+    let arch = root
+        .enclosing_declaration(&code.s1("architecture").pos())
+        .unwrap();
+    assert_eq!(arch.decl_pos(), Some(&code.sa("architecture ", "a").pos()));
+    assert!(matches!(
+        arch.kind(),
+        AnyEntKind::Design(Design::Architecture(..))
+    ));
+}
+
+#[test]
+fn enclosing_declaration_of_declarative_part() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+-- A comment before the first design unit
+package pkg is
+  constant c0 : natural := 0;
+end package pkg;
+
+-- A comment between design units
+
+entity ent is
+  port (clk : bit);
+end entity;
+
+use work.pkg.all;
+
+architecture a of ent is
+  signal s0 : natural;
+begin
+end architecture;
+      ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let enclosing = |substr: &str| {
+        root.enclosing_declaration(&code.s1(substr).pos())
+            .and_then(|ent| ent.decl_pos().cloned())
+    };
+
+    let arch = Some(code.sa("architecture ", "a").pos());
+    assert_eq!(enclosing("signal s0"), arch);
+    assert_eq!(enclosing("use work.pkg.all"), arch);
+    assert_eq!(
+        enclosing("clk : bit"),
+        Some(code.sa("entity ", "ent").pos())
+    );
+    assert_eq!(
+        enclosing("constant c0"),
+        Some(code.sa("package ", "pkg").pos())
+    );
+    assert_eq!(enclosing("A comment before"), None);
+    assert_eq!(enclosing("A comment between"), None);
+}
+
+#[test]
+fn enclosing_declaration_ends_with_anonymous_end() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (clk : bit);
+end entity;
+
+-- A comment after the entity
+      ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let enclosing = |substr: &str| {
+        root.enclosing_declaration(&code.s1(substr).pos())
+            .and_then(|ent| ent.decl_pos().cloned())
+    };
+
+    assert_eq!(
+        enclosing("clk : bit"),
+        Some(code.sa("entity ", "ent").pos())
+    );
+    assert_eq!(enclosing("A comment after"), None);
+}
+
+#[test]
+fn call_hierarchy_of_subprograms() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function b(arg : natural) return natural;
+  procedure c;
+end package;
+
+package body pkg is
+  function b(arg : natural) return natural is
+  begin
+    c;
+    return arg;
+  end function;
+
+  procedure c is
+  begin
+  end procedure;
+end package body;
+
+entity ent is
+end entity;
+
+use work.pkg.all;
+
+architecture a of ent is
+  function a(arg : natural) return natural is
+    constant c0 : natural := b(0);
+  begin
+    return b(arg) + b(c0);
+  end function;
+begin
+  main: process
+  begin
+    c;
+  end process;
+end architecture;
+      ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent_at = |pos| root.search_reference(code.source(), pos).unwrap();
+    let a = ent_at(code.sa("function ", "a").start());
+    let b = ent_at(code.sa("function ", "b").start());
+    let b_body = ent_at(code.s("function b", 2).sa("function ", "b").start());
+    let c = ent_at(code.sa("procedure ", "c").start());
+
+    assert_eq!(root.callers_of(b.id()), vec![a.id()]);
+    assert_eq!(root.callees_of(a.id()), vec![b.id()]);
+
+    // The body and the declaration of a subprogram are the same
+    assert_eq!(root.callees_of(b_body.id()), vec![c.id()]);
+    assert_eq!(root.callers_of(c.id()), vec![b.id()]);
+    assert_eq!(root.callees_of(c.id()), vec![]);
+
+    // Subprogram bodies are enclosing declarations
+    assert_eq!(
+        root.enclosing_declaration(&code.s1("b(arg)").pos())
+            .map(|ent| ent.id()),
+        Some(a.id())
+    );
+}
+
+#[test]
+fn recursive_calls_are_found() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+end package;
+
+package body pkg is
+  function fact(n : natural) return natural is
+  begin
+    if n = 0 then
+      return 1;
+    end if;
+    return n * fact(n - 1);
+  end function fact;
+
+  function g(n : natural) return natural is
+  begin
+    return n;
+  end function g;
+end package body;
+      ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent_at = |pos| root.search_reference(code.source(), pos).unwrap();
+    let fact = ent_at(code.s1("fact").start());
+    let g = ent_at(code.sa("function ", "g").start());
+
+    assert_eq!(root.callers_of(fact.id()), vec![fact.id()]);
+    assert_eq!(root.callees_of(fact.id()), vec![fact.id()]);
+
+    // The end identifier is not a call
+    assert_eq!(root.callers_of(g.id()), vec![]);
+    assert_eq!(root.callees_of(g.id()), vec![]);
+}
+
 #[test]
 fn exit_and_next_outside_of_loop() {
     let mut builder = LibraryBuilder::new();
@@ -405,3 +636,158 @@ end architecture;
         ],
     );
 }
+
+#[test]
+fn entity_ports_and_generics() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  generic (
+    width : natural := 8);
+  port (
+    clk : in bit;
+    data : out bit_vector(width - 1 downto 0));
+end entity;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent = root
+        .search_reference(code.source(), code.s("ent", 2).start())
+        .unwrap();
+    let ent = DesignEnt::from_any(ent).unwrap();
+
+    let generics: Vec<_> = ent
+        .generics()
+        .into_iter()
+        .map(|generic| {
+            (
+                generic.designator().to_string(),
+                generic.type_mark().designator().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(generics, vec![("width".to_owned(), "NATURAL".to_owned())]);
+
+    let ports: Vec<_> = ent
+        .ports()
+        .into_iter()
+        .map(|port| {
+            let AnyEntKind::Object(obj) = port.kind() else {
+                panic!("Expected object");
+            };
+            (
+                port.designator().to_string(),
+                obj.mode(),
+                port.base_type().designator().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        ports,
+        vec![
+            ("clk".to_owned(), Some(Mode::In), "BIT".to_owned()),
+            ("data".to_owned(), Some(Mode::Out), "BIT_VECTOR".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn full_name_of_declarations() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant c0 : natural := 0;
+end package;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+  process
+    variable v0 : natural;
+  begin
+  end process;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let c0 = root
+        .search_reference(code.source(), code.s1("c0").start())
+        .unwrap();
+    assert_eq!(c0.full_name(), "libname.pkg.c0");
+
+    let v0 = root
+        .search_reference(code.source(), code.s1("v0").start())
+        .unwrap();
+    assert_eq!(v0.full_name(), "libname.ent.a.v0");
+}
+
+#[test]
+fn selectable_members_of_objects() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t is record
+    field0 : natural;
+    field1 : bit;
+  end record;
+
+  type prot_t is protected
+    procedure proc0;
+    impure function fun0 return natural;
+  end protected;
+end package;
+
+package body pkg is
+  type prot_t is protected body
+    variable private : natural;
+
+    procedure proc0 is
+    begin
+    end procedure;
+
+    impure function fun0 return natural is
+    begin
+      return private;
+    end function;
+
+    procedure hidden is
+    begin
+    end procedure;
+  end protected body;
+
+  shared variable prot : prot_t;
+  constant rec : rec_t := (0, '0');
+end package body;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let members = |name: &str| -> Vec<String> {
+        let ent = root
+            .search_reference(code.source(), code.s1(name).start())
+            .unwrap();
+        root.selectable_members(ent)
+            .into_iter()
+            .map(|ent| ent.designator().to_string())
+            .collect()
+    };
+
+    assert_eq!(members("rec :"), vec!["field0", "field1"]);
+    assert_eq!(members("prot :"), vec!["proc0", "fun0"]);
+}
@@ -144,7 +144,15 @@ end package;
     );
 
     let diagnostics = builder.analyze();
-    check_diagnostics(diagnostics, duplicates(&code, &["a1", "b1"]));
+    let mut expected = duplicates(&code, &["a1", "b1"]);
+    expected.push(
+        Diagnostic::error(
+            code.s("prot_t", 2),
+            "Missing body for procedure proc[NATURAL, NATURAL, NATURAL]",
+        )
+        .related(code.s1("proc(").s1("proc"), "Declared here"),
+    );
+    check_diagnostics(diagnostics, expected);
 }
 
 #[test]
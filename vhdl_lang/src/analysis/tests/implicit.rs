@@ -1,4 +1,5 @@
 use super::*;
+use crate::ast::Operator;
 
 #[test]
 fn adds_to_string_for_standard_types() {
@@ -251,7 +252,7 @@ fn implicit_functions_on_integer_type() {
     check_code_with_no_diagnostics(
         "
 package pkg is
-    type type_t is range 0 to 1;
+    type type_t is range -10 to 10;
 
     constant c0 : type_t := 10;
     constant good1 : type_t := - c0;
@@ -266,3 +267,126 @@ end package;
 ",
     );
 }
+
+#[test]
+fn resolves_to_string_overloads() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal count : natural;
+  signal vec : std_logic_vector(7 downto 0);
+  signal bits : bit_vector(7 downto 0);
+begin
+  main : process
+  begin
+    report to_string(count);
+    report to_string(vec) & to_hstring(vec) & to_ostring(vec);
+    report to_hstring(bits) & to_string(1.0, 3) & to_string(now, ns);
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(root.find_all_unresolved().1, vec![]);
+
+    let to_string = root
+        .search_reference(code.source(), code.s1("to_string(count)").start())
+        .unwrap();
+    assert_eq!(
+        to_string.describe(),
+        "function TO_STRING[INTEGER return STRING]"
+    );
+}
+
+#[test]
+fn resolves_textio_overloads() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+use std.textio.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal vec : std_logic_vector(7 downto 0);
+begin
+  main : process
+    variable l : line;
+    variable value : integer;
+    variable good : boolean;
+    variable bits : bit_vector(7 downto 0);
+  begin
+    write(l, value);
+    write(l, string'(\"value = \") & to_string(value));
+    write(l, now, left, 0, ns);
+    write(l, vec);
+    hwrite(l, bits);
+    writeline(output, l);
+    read(l, value);
+    read(l, value, good);
+    read(l, vec);
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(root.find_all_unresolved().1, vec![]);
+}
+
+#[test]
+fn lists_implicit_declarations_of_enum_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type enum_t is (alpha, beta);
+subtype sub_t is enum_t;
+        ",
+    );
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent = root
+        .search_reference(code.source(), code.s1("sub_t").start())
+        .unwrap();
+    let typ = TypeEnt::from_any(ent).unwrap();
+    let implicits: Vec<_> = typ
+        .implicit_declarations()
+        .map(|ent| ent.designator().to_string())
+        .collect();
+
+    for name in ["alpha", "beta", "\"=\"", "\"/=\"", "TO_STRING"] {
+        assert!(
+            implicits.iter().any(|implicit| implicit == name),
+            "{name} not in {implicits:?}"
+        );
+    }
+
+    let eq = typ
+        .implicit_declarations()
+        .find(|ent| ent.designator() == &Designator::OperatorSymbol(Operator::EQ))
+        .unwrap();
+    assert_eq!(
+        OverloadedEnt::from_any(eq).unwrap().signature().describe(),
+        "[enum_t, enum_t return BOOLEAN]"
+    );
+}
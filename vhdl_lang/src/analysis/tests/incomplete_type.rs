@@ -168,6 +168,91 @@ end package;
     check_diagnostics(diagnostics, vec![missing_full_error(&code.s1("rec_t"))]);
 }
 
+#[test]
+fn error_on_self_referential_type_declaration() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  subtype sub_t is sub_t;
+  type arr_t is array (natural range <>) of arr_t;
+  type rec_t is record
+    field : rec_t;
+  end record;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            self_reference_error(&code.s("sub_t", 2), "sub_t"),
+            self_reference_error(&code.s("arr_t", 2), "arr_t"),
+            self_reference_error(&code.s("rec_t", 2), "rec_t"),
+        ],
+    );
+}
+
+#[test]
+fn error_on_incomplete_type_cycle_without_access_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t;
+  subtype sub_t is rec_t;
+  type rec_t is record
+    field : sub_t;
+  end record;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![self_reference_error(&code.s("sub_t", 2), "rec_t")],
+    );
+}
+
+#[test]
+fn incomplete_type_cycle_through_access_type_is_allowed() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  type node_t;
+  type ptr_t is access node_t;
+  type node_t is record
+    value : natural;
+    next_node : ptr_t;
+  end record;
+  type ptr_vec_t is array (natural range <>) of ptr_t;
+  type tree_t;
+  type tree_ptr_t is access tree_t;
+  type tree_t is record
+    children : ptr_vec_t;
+    parent : tree_ptr_t;
+  end record;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+fn self_reference_error(pos: &impl AsRef<SrcPos>, name: &str) -> Diagnostic {
+    Diagnostic::error(
+        pos,
+        format!("Type '{name}' cannot be used within its own declaration"),
+    )
+}
+
 fn missing_full_error(pos: &impl AsRef<SrcPos>) -> Diagnostic {
     let mut error = Diagnostic::error(
         pos,
@@ -0,0 +1,387 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::analysis::{LintRule, LowercaseSignalNames, NoConcurrentVariables};
+use crate::ast::ObjectClass;
+use crate::data::DiagnosticHandler;
+
+struct ConstantPrefix;
+
+impl LintRule for ConstantPrefix {
+    fn name(&self) -> &str {
+        "constant_prefix"
+    }
+
+    fn check_unit(&self, declarations: &[EntRef], diagnostics: &mut dyn DiagnosticHandler) {
+        for ent in declarations.iter() {
+            if let AnyEntKind::Object(obj) = ent.kind() {
+                if obj.class == ObjectClass::Constant
+                    && !ent.designator().to_string().starts_with("c_")
+                {
+                    diagnostics.push(Diagnostic::hint(
+                        ent.decl_pos().unwrap(),
+                        "Constant name must start with c_",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn custom_lint_rule_runs_after_analysis() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant c_good : natural := 0;
+  constant bad : natural := 0;
+end package;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.add_lint_rule(Box::new(ConstantPrefix));
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::hint(
+            code.s1("bad"),
+            "Constant name must start with c_",
+        )],
+    );
+
+    root.remove_lint_rule("constant_prefix");
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn builtin_lint_rules() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  type prot_t is protected
+  end protected;
+
+  type prot_t is protected body
+  end protected body;
+
+  signal good : bit;
+  signal Bad : bit;
+  shared variable var : prot_t;
+begin
+  process
+    variable ok : natural;
+  begin
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let (mut root, _) = builder.get_analyzed_root();
+    root.add_lint_rule(Box::new(LowercaseSignalNames));
+    root.add_lint_rule(Box::new(NoConcurrentVariables));
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(code.s1("Bad"), "Signal name 'Bad' must be lower case"),
+            Diagnostic::warning(
+                code.s("var", 2),
+                "shared variable 'var' must not be declared in a concurrent region",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn unreachable_assignment_alternatives() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sel, sig : bit;
+begin
+  sig <= '1' when true else '0';
+
+  with sel select
+    sig <= '0' when '0',
+           '1' when '1',
+           '0' when others;
+end architecture;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.set_unreachable_warnings(true);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("'0'"),
+                "Unreachable alternative, a previous condition is always true",
+            ),
+            Diagnostic::warning(
+                code.s1("others"),
+                "Others can never be selected, all values are already covered",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn combinational_loop_between_two_signals() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, a, b, c, d, e : bit;
+begin
+  a <= b;
+  b <= not a when c = '1' else '0';
+
+  -- Feedback through registers is not a combinational loop
+  d <= e when rising_edge(clk);
+  e <= d;
+
+  process (clk)
+  begin
+    if clk'event and clk = '1' then
+      c <= c;
+    end if;
+  end process;
+end architecture;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.set_combinational_loop_warnings(true);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("a <= b").s1("a"),
+            "Combinational loop through 'a', 'b'",
+        )
+        .related(
+            code.s1("b <= not").s1("b"),
+            "Part of the combinational loop",
+        )],
+    );
+}
+
+#[test]
+fn combinational_loop_through_processes() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, a, b, c, d, e, f : bit;
+begin
+  process (all)
+  begin
+    if c = '1' then
+      a <= b;
+    else
+      a <= '0';
+    end if;
+  end process;
+
+  process (a)
+  begin
+    b <= not a;
+  end process;
+
+  c <= a;
+
+  -- Processes with a clock edge or a wait statement are not combinational
+  process (clk)
+  begin
+    if rising_edge(clk) then
+      d <= e;
+    end if;
+  end process;
+
+  process
+  begin
+    e <= d;
+    f <= e;
+    wait on d;
+  end process;
+
+  process (all)
+  begin
+    d <= f;
+  end process;
+end architecture;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.set_combinational_loop_warnings(true);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("a <= b").s1("a"),
+                "Combinational loop through 'a', 'c'",
+            )
+            .related(code.s1("c <= a").s1("c"), "Part of the combinational loop"),
+            Diagnostic::warning(
+                code.s1("a <= b").s1("a"),
+                "Combinational loop through 'a', 'b'",
+            )
+            .related(
+                code.s1("b <= not").s1("b"),
+                "Part of the combinational loop",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn variable_read_before_write_in_process() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, s : bit;
+begin
+  process (clk)
+    variable v0, v1, v2, v3 : bit;
+    variable v4 : bit_vector(0 to 3);
+    variable v5 : bit := '0';
+  begin
+    s <= v0;
+    v0 := '1';
+    v1 := '0';
+    s <= v1;
+
+    -- Reading an attribute of a variable does not read its value
+    for i in v4'range loop
+      v4(i) := v2;
+    end loop;
+    s <= v5;
+
+    -- The other iterations read the value of an earlier one
+    for i in 0 to 3 loop
+      s <= v3;
+      v3 := '1';
+    end loop;
+  end process;
+end architecture;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.set_read_before_write_warnings(true);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("s <= v0").s1("v0"),
+                "variable 'v0' is read before it is assigned",
+            ),
+            Diagnostic::warning(
+                code.s1(":= v2").s1("v2"),
+                "variable 'v2' is read before it is assigned",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn conditionally_written_variable_is_not_read_before_write() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, s : bit;
+  signal sel : natural;
+
+  procedure init(variable value : out bit) is
+  begin
+    value := '0';
+  end procedure;
+begin
+  process (clk, sel)
+    variable v0, v1, v2 : bit;
+  begin
+    if clk = '1' then
+      v0 := '1';
+    end if;
+    s <= v0;
+
+    case sel is
+      when 0 => v1 := '1';
+      when others => null;
+    end case;
+    s <= v1;
+
+    init(v2);
+    s <= v2;
+  end process;
+end architecture;
+",
+    );
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    root.set_read_before_write_warnings(true);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_no_diagnostics(&diagnostics);
+}
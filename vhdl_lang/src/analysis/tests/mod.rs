@@ -6,6 +6,7 @@
 
 mod assignment_typecheck;
 mod association_formal;
+mod case_statement;
 mod circular_dependencies;
 mod context_clause;
 mod deferred_constant;
@@ -14,8 +15,10 @@ mod homographs;
 mod implicit;
 mod incomplete_type;
 mod incremental_analysis;
+mod lint;
 mod package_instance;
 mod protected_type;
+mod read_out_port;
 mod resolves_design_units;
 mod resolves_names;
 mod resolves_type_mark;
@@ -29,6 +32,7 @@ use std::cell::RefCell;
 
 pub use self::util::*;
 use crate::ast::Designator;
+use crate::ast::LabeledConcurrentStatement;
 use crate::ast::UnitId;
 pub use crate::data::Diagnostic;
 use crate::data::NoDiagnostics;
@@ -99,6 +103,33 @@ impl<'a> TestSetup<'a> {
         code
     }
 
+    pub fn concurrent_statement(&'a self, code: &str) -> LabeledConcurrentStatement {
+        let mut statements = vec![self.snippet(code).concurrent_statement()];
+        let dummy_parent = self.arena.alloc(
+            Designator::Anonymous(0),
+            None,
+            Related::None,
+            AnyEntKind::Library,
+            None,
+        );
+        let ctx = self.ctx();
+        ctx.define_labels_for_concurrent_part(
+            &self.scope,
+            dummy_parent,
+            &mut statements,
+            &mut NoDiagnostics,
+        )
+        .unwrap();
+        ctx.analyze_concurrent_part(
+            &self.scope,
+            dummy_parent,
+            &mut statements,
+            &mut NoDiagnostics,
+        )
+        .unwrap();
+        statements.pop().unwrap()
+    }
+
     pub fn lookup(&'a self, sym: &str) -> EntRef<'a> {
         // We cheat and create a source pos as the lookup method requires it
         let designator = self.snippet(sym).designator();
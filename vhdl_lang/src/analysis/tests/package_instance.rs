@@ -608,3 +608,149 @@ end package;
         vec![code.s("sub_t", 1).pos(), code.s("sub_t", 3).pos(),]
     );
 }
+
+#[test]
+fn interface_subprogram_operator_resolves_overloaded_actual() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package gpkg is
+  generic (
+    type type_t;
+    function \"+\"(l, r : type_t) return type_t
+  );
+end package;
+
+package pkg is
+  type rec_t is record
+    field : natural;
+  end record;
+  function my_add(l, r : integer) return integer;
+  function my_add(l, r : rec_t) return rec_t;
+  function \"+\"(l, r : rec_t) return rec_t;
+
+  package ipkg is new work.gpkg
+    generic map (
+      type_t => rec_t,
+      \"+\" => my_add);
+
+  package ipkg2 is new work.gpkg
+    generic map (
+      type_t => rec_t,
+      \"+\" => \"+\");
+end package;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let ent = root
+        .search_reference(code.source(), code.s1("=> my_add").s1("my_add").start())
+        .unwrap();
+    assert_eq!(
+        ent.decl_pos(),
+        Some(&code.s1("my_add(l, r : rec_t)").s1("my_add").pos())
+    );
+}
+
+#[test]
+fn interface_subprogram_operator_actual_does_not_match_signature() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package gpkg is
+  generic (
+    type type_t;
+    function \"+\"(l, r : type_t) return type_t
+  );
+end package;
+
+package pkg is
+  package ipkg is new work.gpkg
+    generic map (
+      type_t => boolean,
+      \"+\" => \"+\");
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].pos, code.s("\"+\"", 3).pos());
+    assert_eq!(
+        diagnostics[0].message,
+        "Cannot map '\"+\"' to subprogram generic \"+\"[BOOLEAN, BOOLEAN return BOOLEAN]"
+    );
+}
+
+#[test]
+fn generic_constant_actual_constrains_instantiated_declarations() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package gpkg is
+  generic (
+    N : natural
+  );
+  constant zeros : bit_vector(0 to N - 1) := (others => '0');
+end package;
+
+package ipkg is new work.gpkg
+  generic map (
+    N => 8
+  );
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+  process
+    variable short : bit_vector(0 to 3);
+    variable long : bit_vector(0 to 7);
+  begin
+    long := work.ipkg.zeros;
+    short := work.ipkg.zeros;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("short := work.ipkg.zeros").s1("work.ipkg.zeros"),
+            "Length mismatch, target has length 4 but value has length 8",
+        )],
+    );
+}
+
+#[test]
+fn generic_constant_actual_must_match_formal_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package gpkg is
+  generic (
+    N : natural
+  );
+end package;
+
+package ipkg is new work.gpkg
+  generic map (
+    N => true
+  );
+",
+    );
+
+    let diagnostics = builder.analyze();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].pos, code.s1("true").pos());
+}
@@ -290,3 +290,136 @@ end package;",
         Some(&code.s("prot_t", 2).pos())
     );
 }
+
+#[test]
+fn only_public_methods_of_protected_type_can_be_selected() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end;
+
+architecture a of ent is
+  type prot_t is protected
+    function get return natural;
+  end protected;
+
+  type prot_t is protected body
+    variable count : natural := 0;
+
+    function helper return natural is
+    begin
+      return count;
+    end;
+
+    function get return natural is
+    begin
+      return helper;
+    end;
+  end protected body;
+
+  shared variable pvar : prot_t;
+begin
+
+  main : process
+    variable v : natural;
+  begin
+    v := pvar.get;
+    v := pvar.helper;
+    v := pvar.count;
+    wait;
+  end process;
+
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("helper", 3),
+                "No declaration of 'helper' within protected type 'prot_t'",
+            ),
+            Diagnostic::error(
+                code.s("count", 3),
+                "No declaration of 'count' within protected type 'prot_t'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn protected_type_body_implements_all_methods() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg1 is
+  type prot_t is protected
+    procedure proc(arg : natural);
+    procedure proc(arg : boolean);
+    impure function fun return natural;
+  end protected;
+
+  type prot_t is protected body
+    variable state : natural := 0;
+
+    procedure proc(arg : natural) is
+    begin
+      state := arg;
+    end;
+
+    procedure proc(arg : boolean) is
+    begin
+    end;
+
+    impure function fun return natural is
+    begin
+      return state;
+    end;
+  end protected body;
+end package;",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn error_on_missing_protected_method_body() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg1 is
+  type prot_t is protected
+    procedure proc(arg : natural);
+    procedure proc(arg : boolean);
+  end protected;
+
+  type prot_t is protected body
+    procedure proc(arg : natural) is
+    begin
+    end;
+
+    -- Private methods do not need a declaration
+    procedure private(arg : boolean) is
+    begin
+    end;
+  end protected body;
+end package;",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("prot_t", 2),
+            "Missing body for procedure proc[BOOLEAN]",
+        )
+        .related(code.s1("proc(arg : boolean)").s1("proc"), "Declared here")],
+    );
+}
@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::VhdlStandard;
+
+fn out_port_code(builder: &mut LibraryBuilder) -> Code {
+    builder.code(
+        "libname",
+        "
+entity sub is
+  port (
+    o : out bit);
+end entity;
+
+architecture a of sub is
+begin
+end architecture;
+
+entity ent is
+  port (
+    o : out bit;
+    o2 : out bit);
+end entity;
+
+architecture a of ent is
+  signal s : bit;
+begin
+  s <= o;
+  o2 <= not o;
+  inst: entity work.sub port map (o => o);
+end architecture;
+",
+    )
+}
+
+#[test]
+fn reading_out_port_is_an_error_before_2008() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL1993);
+    let code = out_port_code(&mut builder);
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("s <= o").s1("o"),
+                "Interface signal 'o' of mode out cannot be read before VHDL-2008",
+            ),
+            Diagnostic::error(
+                code.s1("not o").s("o", 2),
+                "Interface signal 'o' of mode out cannot be read before VHDL-2008",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn reading_out_port_is_allowed_in_2008() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2008);
+    out_port_code(&mut builder);
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn changing_the_standard_re_analyzes_reading_out_port() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL1993);
+    out_port_code(&mut builder);
+
+    let (mut root, diagnostics) = builder.get_analyzed_root();
+    assert_eq!(root.standard(), VhdlStandard::VHDL1993);
+    assert_eq!(diagnostics.len(), 2);
+
+    root.set_standard(VhdlStandard::VHDL2008);
+    assert_eq!(root.standard(), VhdlStandard::VHDL2008);
+    let mut diagnostics = Vec::new();
+    root.analyze(&mut diagnostics);
+    check_no_diagnostics(&diagnostics);
+}
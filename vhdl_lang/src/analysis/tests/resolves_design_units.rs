@@ -6,6 +6,7 @@
 
 use super::resolves_type_mark::kind_error;
 use super::*;
+use crate::ast::{PrimaryKind, SecondaryKind, UnitId};
 
 #[test]
 fn error_on_configuration_before_entity_in_same_file() {
@@ -21,6 +22,10 @@ end configuration;
 
 entity ent is
 end entity;
+
+architecture rtl of ent is
+begin
+end architecture;
 ",
     );
 
@@ -64,6 +69,10 @@ fn good_configurations() {
 entity ent is
 end entity;
 
+architecture rtl of ent is
+begin
+end architecture;
+
 configuration cfg_good1 of ent is
 for rtl
 end for;
@@ -85,6 +94,268 @@ end configuration;
     check_no_diagnostics(&builder.analyze());
 }
 
+#[test]
+fn configuration_binds_component_to_architecture() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity sub is
+end entity;
+
+architecture rtl of sub is
+begin
+end architecture;
+
+entity top is
+end entity;
+
+architecture rtl of top is
+  component sub_comp is
+  end component;
+begin
+  inst : sub_comp;
+end architecture;
+
+configuration cfg of top is
+  for rtl
+    for inst : sub_comp
+      use entity work.sub(rtl);
+      for rtl
+      end for;
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("sub_comp", 3).start()),
+        Some(code.s1("sub_comp").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("work.sub").s1("sub").start()),
+        Some(code.s1("sub").pos())
+    );
+}
+
+#[test]
+fn error_on_configuration_of_missing_architecture_or_component() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity sub is
+end entity;
+
+entity top is
+end entity;
+
+architecture rtl of top is
+  component sub_comp is
+  end component;
+begin
+  inst : sub_comp;
+end architecture;
+
+configuration cfg1 of top is
+  for missing_arch
+  end for;
+end configuration;
+
+configuration cfg2 of top is
+  for rtl
+    for inst : missing_comp
+    end for;
+    for inst : sub_comp
+      use entity work.sub(missing_sub_arch);
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("missing_arch"),
+                "No architecture 'missing_arch' for entity 'libname.top'",
+            ),
+            Diagnostic::error(code.s1("missing_comp"), "No declaration of 'missing_comp'"),
+            Diagnostic::error(
+                code.s1("missing_sub_arch"),
+                "No architecture 'missing_sub_arch' for entity 'libname.sub'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn configuration_of_component_declared_in_package() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  component sub_comp is
+  end component;
+end package;
+
+entity sub is
+end entity;
+
+entity top is
+end entity;
+
+use work.pkg.all;
+
+architecture rtl of top is
+begin
+  inst : sub_comp;
+end architecture;
+
+configuration cfg of top is
+  for rtl
+    for inst : sub_comp
+      use entity work.sub;
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("sub_comp", 3).start()),
+        Some(code.s1("sub_comp").pos())
+    );
+}
+
+#[test]
+fn configuration_of_components_within_generate_and_block_statements() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity sub is
+end entity;
+
+entity top is
+end entity;
+
+architecture rtl of top is
+begin
+  gen : for i in 0 to 1 generate
+    component gen_comp is
+    end component;
+  begin
+    inst : gen_comp;
+  end generate;
+
+  blk : block is
+  begin
+    inner : block is
+      component sub_comp is
+      end component;
+    begin
+      inst : sub_comp;
+    end block;
+  end block;
+end architecture;
+
+configuration cfg of top is
+  for rtl
+    for gen(0)
+      for inst : gen_comp
+        use entity work.sub;
+      end for;
+    end for;
+    for blk
+      for inner
+        for inst : sub_comp
+          use entity work.sub;
+        end for;
+      end for;
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("for gen(0)").s1("gen").start()),
+        Some(code.s1("gen").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("gen_comp", 3).start()),
+        Some(code.s1("gen_comp").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("inner", 2).start()),
+        Some(code.s1("inner").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("sub_comp", 3).start()),
+        Some(code.s1("sub_comp").pos())
+    );
+}
+
+#[test]
+fn error_on_configuration_of_missing_block() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity top is
+end entity;
+
+architecture rtl of top is
+  component sub_comp is
+  end component;
+begin
+  blk : block is
+  begin
+  end block;
+end architecture;
+
+configuration cfg of top is
+  for rtl
+    for missing_blk
+    end for;
+    for sub_comp
+    end for;
+    for blk
+      for inner
+      end for;
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(code.s1("missing_blk"), "No declaration of 'missing_blk'"),
+            Diagnostic::error(
+                code.s("sub_comp", 2),
+                "Expected block or generate statement, got component 'sub_comp'",
+            )
+            .related(code.s1("sub_comp"), "Defined here"),
+            Diagnostic::error(code.s1("inner"), "No declaration of 'inner'"),
+        ],
+    );
+}
+
 #[test]
 fn error_on_configuration_of_entity_outside_of_library() {
     let mut builder = LibraryBuilder::new();
@@ -122,6 +393,10 @@ fn search_reference_from_configuration_to_entity() {
 entity decl is
 end entity;
 
+architecture rtl of decl is
+begin
+end architecture;
+
 configuration cfg_good1 of decl is
 for rtl
 end for;
@@ -135,6 +410,77 @@ end configuration;
     );
 }
 
+#[test]
+fn direct_entity_instantiation_binds_named_architecture() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity sub is
+  generic (width : natural);
+  port (i : in bit; o : out bit);
+end entity;
+
+architecture rtl of sub is
+begin
+  o <= i;
+end architecture;
+
+entity other is
+end entity;
+
+architecture oth of other is
+begin
+end architecture;
+
+entity top is
+end entity;
+
+architecture a of top is
+  signal s, t : bit;
+begin
+  good_inst : entity work.sub(rtl)
+    generic map (width => 1)
+    port map (i => s, o => t);
+  missing_inst : entity work.sub(missing)
+    generic map (width => 1)
+    port map (i => s, o => t);
+  other_inst : entity work.sub(oth)
+    generic map (width => 1)
+    port map (i => s, o => t);
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("work.sub(missing)").s1("missing"),
+                "No architecture 'missing' for entity 'libname.sub'",
+            ),
+            Diagnostic::error(
+                code.s1("work.sub(oth)").s1("oth"),
+                "No architecture 'oth' for entity 'libname.sub'",
+            ),
+        ],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("sub(rtl)").s1("rtl").start()),
+        Some(code.s1("architecture rtl").s1("rtl").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("width => 1").start()),
+        Some(code.s1("width").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("i => s").start()),
+        Some(code.s1("i : in bit").s1("i").pos())
+    );
+}
+
 #[test]
 fn error_on_architecture_of_missing_entity() {
     let mut builder = LibraryBuilder::new();
@@ -356,12 +702,19 @@ fn search_configuration_instance() {
 entity ent is
 end entity;
 
+architecture a of ent is
+begin
+end architecture;
+
 configuration decl of ent is
   for a
   end for;
 end configuration;
 
-architecture a of ent is
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
 begin
   inst : configuration work.decl;
 end architecture;
@@ -539,3 +892,59 @@ end architecture;
         Some(&code.s1("empty").pos())
     );
 }
+
+#[test]
+fn dependency_order_places_used_package_first() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  use work.pkg.all;
+begin
+end architecture;
+
+package pkg is
+  constant c0 : natural := 0;
+end package;
+
+package body pkg is
+end package body;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let libname = root.symbol_utf8("libname");
+    let order: Vec<_> = root
+        .dependency_order()
+        .unwrap()
+        .into_iter()
+        .filter(|unit_id| unit_id.library_name() == &libname)
+        .collect();
+    let index_of = |unit_id: &UnitId| order.iter().position(|id| id == unit_id).unwrap();
+
+    let ent = UnitId::primary(&libname, PrimaryKind::Entity, &root.symbol_utf8("ent"));
+    let arch = UnitId::secondary(
+        &libname,
+        SecondaryKind::Architecture,
+        &root.symbol_utf8("ent"),
+        &root.symbol_utf8("a"),
+    );
+    let pkg = UnitId::package(&libname, &root.symbol_utf8("pkg"));
+    let body = UnitId::secondary(
+        &libname,
+        SecondaryKind::PackageBody,
+        &root.symbol_utf8("pkg"),
+        &root.symbol_utf8("pkg"),
+    );
+
+    assert_eq!(order.len(), 4);
+    assert!(index_of(&ent) < index_of(&arch));
+    assert!(index_of(&pkg) < index_of(&arch));
+    assert!(index_of(&pkg) < index_of(&body));
+}
@@ -5,6 +5,9 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::ast::search::AstNode;
+use crate::ast::{Expression, Name, ObjectClass};
+use crate::data::WithPos;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -633,7 +636,6 @@ architecture a of ent is
 begin
   main : process(missing) is
   begin
-    wait on missing until missing = 0 ns for missing;
     missing <= missing after missing;
     missing <= force missing;
     missing <= release;
@@ -642,6 +644,11 @@ begin
                   missing when others;
 
   end process;
+
+  waiting : process is
+  begin
+    wait on missing until missing = 0 ns for missing;
+  end process;
 end architecture;
 ",
     );
@@ -659,7 +666,6 @@ architecture a of ent is
 begin
   main : process (decl) is
   begin
-    wait on decl until decl = 0 ns for decl;
     decl <= decl after decl;
     decl <= force decl;
     decl <= release;
@@ -667,6 +673,11 @@ begin
        decl <= decl when decl,
                decl when others;
   end process;
+
+  waiting : process is
+  begin
+    wait on decl until decl = 0 ns for decl;
+  end process;
 end architecture;
 ",
     );
@@ -938,6 +949,124 @@ end architecture;
     );
 }
 
+#[test]
+fn for_generate_index_has_type_of_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity child is
+  port (x : in bit);
+end entity;
+
+architecture a of child is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit_vector(0 to 3);
+begin
+ gen: for i in 0 to 3 generate
+   constant c : natural := i;
+ begin
+   good: entity work.child port map (x => sig(i));
+   bad: entity work.child port map (x => i);
+ end generate;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("x => i").s1("i"),
+            "loop parameter 'i' of integer type 'INTEGER' does not match type 'BIT'",
+        )],
+    );
+}
+
+#[test]
+fn for_loop_index_has_index_type_of_range_attribute() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  type state_t is (idle, busy);
+  type arr_t is array (state_t) of natural;
+  type arr2_t is array (state_t, boolean) of natural;
+  signal sig : arr_t;
+  signal sig2 : arr2_t;
+begin
+  process
+    variable state : state_t;
+    variable flag : boolean;
+    variable n : natural;
+  begin
+    for i in sig'range loop
+      state := i;
+      n := sig(i);
+      n := i;
+    end loop;
+    for j in sig2'range(2) loop
+      flag := j;
+      state := j;
+    end loop;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("n := i").s1("i"),
+                "loop parameter 'i' of type 'state_t' does not match subtype 'NATURAL'",
+            ),
+            Diagnostic::error(
+                code.s1("state := j").s1("j"),
+                "loop parameter 'j' of type 'BOOLEAN' does not match type 'state_t'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn case_generate_choices_are_analyzed() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  generic (sel : natural := 0);
+end entity;
+
+architecture a of ent is
+  constant c : natural := 1;
+begin
+ gen: case sel generate
+   when c =>
+   when missing =>
+   when others =>
+ end generate;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(diagnostics, vec![missing(&code, "missing", 1)]);
+}
+
 #[test]
 fn search_if_generate_conditions() {
     check_search_reference(
@@ -975,6 +1104,68 @@ end architecture;
     );
 }
 
+#[test]
+fn resolves_declarations_and_labels_of_generate_alternatives() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  generic (sel : natural := 0);
+end entity;
+
+architecture a of ent is
+begin
+  gen0: if alt0: sel = 0 generate
+    signal sig0 : bit;
+  begin
+    sig0 <= '1';
+  end alt0;
+  elsif alt1: sel = 1 generate
+    signal sig0 : bit;
+  begin
+    inner: block
+    begin
+      sig0 <= '0';
+    end block;
+  end alt1;
+  end generate;
+
+  gen1: case sel generate
+    when alt2: 0 =>
+      signal sig1 : bit;
+    begin
+      sig1 <= '1';
+    end alt2;
+    when others =>
+  end generate;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let path_name = |substr: &str, occurence: usize| {
+        root.search_reference(code.source(), code.s(substr, occurence).start())
+            .unwrap()
+            .path_name()
+    };
+
+    assert_eq!(path_name("sig0", 2), "libname.ent.a.gen0.alt0.sig0");
+    assert_eq!(path_name("sig0", 4), "libname.ent.a.gen0.alt1.sig0");
+    assert_eq!(path_name("sig1", 2), "libname.ent.a.gen1.alt2.sig1");
+    assert_eq!(path_name("inner", 1), "libname.ent.a.gen0.alt1.inner");
+    assert_eq!(path_name("alt0", 2), "libname.ent.a.gen0.alt0");
+    assert_eq!(path_name("alt2", 2), "libname.ent.a.gen1.alt2");
+
+    // The end label of an alternative refers to its label
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("alt1", 2).start()),
+        Some(code.s1("alt1").pos())
+    );
+}
+
 #[test]
 fn resolves_missing_name_in_alias() {
     check_missing(
@@ -1036,6 +1227,56 @@ end architecture;
     );
 }
 
+#[test]
+fn external_name_resolves_port_of_instance() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity dut_ent is
+  port (clk : in bit);
+end entity;
+
+architecture a of dut_ent is
+  signal internal : bit;
+begin
+end architecture;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+  signal clk : bit;
+  alias dut_clk is << signal dut.clk : bit >>;
+begin
+  dut : entity work.dut_ent
+    port map (clk => clk);
+
+  clk <= << signal dut.internal : bit >>;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("dut.clk").start()),
+        Some(code.s1("dut :").s1("dut").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("dut.clk").s1("clk").start()),
+        Some(code.s1("clk : in bit").s1("clk").pos())
+    );
+    // Declarations within the architecture of the instance are not followed
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("dut.internal").s1("internal").start()
+        ),
+        None
+    );
+}
+
 #[test]
 fn block_names_are_visible() {
     check_code_with_no_diagnostics(
@@ -1304,6 +1545,43 @@ end architecture;",
     );
 }
 
+#[test]
+fn reference_at_record_field_use() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  type rec_t is record
+    field : natural;
+  end record;
+
+  signal sig : rec_t;
+begin
+  sig.field <= 1;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let decl = code.s1("field");
+    let (decl_pos, id) = root
+        .reference_at(&code.s1("sig.field").s1("field").pos())
+        .unwrap();
+    assert_eq!(decl_pos, decl.pos());
+    assert_eq!(root.get_ent(id).decl_pos(), Some(&decl.pos()));
+    assert!(matches!(
+        root.get_ent(id).kind(),
+        AnyEntKind::ElementDeclaration(..)
+    ));
+
+    assert_eq!(root.reference_at(&code.s1("<=").pos()), None);
+}
+
 #[test]
 fn record_subtype_can_be_selected() {
     let mut builder = LibraryBuilder::new();
@@ -1953,7 +2231,7 @@ package body pkg is
 end package body pkg;
 
 configuration cfg1 of ent1 is
-  for rtl(0)
+  for a1
   end for;
 end configuration cfg1;
 
@@ -2033,3 +2311,376 @@ end architecture;
         );
     }
 }
+
+#[test]
+fn selected_element_of_record_subtype_has_element_constraint() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  type rec_t is record
+    field : bit_vector;
+  end record;
+
+  subtype rec_c is rec_t(field(0 to 7));
+  signal sig : rec_c;
+  signal vec : bit_vector(0 to 7);
+begin
+  vec <= sig.field;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let elem = root
+        .search_reference(code.source(), code.sa("sig.", "field").start())
+        .unwrap();
+    let AnyEntKind::ElementDeclaration(subtype) = elem.kind() else {
+        panic!("Expected element declaration");
+    };
+    assert_eq!(subtype.type_mark().designator().to_string(), "BIT_VECTOR");
+    assert_eq!(
+        subtype.static_range(),
+        Some(StaticRange::new(0, 7, crate::ast::Direction::Ascending))
+    );
+
+    // The constrained element still refers to its declaration
+    assert_eq!(elem.decl_pos(), Some(&code.s1("field").pos()));
+    assert_eq!(
+        root.find_all_references_pos(&code.s1("field").pos()),
+        vec![code.s1("field").pos(), code.sa("sig.", "field").pos()]
+    );
+}
+
+#[test]
+fn iterates_entities_of_analyzed_design() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal the_signal : natural;
+begin
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let signals: Vec<_> = root
+        .entities()
+        .filter(|ent| ent.designator() == &Designator::Identifier(root.symbol_utf8("the_signal")))
+        .collect();
+    assert_eq!(signals.len(), 1);
+
+    let ent = signals[0];
+    assert!(matches!(ent.kind(), AnyEntKind::Object(obj) if obj.class == ObjectClass::Signal));
+    assert_eq!(ent.decl_pos(), Some(&code.s1("the_signal").pos()));
+    assert_eq!(root.get_ent(ent.id()).id(), ent.id());
+
+    // The standard package is also part of the design
+    assert!(root
+        .entities()
+        .any(|ent| matches!(ent.kind(), AnyEntKind::Type(Type::Enum(..)))
+            && ent.designator() == &Designator::Identifier(root.symbol_utf8("boolean"))));
+}
+
+#[test]
+fn block_statement_has_own_declarative_region() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal outer : natural;
+begin
+  blk0 : block (outer = 0)
+    signal inner : natural;
+  begin
+    inner <= guarded outer when guard else 0;
+    proc : process
+    begin
+      wait;
+    end process;
+  end block;
+
+  blk1 : block (outer)
+  begin
+    proc : process
+    begin
+      wait;
+    end process;
+  end block;
+
+  outer <= inner;
+  outer <= guarded 0;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("(outer)").s1("outer"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(code.s1("<= inner").s1("inner"), "No declaration of 'inner'"),
+            Diagnostic::error(
+                code.s1("outer <= guarded").s1("outer"),
+                "Guarded signal assignment requires a GUARD signal",
+            ),
+        ],
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("inner <=").start()),
+        Some(code.s1("inner").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("when guard").s1("guard").start()),
+        Some(code.s1("outer = 0").pos())
+    );
+}
+
+#[test]
+fn guarded_signal_assignment_requires_boolean_guard_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+  signal en : boolean;
+begin
+  blk0 : block (en)
+  begin
+    sig <= guarded 1;
+    gen : if true generate
+      sig <= guarded 2;
+    end generate;
+  end block;
+
+  blk1 : block
+    signal guard : boolean;
+  begin
+    sig <= guarded 3;
+  end block;
+
+  blk2 : block
+    constant guard : natural := 0;
+  begin
+    sig <= guarded 4;
+  end block;
+
+  sig <= guarded 5;
+end architecture;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("sig <= guarded 4").s1("sig"),
+                "Guarded signal assignment requires a GUARD signal of type 'BOOLEAN', got constant 'guard'",
+            )
+            .related(code.s1("constant guard").s1("guard"), "Defined here"),
+            Diagnostic::error(
+                code.s1("sig <= guarded 5").s1("sig"),
+                "Guarded signal assignment requires a GUARD signal",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn disconnection_specification_resolves_guarded_signals() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal reg : bit register;
+  signal bs : bit bus;
+  disconnect reg, bs : bit after 5 ns;
+  disconnect others : bit after 0 ns;
+begin
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("reg, bs").start()),
+        Some(code.s1("reg").pos())
+    );
+}
+
+#[test]
+fn disconnection_specification_requires_guarded_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal reg : bit register;
+  signal plain : bit;
+  constant c : bit := '0';
+  disconnect plain : bit after 5 ns;
+  disconnect c : bit after 5 ns;
+  disconnect reg : boolean after 5 ns;
+  disconnect reg : bit after 5;
+begin
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("disconnect plain").s1("plain"),
+                "signal 'plain' is not a guarded signal",
+            ),
+            Diagnostic::error(
+                code.s1("disconnect c :").s1("c :").s1("c"),
+                "constant 'c' is not a guarded signal",
+            ),
+            Diagnostic::error(
+                code.s1("disconnect reg : boolean").s1("reg"),
+                "signal 'reg' does not match type 'BOOLEAN'",
+            ),
+            Diagnostic::error(
+                code.s1("after 5;").s1("5"),
+                "integer literal does not match physical type 'TIME'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn node_at_finds_smallest_enclosing_node() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t is record
+    field : natural;
+  end record;
+  constant rec : rec_t := (field => 0);
+  constant c : natural := rec.field + 1;
+end package;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    // The selected name is the smallest name that contains its suffix
+    let node = root
+        .node_at(&code.s1("rec.field").s1("field").pos())
+        .unwrap();
+    assert_eq!(node.pos(), &code.s1("rec.field").pos());
+    assert!(matches!(
+        node,
+        AstNode::Name(WithPos {
+            item: Name::Selected(..),
+            ..
+        })
+    ));
+
+    let node = root.node_at(&code.s1("rec.field").s1("rec").pos()).unwrap();
+    assert_eq!(node.pos(), &code.s1("rec.field").s1("rec").pos());
+    assert!(matches!(
+        node,
+        AstNode::Name(WithPos {
+            item: Name::Designator(..),
+            ..
+        })
+    ));
+
+    let node = root.node_at(&code.s1("rec.field + 1").pos()).unwrap();
+    assert!(
+        matches!(node, AstNode::Expression(ref expr) if matches!(expr.item, Expression::Binary(..)))
+    );
+
+    assert_eq!(root.node_at(&code.s1("package pkg").pos()), None);
+}
+
+#[test]
+fn resolves_member_of_nested_package() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package outer_pkg is
+  package inner_pkg is
+    constant x : natural := 1;
+    function f return natural;
+  end package;
+
+  package body inner_pkg is
+    function f return natural is
+    begin
+      return x;
+    end function;
+  end package body;
+end package;
+
+use work.outer_pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant c0 : natural := work.outer_pkg.inner_pkg.x;
+  constant c1 : natural := inner_pkg.f;
+  constant c2 : natural := inner_pkg.missing;
+begin
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("missing"),
+            "No declaration of 'missing' within package 'inner_pkg'",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("x", 3).start()),
+        Some(code.s1("x").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("inner_pkg.f", 1).s1("f").start()),
+        Some(code.s1("f return natural;").s1("f").pos())
+    );
+}
@@ -470,6 +470,51 @@ end package body;
     );
 }
 
+#[test]
+fn type_alias_in_subtype_indications() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+  field : natural;
+end record;
+alias my_int is integer;
+alias my_rec is rec_t;
+alias my_bv is bit_vector;
+
+subtype sub_t is my_int range 0 to 3;
+constant c0 : my_int range 0 to 7 := 5;
+constant c1 : my_rec := (field => 1);
+constant c2 : my_bv(0 to 1) := \"01\";
+constant c3 : natural := my_int'high;
+constant c4 : my_int := my_int'(3) + my_int(2.0);
+type acc_t is access my_rec;
+constant bad : my_bv(0 to 1) := my_rec'(field => 1);
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("my_rec'(field => 1)"),
+            "alias 'my_rec' does not match alias 'my_bv'",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("sub_t is my_int").s1("my_int").start()
+        ),
+        Some(code.s1("my_int").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("my_bv", 2).start()),
+        Some(code.s1("my_bv").pos())
+    );
+}
+
 pub fn kind_error(
     code: &Code,
     name: &str,
@@ -115,3 +115,141 @@ end architecture;
     let (_, diagnostics) = builder.get_analyzed_root();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+fn wait_statement_clauses_are_type_checked() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process
+    variable v : natural;
+  begin
+    wait on sig until sig = '1' for 10 ns;
+    wait on v;
+    wait until v;
+    wait for v;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("wait on v").s1("v"),
+                "variable 'v' is not a signal and cannot be in a sensitivity list",
+            ),
+            Diagnostic::error(
+                code.s1("wait until v").s1("v"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(
+                code.s1("wait for v").s1("v"),
+                "variable 'v' of subtype 'NATURAL' does not match physical type 'TIME'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn process_all_is_analyzed() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, b, c : bit;
+begin
+  main: process (all)
+  begin
+    if a = '1' then
+      c <= b;
+    else
+      c <= '0';
+    end if;
+  end process main;
+end architecture;
+        ",
+    );
+    let (_, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn process_all_cannot_contain_wait_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, c : bit;
+begin
+  process (all)
+  begin
+    for i in 0 to 1 loop
+      wait on a;
+    end loop;
+    c <= a;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("wait on a;"),
+            "A process with a sensitivity list cannot contain a wait statement",
+        )],
+    );
+}
+
+#[test]
+fn process_with_sensitivity_list_cannot_contain_wait_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, c : bit;
+begin
+  process (a)
+  begin
+    c <= a;
+    wait for 1 ns;
+  end process;
+
+  process
+  begin
+    c <= a;
+    wait for 1 ns;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("wait for 1 ns;"),
+            "A process with a sensitivity list cannot contain a wait statement",
+        )],
+    );
+}
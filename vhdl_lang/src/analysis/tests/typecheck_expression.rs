@@ -5,6 +5,7 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::VhdlStandard;
 
 #[test]
 fn test_integer_literal_expression_typecheck() {
@@ -422,6 +423,61 @@ constant bar : natural := foo(0);
     );
 }
 
+#[test]
+fn indexing_a_record_hints_at_selection() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+  field : natural;
+end record;
+constant rec : rec_t := (field => 0);
+constant bar : natural := rec(0);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("rec(0)").s1("rec"),
+            "constant 'rec' of record type 'rec_t' cannot be indexed",
+        )
+        .related(
+            code.s1("rec(0)").s1("rec"),
+            "Elements of record type 'rec_t' are selected with '.' rather than indexed",
+        )],
+    );
+}
+
+#[test]
+fn selecting_from_an_array_hints_at_indexing() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+  field : natural;
+end record;
+type arr_t is array (0 to 1) of rec_t;
+constant arr : arr_t := (others => (field => 0));
+constant bar : natural := arr.field;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("arr.field").s1("arr"),
+            "constant 'arr' of array type 'arr_t' cannot be selected",
+        )
+        .related(
+            code.s1("arr.field").s1("arr"),
+            "Index array type 'arr_t' with '(...)' before selecting from an element",
+        )],
+    );
+}
+
 #[test]
 fn test_name_can_be_sliced() {
     let mut builder = LibraryBuilder::new();
@@ -463,6 +519,30 @@ end procedure;
     check_no_diagnostics(&diagnostics);
 }
 
+#[test]
+fn non_access_type_cannot_be_dereferenced() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure proc is
+   variable myvar : integer;
+   variable foo : integer;
+begin
+    foo := myvar.all;
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("myvar.all").s1("myvar"),
+            "integer type 'INTEGER' is not an access type and cannot be dereferenced with .all",
+        )],
+    );
+}
+
 #[test]
 fn function_result_can_be_indexed() {
     let mut builder = LibraryBuilder::new();
@@ -481,6 +561,63 @@ constant good : natural := thefun(0)(0);
     check_no_diagnostics(&diagnostics);
 }
 
+#[test]
+fn function_result_can_be_selected_then_indexed() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+  arr_field : integer_vector(0 to 3);
+  nested : integer_vector(0 to 1);
+end record;
+
+type rec_vec_t is array (natural range <>) of rec_t;
+
+function thefun(arg : natural) return rec_t is
+begin
+   return (arr_field => (others => arg), nested => (others => arg));
+end;
+
+function noarg return rec_t is
+begin
+   return thefun(0);
+end;
+
+function thevec(arg : natural) return rec_vec_t is
+begin
+   return (0 => thefun(arg));
+end;
+
+constant good1 : integer := thefun(0).arr_field(2);
+constant good2 : integer_vector := thefun(0).arr_field(1 to 2);
+constant good3 : integer := thevec(0)(0).nested(1);
+constant good4 : integer := noarg.arr_field(3);
+constant bad : boolean := thefun(0).arr_field(2);
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("boolean := thefun(0).arr_field(2)")
+                .s1("thefun(0).arr_field(2)"),
+            "Expression of integer type 'INTEGER' does not match type 'BOOLEAN'",
+        )],
+    );
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("(0).arr_field(2)").s1("arr_field").start()
+        ),
+        Some(code.s1("arr_field").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(0)(0).nested").s1("nested").start()),
+        Some(code.s1("nested").pos())
+    );
+}
+
 #[ignore = "Does not work yet"]
 #[test]
 fn function_result_can_be_indexed_no_arg() {
@@ -513,6 +650,39 @@ fn test_type_conversion() {
     check_no_diagnostics(&diagnostics);
 }
 
+#[test]
+fn array_type_conversion_of_closely_related_types() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+  type unsigned is array (natural range <>) of std_ulogic;
+  type int_vec_t is array (natural range <>) of integer;
+
+  constant uns : unsigned(7 downto 0) := (others => '0');
+  constant slv : std_logic_vector(7 downto 0) := std_logic_vector(uns);
+  constant uns2 : unsigned(7 downto 0) := unsigned(slv);
+  constant ints : int_vec_t(7 downto 0) := (others => 0);
+  constant bad : std_logic_vector(7 downto 0) := std_logic_vector(ints);
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("std_logic_vector(ints)").s1("ints"),
+            "array type 'int_vec_t' cannot be converted to subtype 'STD_LOGIC_VECTOR'",
+        )],
+    );
+}
+
 #[test]
 fn test_indexed_array_dimension_check() {
     let mut builder = LibraryBuilder::new();
@@ -554,6 +724,57 @@ constant bar4 : natural := foo2(0, 1);
     );
 }
 
+#[test]
+fn indexing_array_of_arrays_and_multidimensional_array() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy);
+type row_t is array (state_t) of natural;
+type rows_t is array (0 to 1) of row_t;
+type matrix_t is array (0 to 1, state_t) of natural;
+constant rows : rows_t := ((0, 1), (2, 3));
+constant matrix : matrix_t := ((0, 1), (2, 3));
+
+constant good1 : row_t := rows(0);
+constant good2 : natural := rows(0)(busy);
+constant good3 : natural := matrix(1, idle);
+
+constant bad1 : natural := rows(0);
+constant bad2 : natural := rows(0)(1);
+constant bad3 : natural := matrix(1, 0);
+constant bad4 : natural := matrix(1)(idle);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("natural := rows(0);").s1("rows(0)"),
+                "array type 'row_t' does not match subtype 'NATURAL'",
+            ),
+            Diagnostic::error(
+                code.s1("rows(0)(1)").s1("(1)").s1("1"),
+                "integer literal does not match type 'state_t'",
+            ),
+            Diagnostic::error(
+                code.s1("matrix(1, 0)").s1("0"),
+                "integer literal does not match type 'state_t'",
+            ),
+            Diagnostic::error(
+                code.s1("matrix(1)(idle)").s1("matrix(1)"),
+                "Number of indexes does not match array dimension",
+            )
+            .related(
+                code.s1("matrix_t"),
+                "Array type 'matrix_t' has 2 dimensions, got 1 index",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn test_disambiguates_indexed_name_and_function_call() {
     let mut builder = LibraryBuilder::new();
@@ -955,6 +1176,40 @@ constant bad : rec_t := (field => 0);
     );
 }
 
+#[test]
+fn array_of_record_aggregate() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+    field : natural;
+    flag : boolean;
+end record;
+type arr_t is array (0 to 1) of rec_t;
+
+constant good1 : arr_t := (0 => (field => 1, flag => true), 1 => (field => 2, flag => false));
+constant good2 : arr_t := ((1, true), (flag => false, field => 2));
+constant bad1 : arr_t := (0 => (field => 1, flag => true), 1 => (field => true, flag => false));
+constant bad2 : arr_t := (others => (field => 1, flag => 'a'));
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("field => true").s1("true"),
+                "'true' does not match integer type 'INTEGER'",
+            ),
+            Diagnostic::error(
+                code.s1("'a'"),
+                "character literal does not match type 'BOOLEAN'",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn record_others() {
     let mut builder = LibraryBuilder::new();
@@ -998,6 +1253,84 @@ constant bad3 : rec_t := ('a', 0, 0, others => 3);
     );
 }
 
+#[test]
+fn others_must_be_last_and_alone() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+    f1 : integer;
+    f2 : integer;
+end record;
+type arr_t is array (0 to 3) of natural;
+
+constant good : arr_t := (0 => 1, others => 0);
+constant bad1 : arr_t := (others => 0, 0 => 1);
+constant bad2 : arr_t := (0 | others => 0);
+constant bad3 : rec_t := (others => 0, f1 => 1);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("(others => 0, 0 => 1)").s1("others"),
+                "'others' must be the last choice of an aggregate",
+            ),
+            Diagnostic::error(
+                code.s1("0 | others").s1("others"),
+                "'others' must be the only choice of an element association",
+            ),
+            Diagnostic::error(
+                code.s1("(others => 0, f1 => 1)").s1("others"),
+                "'others' must be the last choice of an aggregate",
+            ),
+            Diagnostic::error(
+                code.s1("f1 => 1").s1("f1"),
+                "Record element 'f1' has already been associated",
+            )
+            .related(
+                code.s1("(others => 0, f1 => 1)").s1("others"),
+                "Previously associated here",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn others_requires_constrained_array() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type arr_t is array (natural range <>) of natural;
+subtype sub_t is arr_t(0 to 3);
+
+constant good1 : arr_t(0 to 3) := (others => 0);
+constant good2 : sub_t := (others => 0);
+constant good3 : arr_t := (0, 1, 2);
+constant bad1 : arr_t := (others => 0);
+constant bad2 : bit_vector := (0 => '1', others => '0');
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("bad1 : arr_t := (others").s1("others"),
+                "'others' cannot be used when the bounds of array type 'arr_t' are not known",
+            ),
+            Diagnostic::error(
+                code.s1("others => '0'").s1("others"),
+                "'others' cannot be used when the bounds of array type 'BIT_VECTOR' are not known",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn typecheck_aggregate_element_association_expr() {
     let mut builder = LibraryBuilder::new();
@@ -1013,7 +1346,7 @@ constant good1 : integer_vector := (0, 1, 2);
 constant good2 : arr2_t := ((0, 1), (2, 3));
 constant good3 : rec_t := (field => 0);
 constant bad1 : integer_vector := (3, 4, 'c');
-constant bad2 : integer_vector := (others => 'd');
+constant bad2 : integer_vector(0 to 1) := (others => 'd');
 constant bad3 : integer_vector := (1 to 3 => 'e');
 constant bad4 : rec_t := (field => 'f');
 
@@ -1205,6 +1538,40 @@ constant bad2 : character := 'a' + 'b';
     );
 }
 
+#[test]
+fn operator_argument_disambiguates_overloaded_enum_literal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type enum1_t is (alpha, beta);
+type enum2_t is (alpha, gamma);
+constant x : enum1_t := beta;
+constant y : enum2_t := gamma;
+constant good1 : boolean := x = alpha;
+constant good2 : boolean := alpha /= y;
+constant bad : boolean := x = gamma;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("x = gamma").s1("="),
+            "Found no match for operator \"=\"",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("alpha", 3).start()),
+        Some(code.s("alpha", 1).pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("alpha", 4).start()),
+        Some(code.s("alpha", 2).pos())
+    );
+}
+
 #[test]
 fn overloading_nested_ambiguous_op_has_acceptable_performance() {
     let mut builder = LibraryBuilder::new();
@@ -1398,20 +1765,23 @@ end;
 }
 
 #[test]
-fn typecheck_report_statement() {
+fn typecheck_nested_function_return_statement() {
     let mut builder = LibraryBuilder::new();
     let code = builder.in_declarative_region(
         "
-procedure wrapper is
+function fun(arg : natural) return integer is
 begin
-   report \"good\";
-   report 16#bad#;
-   report \"good\" severity error;
-   report \"good\" severity \"bad\";
+  for i in 0 to 3 loop
+    if i = arg then
+      return i;
+    end if;
+  end loop;
+
+  case arg is
+    when 0 => return 'c';
+    when others => return;
+  end case;
 end;
-
-
-
 ",
     );
 
@@ -1420,35 +1790,117 @@ end;
         diagnostics,
         vec![
             Diagnostic::error(
-                code.s1("16#bad#"),
-                "integer literal does not match array type 'STRING'",
+                code.s1("'c'"),
+                "character literal does not match integer type 'INTEGER'",
             ),
             Diagnostic::error(
-                code.s1("\"bad\""),
-                "string literal does not match type 'SEVERITY_LEVEL'",
+                code.s1("return;"),
+                "Functions cannot return without a value",
             ),
         ],
     );
 }
 
 #[test]
-fn typecheck_assert_statement() {
+fn typecheck_nested_procedure_return_statement() {
     let mut builder = LibraryBuilder::new();
     let code = builder.in_declarative_region(
         "
-procedure wrapper is
+procedure proc(arg : natural) is
 begin
-   assert true report \"good\";
-   assert true report 16#bad#;
-   assert true report \"good\" severity error;
-   assert true report \"good\" severity \"bad\";
-   assert 123;
-   assert bit'('0');
+  for i in 0 to 3 loop
+    if i = arg then
+      return;
+    elsif i = 2 then
+      return i;
+    end if;
+  end loop;
+
+  case arg is
+    when 0 => return;
+    when others => return arg;
+  end case;
 end;
-",
-    );
 
-    let diagnostics = builder.analyze();
+function fun(arg : natural) return integer is
+begin
+  if arg = 0 then
+    return 0;
+  elsif arg = 1 then
+    return;
+  else
+    return arg;
+  end if;
+end;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(code.s1("return i;"), "Procedures cannot return a value"),
+            Diagnostic::error(code.s1("return arg;"), "Procedures cannot return a value"),
+            Diagnostic::error(
+                code.s1("return;\n  else").s1("return;"),
+                "Functions cannot return without a value",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_report_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure wrapper is
+begin
+   report \"good\";
+   report 16#bad#;
+   report \"good\" severity error;
+   report \"good\" severity \"bad\";
+end;
+
+
+
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("16#bad#"),
+                "integer literal does not match array type 'STRING'",
+            ),
+            Diagnostic::error(
+                code.s1("\"bad\""),
+                "string literal does not match type 'SEVERITY_LEVEL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_assert_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure wrapper is
+begin
+   assert true report \"good\";
+   assert true report 16#bad#;
+   assert true report \"good\" severity error;
+   assert true report \"good\" severity \"bad\";
+   assert 123;
+   assert bit'('0');
+end;
+",
+    );
+
+    let diagnostics = builder.analyze();
     check_diagnostics(
         diagnostics,
         vec![
@@ -1468,6 +1920,76 @@ end;
     );
 }
 
+#[test]
+fn typecheck_concurrent_assert_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal valid : boolean;
+begin
+  assert valid report \"good\" severity failure;
+  postponed assert 123;
+  assert valid report 16#bad#;
+  assert valid severity \"bad\";
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("123"),
+                "type universal_integer cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(
+                code.s1("16#bad#"),
+                "integer literal does not match array type 'STRING'",
+            ),
+            Diagnostic::error(
+                code.s1("\"bad\""),
+                "string literal does not match type 'SEVERITY_LEVEL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_concurrent_assert_in_generate() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal count : natural;
+begin
+  gen: for i in 0 to 1 generate
+    chk: assert count report \"bad\";
+    assert i = 0 or count > 0;
+  end generate;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("assert count").s1("count"),
+            "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+        )],
+    );
+}
+
 #[test]
 fn resolves_unambiguous_boolean_reference() {
     let mut builder = LibraryBuilder::new();
@@ -1834,6 +2356,47 @@ constant good6 : bit := \"01\" ?>= \"10\";
     check_no_diagnostics(&diagnostics);
 }
 
+#[test]
+fn bit_vector_shift_operators() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant bv : bit_vector(0 to 3) := \"0110\";
+constant good1 : bit_vector := bv sll 1;
+constant good2 : bit_vector := bv srl 1;
+constant good3 : bit_vector := bv sla 1;
+constant good4 : bit_vector := bv sra 1;
+constant good5 : bit_vector := bv rol 1;
+constant good6 : boolean_vector := (true, false) ror 1;
+constant bad1 : bit_vector := bv sll '1';
+constant bad2 : integer := 1 sll 1;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("bv sll '1'").s1("sll"),
+                "Found no match for operator \"sll\"",
+            ),
+            Diagnostic::error(
+                code.s1("1 sll 1").s1("sll"),
+                "Found no match for operator \"sll\"",
+            ),
+        ],
+    );
+
+    let sll = root
+        .search_reference(code.source(), code.s1("bv sll 1").s1("sll").start())
+        .unwrap();
+    assert_eq!(
+        sll.describe(),
+        "operator \"sll\"[BIT_VECTOR, INTEGER return BIT_VECTOR]"
+    );
+}
+
 #[test]
 fn std_ulogic_matching_operators() {
     let mut builder = LibraryBuilder::new();
@@ -1865,3 +2428,719 @@ end package;
     let diagnostics = builder.analyze();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+fn clock_edge_idioms() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, d, q : std_ulogic;
+  signal bclk : bit;
+begin
+  q <= d when clk'event and clk = '1';
+  q <= d when rising_edge(clk);
+
+  process (clk, bclk)
+  begin
+    if clk'event and clk = '1' then
+      q <= d;
+    end if;
+    if clk'event and not clk'stable then
+      q <= d;
+    end if;
+    if not clk'stable and clk'last_value = '0' and clk = '1' then
+      q <= d;
+    end if;
+    if falling_edge(clk) then
+      q <= d;
+    end if;
+    if bclk'event and bclk = '1' then
+      q <= d;
+    end if;
+    if rising_edge(bclk) then
+      q <= d;
+    end if;
+
+    if clk'event and clk then
+      q <= d;
+    end if;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("clk'event and clk then").s1("and"),
+            "Found no match for operator \"and\"",
+        )],
+    );
+}
+
+#[test]
+fn slice_direction_must_match_index_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(7 downto 0);
+signal s : bit_vector(7 downto 0);
+signal w : word_t;
+signal t : bit_vector(0 to 3);
+signal u : bit_vector(3 downto 0);
+
+procedure proc is
+begin
+  t <= s(0 to 3);
+  u <= s(3 downto 0);
+  t <= w(0 to 3);
+  t <= t(0 to 3);
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("s(0 to 3)").s1("0 to 3"),
+                "Slice direction 'to' does not match the 'downto' index range of signal 's'",
+            ),
+            Diagnostic::warning(
+                code.s1("w(0 to 3)").s1("0 to 3"),
+                "Slice direction 'to' does not match the 'downto' index range of signal 'w'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn aggregate_choices_cover_constrained_target() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(0 to 7);
+constant c0 : word_t := (0 => '1', 1 to 3 => '0', others => '1');
+constant c1 : word_t := (7 downto 4 => '1', 0 to 3 => '0');
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn aggregate_choices_must_not_overlap() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(0 to 7);
+constant c0 : word_t := (0 to 3 => '1', 3 => '0', others => '1');
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("3 => '0'").s1("3"),
+            "Aggregate choice overlaps with a previous choice",
+        )
+        .related(code.s1("0 to 3"), "Previously covered here")],
+    );
+}
+
+#[test]
+fn aggregate_choices_must_cover_constrained_target() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(0 to 7);
+constant c0 : word_t := (0 to 3 => '1', 5 => '0');
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("(0 to 3 => '1', 5 => '0')"),
+            "Missing choices for subtype 'word_t': 4, 6 to 7",
+        )],
+    );
+}
+
+#[test]
+fn typecheck_allocator() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type int_ptr_t is access integer;
+type vec_ptr_t is access integer_vector;
+
+procedure proc is
+  variable iptr : int_ptr_t;
+  variable vptr : vec_ptr_t;
+begin
+  iptr := new integer;
+  iptr := new integer'(0);
+  vptr := new integer_vector'(0, 1, 2);
+  vptr := new integer_vector(0 to 1);
+
+  iptr := new character;
+  vptr := new integer'(0);
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("new character"),
+                "allocator of type 'CHARACTER' does not match access type 'int_ptr_t'",
+            ),
+            Diagnostic::error(
+                code.s("new integer'(0)", 2),
+                "allocator of integer type 'INTEGER' does not match access type 'vec_ptr_t'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn static_initial_value_within_subtype_range() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+subtype active_t is state_t range busy to done;
+constant c0 : natural := 0;
+constant c1 : integer range -1 to 1 := -1;
+constant c2 : active_t := done;
+constant c3 : positive := natural'high;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn static_initial_value_outside_of_subtype_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+subtype active_t is state_t range busy to done;
+constant c0 : natural := -1;
+signal s0 : integer range 7 downto 0 := 2 * 4;
+constant c1 : active_t := idle;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("-1"),
+                "Value -1 is outside of the range 0 to 2147483647",
+            ),
+            Diagnostic::error(
+                code.s1("2 * 4"),
+                "Value 8 is outside of the range 7 downto 0",
+            ),
+            Diagnostic::error(
+                code.s1(":= idle").s1("idle"),
+                "Value idle is outside of the range busy to done",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn object_alias_with_subtype_indication() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+signal sig : bit_vector(7 downto 0);
+alias a0 : bit_vector(0 to 7) is sig;
+alias a1 : bit is sig(0);
+constant c0 : bit := a1;
+constant c1 : bit_vector(0 to 7) := a0;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn object_alias_with_mismatched_subtype_indication() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal sig : bit_vector(7 downto 0);
+alias a0 : natural is sig;
+alias a1 : bit is natural;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("alias a0 : natural").s1("natural"),
+                "signal 'sig' of array type 'BIT_VECTOR' does not match subtype 'NATURAL'",
+            ),
+            Diagnostic::error(
+                code.s1("alias a1 : bit").s1("bit"),
+                "Only an object alias may have a subtype indication, got subtype 'NATURAL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn attribute_of_object_subtype() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+type state_vec_t is array (natural range <>) of state_t;
+signal sig : integer range 0 to 7;
+signal vec : state_vec_t(0 to 3);
+constant c0 : natural := sig'subtype'left;
+constant c1 : state_t := vec'element'high;
+constant c2 : state_t := sig'subtype'high;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("sig'subtype'high"),
+            "Expression of integer type 'INTEGER' does not match type 'state_t'",
+        )],
+    );
+}
+
+#[test]
+fn object_subtype_attribute_keeps_constraint() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal vec : bit_vector(7 downto 0);
+signal num : integer range 0 to 3;
+signal copy : vec'subtype;
+constant c0 : integer range 8 to 8 := vec'subtype'length;
+constant c1 : integer range 7 to 7 := copy'subtype'high;
+constant c2 : integer range 0 to 7 := vec'subtype'length;
+constant c3 : num'subtype := 4;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("vec'subtype'length", 2),
+                "Value 8 is outside of the range 0 to 7",
+            ),
+            Diagnostic::error(
+                code.s1(":= 4").s1("4"),
+                "Value 4 is outside of the range 0 to 3",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn image_and_value_of_enum_with_character_literals() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+  type enum_t is ('a', 'b', alpha);
+
+  constant x : std_ulogic := '1';
+  constant img : string := std_ulogic'image(x);
+  constant val : std_ulogic := std_ulogic'value(\"'1'\");
+  constant char_img : string := std_ulogic'image('Z');
+  constant enum_img : string := enum_t'image('b');
+  constant enum_val : enum_t := enum_t'value(\"alpha\");
+  constant bad : std_ulogic := std_ulogic'value('1');
+end package;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("std_ulogic'value('1')").s1("'1'"),
+            "character literal does not match array type 'STRING'",
+        )],
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("image(x)").s1("x").start()),
+        Some(code.s1("x :").s1("x").pos())
+    );
+}
+
+#[test]
+fn image_of_object_in_vhdl_2019() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    builder.in_declarative_region(
+        "
+signal sig : natural;
+constant img : string := sig'image;
+constant arg_img : string := natural'image(sig);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn image_of_object_before_vhdl_2019() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL1993);
+    let code = builder.in_declarative_region(
+        "
+signal sig : natural;
+constant img : string := sig'image;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("sig'image"),
+            "'image of an object requires VHDL-2019, use NATURAL'image(sig) instead",
+        )],
+    );
+}
+
+#[test]
+fn conditional_expression() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    let code = builder.in_declarative_region(
+        "
+procedure proc(cond : boolean; sel : bit) is
+  variable a, b : natural;
+  variable x : natural;
+  variable str : string(1 to 3);
+  variable ch : character;
+begin
+  x := (a when cond else b);
+  x := (a when cond else 1 when sel else b + 1);
+  str := (\"abc\" when cond else \"def\");
+  x := (a when x else b);
+  x := (a when cond else str);
+  ch := str((1 when cond else 2));
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("when x").s1("x"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(
+                code.s1("else str").s1("str"),
+                "variable 'str' of array type 'STRING' does not match subtype 'NATURAL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn selected_expression() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done);
+
+procedure proc(state : state_t; sel : bit) is
+  variable a, b : natural;
+  variable x : natural;
+  variable ch : character;
+begin
+  x := with state select (a when idle, b when busy, 1 when done);
+  x := with sel select (a when '0', b + 1 when others);
+  ch := with sel select ('a' when '0', 'b' when '1');
+  assert with state select (true when idle, false when others);
+  x := with state select (a when idle, b when busy);
+  x := with sel select (a when '0', ch when '1');
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("with state select (a when idle, b when busy)")
+                    .s1("state"),
+                "Missing choice for type 'state_t': done",
+            ),
+            Diagnostic::error(
+                code.s1("ch when").s1("ch"),
+                "variable 'ch' of type 'CHARACTER' does not match subtype 'NATURAL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn universal_alternative_takes_the_type_of_the_other_alternatives() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    let code = builder.in_declarative_region(
+        "
+type my_int is range 0 to 10;
+function f(arg : integer) return boolean is
+begin
+  return true;
+end function;
+function f(arg : my_int) return boolean is
+begin
+  return false;
+end function;
+
+procedure proc(cond : boolean; sel : bit) is
+  variable i : integer;
+  variable b : boolean;
+begin
+  b := f((1 when cond else i));
+  b := f((with sel select (1 when '0', i when others)));
+  b := f((1 when cond else 2));
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("f((1 when cond else 2))").s1("f"),
+            "Ambiguous call to 'f'",
+        )
+        .related(
+            code.s("function f(", 1).s1("f(").s1("f"),
+            "Migth be function f[INTEGER return BOOLEAN]",
+        )
+        .related(
+            code.s("function f(", 2).s1("f(").s1("f"),
+            "Migth be function f[my_int return BOOLEAN]",
+        )],
+    );
+}
+
+#[test]
+fn file_declaration_open_information() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type ft is file of integer;
+constant name : string := \"name.txt\";
+file f0 : ft open read_mode is \"name.txt\";
+file f1 : ft is name;
+file f2 : ft;
+file f3 : ft open 1 is \"name.txt\";
+file f4 : ft open write_mode is 5;
+file f5 : integer is \"name.txt\";
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("open 1").s1("1"),
+                "integer literal does not match type 'FILE_OPEN_KIND'",
+            ),
+            Diagnostic::error(
+                code.s1("is 5").s1("5"),
+                "integer literal does not match array type 'STRING'",
+            ),
+            Diagnostic::error(
+                code.s1("f5 : integer").s1("integer"),
+                "integer type 'INTEGER' is not a file type",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn selected_expression_covers_subtype_of_selector() {
+    let mut builder = LibraryBuilder::with_standard(VhdlStandard::VHDL2019);
+    builder.in_declarative_region(
+        "
+type state_t is (idle, busy, done, error);
+subtype active_t is state_t range busy to done;
+type arr_t is array (0 to 1) of active_t;
+
+function f return active_t is
+begin
+  return busy;
+end function;
+
+procedure proc(arr : arr_t; state : state_t) is
+  variable x : natural;
+begin
+  x := with arr(0) select (1 when busy, 2 when done);
+  x := with active_t'(state) select (1 when busy, 2 when done);
+  x := with f select (1 when busy, 2 when done);
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn physical_literal_must_match_target_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type voltage_t is range 0 to 1000
+  units
+    mv;
+    volts = 1000 mv;
+  end units;
+
+constant good_time : time := 5 ns;
+constant good_voltage : voltage_t := 3 volts;
+constant bad_time : time := 5 volts;
+
+procedure proc is
+begin
+  wait for 5 ns;
+  wait for 5 volts;
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1(":= 5 volts").s1("5 volts"),
+                "physical type 'voltage_t' does not match physical type 'TIME'",
+            ),
+            Diagnostic::error(
+                code.s1("wait for 5 volts").s1("5 volts"),
+                "physical type 'voltage_t' does not match physical type 'TIME'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn subtype_attribute_of_slice_and_indexed_name() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype elem_t is integer range 0 to 7;
+type arr_t is array (natural range <>) of elem_t;
+signal arr : arr_t(0 to 7);
+constant c0 : integer range 4 to 4 := arr(0 to 3)'length;
+constant c1 : integer range 4 to 4 := arr(2 to 5)'subtype'length;
+constant c2 : integer range 3 to 3 := arr(0 to 3)'high;
+constant c3 : integer range 7 to 7 := arr(0)'subtype'high;
+constant c4 : integer range 0 to 3 := arr(0 to 3)'subtype'length;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("arr(0 to 3)'subtype'length"),
+            "Value 4 is outside of the range 0 to 3",
+        )],
+    );
+}
+
+#[test]
+fn subtype_constrained_by_range_attribute() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal data : bit_vector(3 to 10);
+subtype s is integer range data'range;
+subtype r is integer range data'reverse_range;
+constant c0 : integer range 8 to 8 := s'high - s'low + 1;
+constant c1 : integer range 10 to 10 := r'left;
+constant c2 : s := 2;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1(":= 2").s1("2"),
+            "Value 2 is outside of the range 3 to 10",
+        )],
+    );
+}
+
+#[test]
+fn universal_operand_converts_to_other_operand_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type my_int is range 0 to 100;
+type my_real is range 0.0 to 100.0;
+constant my_i : my_int := 5;
+constant my_r : my_real := 2.0;
+constant c0 : my_int := 3 + my_i;
+constant c1 : my_int := my_i + 3;
+constant c2 : my_real := 1.0 * my_r;
+constant c3 : my_real := my_r * 1.0;
+constant c4 : boolean := 3 < my_i;
+constant c5 : my_real := 1 * my_r;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("1 * my_r").s1("*"),
+            "Found no match for operator \"*\"",
+        )],
+    );
+}
@@ -8,6 +8,7 @@ use crate::analysis::DesignRoot;
 use crate::data::*;
 use crate::syntax::test::*;
 use crate::syntax::Symbols;
+use crate::VhdlStandard;
 use pretty_assertions::assert_eq;
 use std::collections::{hash_map::Entry, HashMap};
 use std::sync::Arc;
@@ -15,6 +16,7 @@ use std::sync::Arc;
 pub struct LibraryBuilder {
     code_builder: CodeBuilder,
     libraries: HashMap<Symbol, Vec<Code>>,
+    standard: VhdlStandard,
 }
 
 impl LibraryBuilder {
@@ -22,6 +24,15 @@ impl LibraryBuilder {
         LibraryBuilder {
             code_builder: CodeBuilder::new(),
             libraries: HashMap::default(),
+            standard: VhdlStandard::default(),
+        }
+    }
+
+    pub fn with_standard(standard: VhdlStandard) -> LibraryBuilder {
+        LibraryBuilder {
+            code_builder: CodeBuilder::with_standard(standard),
+            libraries: HashMap::default(),
+            standard,
         }
     }
 
@@ -71,6 +82,7 @@ end architecture;"
 
     pub fn get_analyzed_root(&self) -> (DesignRoot, Vec<Diagnostic>) {
         let mut root = DesignRoot::new(self.code_builder.symbols.clone());
+        root.set_standard(self.standard);
         let mut diagnostics = Vec::new();
 
         add_standard_library(self.symbols(), &mut root);
@@ -140,6 +152,7 @@ fn std_logic_1164_package() -> Source {
 pub fn add_standard_library(symbols: Arc<Symbols>, root: &mut DesignRoot) {
     let builder = CodeBuilder {
         symbols: symbols.clone(),
+        standard: VhdlStandard::default(),
     };
     let std_standard = builder.code_from_source(standard_package());
     let std_textio = builder.code_from_source(textio_package());
@@ -757,3 +757,40 @@ end architecture;
         .unwrap();
     assert_eq!(label.decl_pos(), Some(&code.sb("main", ": for i ").pos()));
 }
+
+#[test]
+fn hint_package_declaring_name_that_is_not_visible() {
+    let mut builder = LibraryBuilder::new();
+    let pkg = builder.code(
+        "libname",
+        "
+package pkg is
+  function to_int(value : boolean) return integer;
+  function to_int(value : character) return integer;
+end package;
+",
+    );
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant c0 : integer := to_int(true);
+begin
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(code.s1("to_int"), "No declaration of 'to_int'").related(
+                pkg.s1("to_int"),
+                "'to_int' is declared in package 'work.pkg', add 'use work.pkg.all;'",
+            ),
+        ],
+    );
+}
@@ -61,6 +61,18 @@ impl<'a> Visibility<'a> {
         });
     }
 
+    /// The entities made potentially visible by use clauses
+    pub fn visible_entities(&self) -> impl Iterator<Item = EntRef<'a>> + '_ {
+        self.all_in_regions
+            .iter()
+            .flat_map(|visible_region| visible_region.region.immediates())
+            .chain(
+                self.visible
+                    .values()
+                    .flat_map(|visible| visible.values().map(|visible_ent| visible_ent.entity)),
+            )
+    }
+
     pub fn add_context_visibility(
         &mut self,
         visible_pos: Option<&SrcPos>,
@@ -10,6 +10,8 @@
 #![allow(clippy::large_enum_variant)]
 
 mod display;
+pub mod organize;
+pub mod printer;
 mod util;
 
 #[macro_use]
@@ -308,6 +310,12 @@ pub enum Expression {
 
     /// LRM 9.3.7 Allocators
     New(Box<WithPos<Allocator>>),
+
+    /// VHDL-2019 conditional expression, the else item is always present
+    Conditional(Box<Conditionals<WithPos<Expression>>>),
+
+    /// VHDL-2019 selected expression
+    Selected(Box<Selection<WithPos<Expression>>>),
 }
 
 /// An identifier together with the lexical source location it occurs in.
@@ -419,6 +427,8 @@ pub enum Designator {
     OperatorSymbol(Operator),
     Character(u8),
     Anonymous(usize),
+    /// The others formal of an association list
+    Others,
 }
 
 pub type Reference = Option<EntityId>;
@@ -603,6 +613,13 @@ pub enum ObjectClass {
     SharedVariable,
 }
 
+/// LRM 6.4.2.3 Signal declarations
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SignalKind {
+    Register,
+    Bus,
+}
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum InterfaceType {
     Port,
@@ -615,6 +632,8 @@ pub struct ObjectDeclaration {
     pub class: ObjectClass,
     pub ident: WithDecl<Ident>,
     pub subtype_indication: SubtypeIndication,
+    /// Signals with a kind are guarded signals
+    pub signal_kind: Option<SignalKind>,
     pub expression: Option<WithPos<Expression>>,
 }
 
@@ -655,6 +674,8 @@ pub struct SubprogramBody {
     pub declarations: Vec<Declaration>,
     pub statements: Vec<LabeledSequentialStatement>,
     pub end_ident_pos: Option<SrcPos>,
+    // The full source range from the specification until the final semicolon
+    pub pos: SrcPos,
 }
 
 /// LRM 4.5.3 Signatures
@@ -683,6 +704,8 @@ pub struct InterfaceObjectDeclaration {
     pub class: ObjectClass,
     pub ident: WithDecl<Ident>,
     pub mode: Mode,
+    /// The mode view of a VHDL-2019 port, the mode of each element is given by the view
+    pub mode_view: Option<WithPos<SelectedName>>,
     pub subtype_indication: SubtypeIndication,
     pub expression: Option<WithPos<Expression>>,
 }
@@ -754,7 +777,28 @@ pub enum Declaration {
     SubprogramBody(SubprogramBody),
     Use(WithPos<UseClause>),
     Package(PackageInstantiation),
+    /// Package declared within a declarative part (VHDL-2008)
+    PackageDeclaration(PackageDeclaration),
+    PackageBody(PackageBody),
     Configuration(ConfigurationSpecification),
+    Disconnection(DisconnectionSpecification),
+    View(ModeViewDeclaration),
+}
+
+/// LRM 6.5.2 Mode view declarations (VHDL-2019)
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewDeclaration {
+    pub ident: WithDecl<Ident>,
+    pub typ: SubtypeIndication,
+    pub elements: Vec<ModeViewElementDefinition>,
+    pub end_ident_pos: Option<SrcPos>,
+}
+
+/// LRM 6.5.2 Mode view element definition
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewElementDefinition {
+    pub names: Vec<WithRef<Ident>>,
+    pub mode: WithPos<Mode>,
 }
 
 /// LRM 10.2 Wait statement
@@ -816,7 +860,7 @@ pub struct SignalAssignment {
     pub rhs: AssignmentRightHand<Waveform>,
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum ForceMode {
     In,
     Out,
@@ -1111,6 +1155,8 @@ pub struct ContextDeclaration {
     pub ident: WithDecl<Ident>,
     pub items: ContextClause,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 4.9 Package instatiation declaration
@@ -1120,6 +1166,8 @@ pub struct PackageInstantiation {
     pub ident: WithDecl<Ident>,
     pub package_name: WithPos<SelectedName>,
     pub generic_map: Option<Vec<AssociationElement>>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 7.3 Configuration specification
@@ -1167,6 +1215,22 @@ pub struct ConfigurationSpecification {
     pub vunit_bind_inds: Vec<VUnitBindingIndication>,
 }
 
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub enum GuardedSignalList {
+    Names(Vec<WithPos<Name>>),
+    Others,
+    All,
+}
+
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub struct DisconnectionSpecification {
+    pub signals: GuardedSignalList,
+    pub type_mark: WithPos<TypeMark>,
+    pub time: WithPos<Expression>,
+}
+
 /// LRM 3.4 Configuration declarations
 #[derive(PartialEq, Debug, Clone)]
 pub enum ConfigurationDeclarativeItem {
@@ -1208,6 +1272,8 @@ pub struct ConfigurationDeclaration {
     pub vunit_bind_inds: Vec<VUnitBindingIndication>,
     pub block_config: BlockConfiguration,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 3.2 Entity declarations
@@ -1220,6 +1286,8 @@ pub struct EntityDeclaration {
     pub decl: Vec<Declaration>,
     pub statements: Vec<LabeledConcurrentStatement>,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 3.3 Architecture bodies
@@ -1231,6 +1299,8 @@ pub struct ArchitectureBody {
     pub decl: Vec<Declaration>,
     pub statements: Vec<LabeledConcurrentStatement>,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 4.7 Package declarations
@@ -1241,6 +1311,8 @@ pub struct PackageDeclaration {
     pub generic_clause: Option<Vec<InterfaceDeclaration>>,
     pub decl: Vec<Declaration>,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 4.8 Package bodies
@@ -1250,6 +1322,8 @@ pub struct PackageBody {
     pub ident: WithDecl<Ident>,
     pub decl: Vec<Declaration>,
     pub end_ident_pos: Option<SrcPos>,
+    // The final semicolon of the design unit
+    pub end_pos: SrcPos,
 }
 
 /// LRM 13.1 Design units
@@ -1290,7 +1364,18 @@ pub enum AnyDesignUnit {
     Secondary(AnySecondaryUnit),
 }
 
+/// A tool directive within a comment such as `-- synthesis translate_off`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Pragma {
+    /// The leading word of the comment in lower case such as pragma or synthesis
+    pub tool: String,
+    /// The remaining text of the comment such as translate_off
+    pub directive: String,
+    pub pos: SrcPos,
+}
+
 #[derive(PartialEq, Debug, Clone, Default)]
 pub struct DesignFile {
     pub design_units: Vec<AnyDesignUnit>,
+    pub pragmas: Vec<Pragma>,
 }
@@ -428,6 +428,32 @@ impl Display for Expression {
             Expression::Name(ref name) => write!(f, "{name}"),
             Expression::Literal(ref literal) => write!(f, "{literal}"),
             Expression::New(ref alloc) => write!(f, "new {alloc}"),
+            Expression::Conditional(ref conds) => {
+                write!(f, "(")?;
+                for cond in conds.conditionals.iter() {
+                    write!(f, "{} when {} else ", cond.item, cond.condition)?;
+                }
+                if let Some(ref expr) = conds.else_item {
+                    write!(f, "{expr}")?;
+                }
+                write!(f, ")")
+            }
+            Expression::Selected(ref selection) => {
+                write!(f, "with {} select (", selection.expression)?;
+                for (i, alternative) in selection.alternatives.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} when ", alternative.item)?;
+                    for (j, choice) in alternative.choices.iter().enumerate() {
+                        if j > 0 {
+                            write!(f, " | ")?;
+                        }
+                        write!(f, "{choice}")?;
+                    }
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -611,6 +637,7 @@ impl Display for Designator {
             Designator::OperatorSymbol(ref op) => write!(f, "\"{op}\""),
             Designator::Character(byte) => write!(f, "'{}'", iso_8859_1_to_utf8(&[*byte])),
             Designator::Anonymous(_) => Ok(()),
+            Designator::Others => write!(f, "others"),
         }
     }
 }
@@ -871,6 +898,19 @@ impl Display for InterfaceFileDeclaration {
 
 impl Display for InterfaceObjectDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if let Some(ref mode_view) = self.mode_view {
+            let class = if self.list_type == InterfaceType::Parameter {
+                "signal "
+            } else {
+                ""
+            };
+            return write!(
+                f,
+                "{class}{} : view {mode_view} of {}",
+                self.ident, self.subtype_indication
+            );
+        }
+
         match self.list_type {
             InterfaceType::Port => {
                 write!(
@@ -1007,6 +1047,22 @@ impl Display for ForGenerateStatement {
     }
 }
 
+impl Display for ModeViewDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "view {} of {} is", self.ident, self.typ)?;
+        for element in self.elements.iter() {
+            let names = element.names.iter().map(|name| name.to_string());
+            write!(
+                f,
+                "\n  {} : {};",
+                names.collect::<Vec<_>>().join(", "),
+                element.mode
+            )?;
+        }
+        write!(f, "\nend view;")
+    }
+}
+
 impl Display for ContextDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         // Not used: items
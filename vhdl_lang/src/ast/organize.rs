@@ -0,0 +1,325 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Organizing of context clauses
+//!
+//! Repeated library, use and context clauses are removed and the remaining
+//! clauses are optionally sorted. The result is a list of text edits that
+//! only touch the clauses and the comments trailing them on the same line,
+//! comments on lines of their own are kept in place.
+
+use super::printer::print;
+use super::*;
+use crate::data::{Position, Range, SrcPos};
+
+/// Replace the text at `pos` with `new_text`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TextEdit {
+    pub pos: SrcPos,
+    pub new_text: String,
+}
+
+/// Returns the edits which remove duplicated clauses of the context clause
+/// and, when `sort` is set, order the remaining clauses as library clauses,
+/// context references and use clauses, each alphabetically
+///
+/// A clause is never moved before a clause that declares a name it refers to
+/// such as `use pkg.all` after `use work.pkg`
+pub fn organize_context_clause(items: &[WithPos<ContextItem>], sort: bool) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+    let mut kept: Vec<(&WithPos<ContextItem>, String)> = Vec::with_capacity(items.len());
+
+    for item in items.iter() {
+        let key = print(&item.item).to_lowercase();
+        if kept.iter().any(|(_, other)| *other == key) {
+            edits.push(TextEdit {
+                pos: removal_pos(&item.pos),
+                new_text: String::new(),
+            });
+        } else {
+            kept.push((item, key));
+        }
+    }
+
+    if sort {
+        let sorted = sort_clauses(kept.clone());
+
+        for ((slot, _), (item, _)) in kept.iter().zip(sorted.iter()) {
+            if slot.pos != item.pos {
+                let item_pos = with_trailing_comment(&item.pos);
+                edits.push(TextEdit {
+                    pos: with_trailing_comment(&slot.pos),
+                    new_text: item_pos.source.contents().text(item_pos.range()),
+                });
+            }
+        }
+    }
+
+    edits.sort_by(|l, r| l.pos.cmp(&r.pos));
+    edits
+}
+
+/// Repeatedly picks the first clause in sort order among the clauses
+/// that do not refer to a name declared by a preceding remaining clause
+fn sort_clauses(
+    mut remaining: Vec<(&WithPos<ContextItem>, String)>,
+) -> Vec<(&WithPos<ContextItem>, String)> {
+    let mut sorted = Vec::with_capacity(remaining.len());
+
+    while !remaining.is_empty() {
+        let idx = (0..remaining.len())
+            .filter(|&idx| {
+                let referenced = referenced_names(&remaining[idx].0.item);
+                !remaining[..idx].iter().any(|(other, _)| {
+                    declared_names(&other.item)
+                        .iter()
+                        .any(|name| referenced.contains(name))
+                })
+            })
+            .min_by(|&lidx, &ridx| {
+                let (litem, lkey) = &remaining[lidx];
+                let (ritem, rkey) = &remaining[ridx];
+                (kind_order(&litem.item), lkey).cmp(&(kind_order(&ritem.item), rkey))
+            })
+            // The first remaining clause has no preceding clause
+            .unwrap();
+        sorted.push(remaining.remove(idx));
+    }
+    sorted
+}
+
+/// The names made visible by a clause, such as `pkg` for `use work.pkg`
+fn declared_names(item: &ContextItem) -> Vec<&Symbol> {
+    match item {
+        ContextItem::Library(clause) => clause
+            .name_list
+            .iter()
+            .map(|name| &name.item.item)
+            .collect(),
+        ContextItem::Use(clause) => clause
+            .name_list
+            .iter()
+            .filter_map(|name| match &name.item {
+                Name::Selected(_, suffix) => match &suffix.item.item {
+                    Designator::Identifier(sym) => Some(sym),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        ContextItem::Context(..) => Vec::new(),
+    }
+}
+
+/// The names that a clause refers to, such as `pkg` for `use pkg.all`
+fn referenced_names(item: &ContextItem) -> Vec<&Symbol> {
+    match item {
+        ContextItem::Library(..) => Vec::new(),
+        ContextItem::Use(UseClause { name_list })
+        | ContextItem::Context(ContextReference { name_list }) => name_list
+            .iter()
+            .filter_map(|name| prefix_name(&name.item))
+            .collect(),
+    }
+}
+
+fn prefix_name(name: &Name) -> Option<&Symbol> {
+    match name {
+        Name::Selected(prefix, _) | Name::SelectedAll(prefix) => prefix_name(&prefix.item),
+        Name::Designator(designator) => match &designator.item {
+            Designator::Identifier(sym) => Some(sym),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn kind_order(item: &ContextItem) -> usize {
+    match item {
+        ContextItem::Library(..) => 0,
+        ContextItem::Context(..) => 1,
+        ContextItem::Use(..) => 2,
+    }
+}
+
+/// A comment following a clause on the same line belongs to the clause
+fn with_trailing_comment(pos: &SrcPos) -> SrcPos {
+    let end = pos.end();
+    let after = pos
+        .source
+        .contents()
+        .text(Range::new(end, Position::new(end.line + 1, 0)));
+    let after = after.trim_end_matches(['\r', '\n']);
+
+    if after.trim_start().starts_with("--") {
+        let end = after.chars().fold(end, Position::after_char);
+        SrcPos::new(pos.source.clone(), Range::new(pos.start(), end))
+    } else {
+        pos.clone()
+    }
+}
+
+/// A clause which is alone on its line is removed together with the line
+fn removal_pos(pos: &SrcPos) -> SrcPos {
+    let pos = with_trailing_comment(pos);
+    let start = pos.start();
+    let end = pos.end();
+    let contents = pos.source.contents();
+
+    let line_start = Position::new(start.line, 0);
+    let line_end = Position::new(end.line + 1, 0);
+    let before = contents.text(Range::new(line_start, start));
+    let after = contents.text(Range::new(end, line_end));
+
+    if before.trim().is_empty() && after.trim().is_empty() && !after.is_empty() {
+        SrcPos::new(pos.source.clone(), Range::new(line_start, line_end))
+    } else {
+        pos.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::test::Code;
+    use assert_matches::assert_matches;
+
+    fn context_clause(code: &Code) -> ContextClause {
+        assert_matches!(
+            code.design_file().design_units.remove(0),
+            AnyDesignUnit::Primary(AnyPrimaryUnit::Entity(entity)) => entity.context_clause
+        )
+    }
+
+    fn apply(code: &Code, edits: Vec<TextEdit>) -> String {
+        let source = code.source();
+        for edit in edits.into_iter().rev() {
+            source.change(Some(&edit.pos.range()), &edit.new_text);
+        }
+        let contents = source.contents();
+        contents.text(Range::new(contents.start(), contents.end()))
+    }
+
+    #[test]
+    fn duplicate_library_clauses_collapse_to_one() {
+        let code = Code::new(
+            "\
+library foo;
+library foo;
+use foo.pkg.all;
+entity ent is
+end entity;
+",
+        );
+        let edits = organize_context_clause(&context_clause(&code), false);
+        assert_eq!(
+            apply(&code, edits),
+            "\
+library foo;
+use foo.pkg.all;
+entity ent is
+end entity;
+"
+        );
+    }
+
+    #[test]
+    fn duplicates_are_case_insensitive() {
+        let code = Code::new(
+            "\
+library foo;
+use foo.pkg.all;
+use FOO.Pkg.all; -- comment
+entity ent is
+end entity;
+",
+        );
+        let edits = organize_context_clause(&context_clause(&code), false);
+        assert_eq!(
+            apply(&code, edits),
+            "\
+library foo;
+use foo.pkg.all;
+entity ent is
+end entity;
+"
+        );
+    }
+
+    #[test]
+    fn sorts_clauses_together_with_trailing_comments() {
+        let code = Code::new(
+            "\
+-- leading comment
+use foo.zpkg.all;
+library foo;
+use foo.apkg.all; -- trailing comment
+context foo.ctx;
+library bar;
+entity ent is
+end entity;
+",
+        );
+        let edits = organize_context_clause(&context_clause(&code), true);
+        assert_eq!(
+            apply(&code, edits),
+            "\
+-- leading comment
+library bar;
+library foo;
+context foo.ctx;
+use foo.apkg.all; -- trailing comment
+use foo.zpkg.all;
+entity ent is
+end entity;
+"
+        );
+    }
+
+    #[test]
+    fn sorting_keeps_clauses_after_the_clauses_they_refer_to() {
+        let code = Code::new(
+            "\
+use work.zpkg;
+use zpkg.nested.all;
+use work.apkg.all;
+use zpkg.b;
+use b.all;
+entity ent is
+end entity;
+",
+        );
+        let edits = organize_context_clause(&context_clause(&code), true);
+        assert_eq!(
+            apply(&code, edits),
+            "\
+use work.apkg.all;
+use work.zpkg;
+use zpkg.b;
+use b.all;
+use zpkg.nested.all;
+entity ent is
+end entity;
+"
+        );
+    }
+
+    #[test]
+    fn organized_clause_has_no_edits() {
+        let code = Code::new(
+            "\
+library foo;
+use foo.pkg.all;
+entity ent is
+end entity;
+",
+        );
+        assert_eq!(
+            organize_context_clause(&context_clause(&code), true),
+            Vec::new()
+        );
+    }
+}
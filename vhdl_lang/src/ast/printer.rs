@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Printing of AST nodes as canonical VHDL source code
+//!
+//! Unlike Display which gives a short summary of a node this prints
+//! the complete node such that it can be parsed again
+
+use super::*;
+
+/// Print an AST node as canonical VHDL source code
+pub fn print<T: Print + ?Sized>(node: &T) -> String {
+    let mut printer = Printer::default();
+    node.print(&mut printer);
+    printer.output
+}
+
+#[derive(Default)]
+pub struct Printer {
+    output: String,
+    indent: usize,
+}
+
+impl Printer {
+    fn write(&mut self, text: impl AsRef<str>) {
+        self.output.push_str(text.as_ref());
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        for _ in 0..self.indent {
+            self.output.push_str("  ");
+        }
+    }
+
+    fn write_list<T: std::fmt::Display>(&mut self, items: &[T]) {
+        for (idx, item) in items.iter().enumerate() {
+            if idx > 0 {
+                self.write(", ");
+            }
+            self.write(item.to_string());
+        }
+    }
+}
+
+pub trait Print {
+    fn print(&self, printer: &mut Printer);
+}
+
+impl<T: Print> Print for WithPos<T> {
+    fn print(&self, printer: &mut Printer) {
+        self.item.print(printer);
+    }
+}
+
+impl Print for Name {
+    fn print(&self, printer: &mut Printer) {
+        printer.write(self.to_string());
+    }
+}
+
+impl Print for LibraryClause {
+    fn print(&self, printer: &mut Printer) {
+        printer.write("library ");
+        printer.write_list(&self.name_list);
+        printer.write(";");
+    }
+}
+
+impl Print for UseClause {
+    fn print(&self, printer: &mut Printer) {
+        printer.write("use ");
+        printer.write_list(&self.name_list);
+        printer.write(";");
+    }
+}
+
+impl Print for ContextReference {
+    fn print(&self, printer: &mut Printer) {
+        printer.write("context ");
+        printer.write_list(&self.name_list);
+        printer.write(";");
+    }
+}
+
+impl Print for ContextItem {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            ContextItem::Use(clause) => clause.print(printer),
+            ContextItem::Library(clause) => clause.print(printer),
+            ContextItem::Context(clause) => clause.print(printer),
+        }
+    }
+}
+
+impl Print for ContextClause {
+    fn print(&self, printer: &mut Printer) {
+        for (idx, item) in self.iter().enumerate() {
+            if idx > 0 {
+                printer.newline();
+            }
+            item.print(printer);
+        }
+    }
+}
+
+impl Print for ContextDeclaration {
+    fn print(&self, printer: &mut Printer) {
+        printer.write(format!("context {} is", self.ident));
+        printer.indent += 1;
+        for item in self.items.iter() {
+            printer.newline();
+            item.print(printer);
+        }
+        printer.indent -= 1;
+        printer.newline();
+        printer.write("end context;");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::test::Code;
+    use assert_matches::assert_matches;
+
+    fn context_declaration(code: &Code) -> ContextDeclaration {
+        assert_matches!(
+            code.design_file().design_units.remove(0),
+            AnyDesignUnit::Primary(AnyPrimaryUnit::Context(context)) => context
+        )
+    }
+
+    #[test]
+    fn print_use_clause() {
+        for source in ["use lib.foo;", "use foo.'a', lib.bar.all;"] {
+            let code = Code::new(source);
+            assert_eq!(print(&code.use_clause()), source);
+        }
+    }
+
+    #[test]
+    fn print_library_clause() {
+        let code = Code::new("library foo, bar;");
+        assert_eq!(print(&code.library_clause()), "library foo, bar;");
+    }
+
+    #[test]
+    fn print_context_declaration() {
+        let source = "\
+context ident is
+  library foo;
+  use foo.bar;
+  context foo.ctx, lib.bar.all;
+end context;";
+        let context = context_declaration(&Code::new(source));
+        assert_eq!(print(&context), source);
+
+        // Printing the parsed output gives an equivalent declaration
+        let reparsed = context_declaration(&Code::new(&print(&context)));
+        assert_eq!(print(&reparsed), source);
+        assert_eq!(reparsed.items.len(), context.items.len());
+    }
+
+    #[test]
+    fn print_empty_context_declaration() {
+        for source in [
+            "context ident is\nend;",
+            "context ident is\nend context ident;",
+        ] {
+            let context = context_declaration(&Code::new(source));
+            assert_eq!(print(&context), "context ident is\nend context;");
+        }
+    }
+}
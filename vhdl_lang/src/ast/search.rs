@@ -52,6 +52,7 @@ pub enum FoundDeclaration<'a> {
     PhysicalTypePrimary(&'a mut WithDecl<Ident>),
     PhysicalTypeSecondary(&'a mut WithDecl<Ident>, &'a mut PhysicalLiteral),
     Component(&'a mut ComponentDeclaration),
+    View(&'a mut ModeViewDeclaration),
     Attribute(&'a mut AttributeDeclaration),
     Alias(&'a mut AliasDeclaration),
     Function(&'a mut FunctionSpecification),
@@ -98,6 +99,28 @@ pub trait Searcher {
     fn search_with_pos(&mut self, _pos: &SrcPos) -> SearchState {
         NotFinished
     }
+
+    /// Search a name before its prefix and suffix are searched
+    fn search_name(&mut self, _pos: &SrcPos, _name: &Name) -> SearchState {
+        NotFinished
+    }
+
+    /// Search an expression before its operands are searched
+    fn search_expr(&mut self, _pos: &SrcPos, _expr: &Expression) -> SearchState {
+        NotFinished
+    }
+
+    /// Search the full source range of a declaration and the position of its end identifier
+    /// Only statements that may have a label and subprogram bodies keep their full range
+    fn search_decl_range(
+        &mut self,
+        _pos: &SrcPos,
+        _end_ident_pos: Option<&SrcPos>,
+        _decl: Reference,
+    ) -> SearchState {
+        NotFinished
+    }
+
     fn search_source(&mut self, _source: &Source) -> SearchState {
         NotFinished
     }
@@ -261,6 +284,11 @@ impl Search for WithPos<Target> {
 
 impl Search for LabeledSequentialStatement {
     fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_finished!(searcher.search_decl_range(
+            &self.statement.pos,
+            self.statement.item.end_label_pos(),
+            self.label.decl
+        ));
         if let Some(ref ident) = self.label.tree {
             return_if_found!(searcher
                 .search_decl(FoundDeclaration::SequentialStatement(
@@ -460,6 +488,11 @@ impl Search for SensitivityList {
 
 impl Search for LabeledConcurrentStatement {
     fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_finished!(searcher.search_decl_range(
+            &self.statement.pos,
+            self.statement.item.end_label_pos(),
+            self.label.decl
+        ));
         if let Some(ref ident) = self.label.tree {
             return_if_found!(searcher
                 .search_decl(FoundDeclaration::ConcurrentStatement(
@@ -579,6 +612,7 @@ fn search_pos_name(
     name: &mut Name,
     searcher: &mut impl Searcher,
 ) -> SearchResult {
+    return_if_finished!(searcher.search_name(pos, name));
     match name {
         Name::Selected(ref mut prefix, ref mut designator) => {
             return_if_found!(prefix.search(searcher));
@@ -608,7 +642,14 @@ fn search_pos_name(
             NotFound
         }
         Name::External(ref mut ename) => {
-            let ExternalName { subtype, .. } = ename.as_mut();
+            let ExternalName { subtype, path, .. } = ename.as_mut();
+            match path.item {
+                ExternalPath::Package(ref mut name)
+                | ExternalPath::Absolute(ref mut name)
+                | ExternalPath::Relative(ref mut name, _) => {
+                    return_if_found!(name.search(searcher));
+                }
+            }
             return_if_found!(subtype.search(searcher));
             NotFound
         }
@@ -822,6 +863,7 @@ fn search_pos_expr(
     searcher: &mut impl Searcher,
 ) -> SearchResult {
     return_if_finished!(searcher.search_with_pos(pos));
+    return_if_finished!(searcher.search_expr(pos, expr));
     match expr {
         Expression::Binary(ref mut op, ref mut left, ref mut right) => {
             return_if_found!(searcher
@@ -852,6 +894,8 @@ fn search_pos_expr(
             }
             _ => NotFound,
         },
+        Expression::Conditional(ref mut conds) => search_conditionals(conds, true, searcher),
+        Expression::Selected(ref mut selection) => search_selection(selection, true, searcher),
     }
 }
 
@@ -965,6 +1009,11 @@ impl Search for Declaration {
                 return_if_found!(typ.search(searcher));
             }
             Declaration::SubprogramBody(body) => {
+                return_if_finished!(searcher.search_decl_range(
+                    &body.pos,
+                    body.end_ident_pos.as_ref(),
+                    *body.specification.reference_mut()
+                ));
                 return_if_found!(body.specification.search(searcher));
                 return_if_found!(body.declarations.search(searcher));
                 return_if_found!(body.statements.search(searcher));
@@ -1058,9 +1107,48 @@ impl Search for Declaration {
                 return_if_found!(package_instance.search(searcher));
             }
 
+            Declaration::PackageDeclaration(ref mut package) => {
+                return_if_found!(package.search(searcher));
+            }
+
+            Declaration::PackageBody(ref mut body) => {
+                return_if_found!(body.search(searcher));
+            }
+
             Declaration::Configuration(_) => {
                 // @TODO
             }
+
+            Declaration::Disconnection(disconnection) => {
+                let DisconnectionSpecification {
+                    signals,
+                    type_mark,
+                    time,
+                } = disconnection;
+                if let GuardedSignalList::Names(names) = signals {
+                    return_if_found!(names.search(searcher));
+                }
+                return_if_found!(type_mark.search(searcher));
+                return_if_found!(time.search(searcher));
+            }
+
+            Declaration::View(view) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::View(view))
+                    .or_not_found());
+                let ModeViewDeclaration {
+                    ident: _,
+                    typ,
+                    elements,
+                    end_ident_pos: _,
+                } = view;
+                return_if_found!(typ.search(searcher));
+                for element in elements.iter_mut() {
+                    for name in element.names.iter_mut() {
+                        return_if_found!(searcher.search_ident_ref(name).or_not_found());
+                    }
+                }
+            }
         }
         NotFound
     }
@@ -1073,6 +1161,7 @@ impl Search for InterfaceDeclaration {
                 return_if_found!(searcher
                     .search_decl(FoundDeclaration::InterfaceObject(decl))
                     .or_not_found());
+                return_if_found!(decl.mode_view.search(searcher));
                 return_if_found!(decl.subtype_indication.search(searcher));
                 return_if_found!(decl.expression.search(searcher));
             }
@@ -1278,7 +1367,38 @@ impl Search for ConfigurationDeclaration {
         return_if_found!(searcher
             .search_decl(FoundDeclaration::Configuration(self))
             .or_not_found());
-        self.entity_name.search(searcher)
+        return_if_found!(self.entity_name.search(searcher));
+        self.block_config.search(searcher)
+    }
+}
+
+impl Search for BlockConfiguration {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_found!(self.block_spec.search(searcher));
+        self.items.search(searcher)
+    }
+}
+
+impl Search for ConfigurationItem {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        match self {
+            ConfigurationItem::Block(ref mut block) => block.search(searcher),
+            ConfigurationItem::Component(ref mut config) => {
+                return_if_found!(config.spec.component_name.search(searcher));
+                return_if_found!(config.bind_ind.search(searcher));
+                config.block_config.search(searcher)
+            }
+        }
+    }
+}
+
+impl Search for BindingIndication {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        match self.entity_aspect {
+            Some(EntityAspect::Entity(ref mut entity_name, _)) => entity_name.search(searcher),
+            Some(EntityAspect::Configuration(ref mut config_name)) => config_name.search(searcher),
+            Some(EntityAspect::Open) | None => NotFound,
+        }
     }
 }
 
@@ -1389,6 +1509,234 @@ impl Searcher for ItemAtCursor {
     }
 }
 
+/// A copy of an AST node found by its source position
+#[derive(PartialEq, Debug, Clone)]
+pub enum AstNode {
+    Name(WithPos<Name>),
+    Expression(WithPos<Expression>),
+}
+
+impl AstNode {
+    pub fn pos(&self) -> &SrcPos {
+        match self {
+            AstNode::Name(name) => &name.pos,
+            AstNode::Expression(expr) => &expr.pos,
+        }
+    }
+}
+
+// Search for the smallest name or expression whose range contains a position
+pub struct FindNodeAt {
+    pos: SrcPos,
+    pub result: Option<AstNode>,
+}
+
+impl FindNodeAt {
+    pub fn new(pos: &SrcPos) -> FindNodeAt {
+        FindNodeAt {
+            pos: pos.clone(),
+            result: None,
+        }
+    }
+
+    fn is_inside(&self, pos: &SrcPos) -> bool {
+        pos.source == self.pos.source
+            && pos.start() <= self.pos.start()
+            && self.pos.end() <= pos.end()
+    }
+}
+
+impl Searcher for FindNodeAt {
+    fn search_with_pos(&mut self, pos: &SrcPos) -> SearchState {
+        if self.is_inside(pos) {
+            NotFinished
+        } else {
+            Finished(NotFound)
+        }
+    }
+
+    // Nested nodes are searched after the enclosing node and thus replace it
+    fn search_name(&mut self, pos: &SrcPos, name: &Name) -> SearchState {
+        if self.is_inside(pos) {
+            self.result = Some(AstNode::Name(WithPos::new(name.clone(), pos.clone())));
+            NotFinished
+        } else {
+            Finished(NotFound)
+        }
+    }
+
+    fn search_expr(&mut self, pos: &SrcPos, expr: &Expression) -> SearchState {
+        if self.is_inside(pos) {
+            self.result = Some(AstNode::Expression(WithPos::new(expr.clone(), pos.clone())));
+            NotFinished
+        } else {
+            Finished(NotFound)
+        }
+    }
+
+    fn search_source(&mut self, source: &Source) -> SearchState {
+        if source == &self.pos.source {
+            NotFinished
+        } else {
+            Finished(NotFound)
+        }
+    }
+}
+
+// Search for the innermost declaration whose full range contains a position
+pub struct FindEnclosingDeclaration {
+    pos: SrcPos,
+    // The innermost statement or subprogram body containing the position
+    declaration: Option<EntityId>,
+    // The design unit starting last before the position, None when it ends before the position
+    design_unit: Option<(Position, Option<EntityId>)>,
+}
+
+impl FindEnclosingDeclaration {
+    pub fn new(pos: &SrcPos) -> FindEnclosingDeclaration {
+        FindEnclosingDeclaration {
+            pos: pos.clone(),
+            declaration: None,
+            design_unit: None,
+        }
+    }
+
+    pub fn result(&self) -> Option<EntityId> {
+        self.declaration
+            .or_else(|| self.design_unit.and_then(|(_, id)| id))
+    }
+}
+
+impl Searcher for FindEnclosingDeclaration {
+    fn search_decl(&mut self, decl: FoundDeclaration) -> SearchState {
+        let (context_clause, end_pos): (&[_], _) = match decl {
+            FoundDeclaration::Entity(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::Architecture(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::Package(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::PackageBody(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::PackageInstance(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::Configuration(ref unit) => (&unit.context_clause, &unit.end_pos),
+            FoundDeclaration::Context(ref unit) => (&[][..], &unit.end_pos),
+            _ => return NotFinished,
+        };
+
+        // A design unit is taken to start at the line of its context clause or identifier
+        // and to end with its final semicolon
+        let first_pos = context_clause
+            .first()
+            .map(|item| &item.pos)
+            .unwrap_or_else(|| decl.pos());
+        let start = Position::new(first_pos.start().line, 0);
+        if start > self.pos.start()
+            || self
+                .design_unit
+                .is_some_and(|(other_start, _)| other_start > start)
+        {
+            return NotFinished;
+        }
+
+        let ended = end_pos.end() < self.pos.end();
+        self.design_unit = Some((start, if ended { None } else { decl.ent_id() }));
+        NotFinished
+    }
+
+    fn search_decl_range(
+        &mut self,
+        pos: &SrcPos,
+        _end_ident_pos: Option<&SrcPos>,
+        decl: Reference,
+    ) -> SearchState {
+        if pos.start() <= self.pos.start() && self.pos.end() <= pos.end() {
+            if let Some(id) = decl {
+                // Nested declarations are searched after their parent
+                self.declaration = Some(id);
+            }
+        }
+        NotFinished
+    }
+
+    fn search_source(&mut self, source: &Source) -> SearchState {
+        if source == &self.pos.source {
+            NotFinished
+        } else {
+            Finished(NotFound)
+        }
+    }
+}
+
+// Search for calls from within subprogram bodies to other subprograms
+pub struct FindAllCalls<'a> {
+    root: &'a DesignRoot,
+    // The subprogram bodies enclosing the current position and the position
+    // of their end identifier, innermost last
+    bodies: Vec<(SrcPos, Option<SrcPos>, EntRef<'a>)>,
+    // Pairs of caller and callee declarations
+    pub calls: Vec<(EntRef<'a>, EntRef<'a>)>,
+}
+
+impl<'a> FindAllCalls<'a> {
+    pub fn new(root: &'a DesignRoot) -> FindAllCalls<'a> {
+        FindAllCalls {
+            root,
+            bodies: Vec::new(),
+            calls: Vec::new(),
+        }
+    }
+
+    fn leave_bodies(&mut self, pos: &SrcPos) {
+        while let Some((body_pos, _, _)) = self.bodies.last() {
+            if body_pos.source == pos.source
+                && body_pos.start() <= pos.start()
+                && pos.end() <= body_pos.end()
+            {
+                break;
+            }
+            self.bodies.pop();
+        }
+    }
+}
+
+impl<'a> Searcher for FindAllCalls<'a> {
+    fn search_decl_range(
+        &mut self,
+        pos: &SrcPos,
+        end_ident_pos: Option<&SrcPos>,
+        decl: Reference,
+    ) -> SearchState {
+        self.leave_bodies(pos);
+        if let Some(id) = decl {
+            let ent = self.root.get_ent(id);
+            if ent.is_subprogram() {
+                self.bodies
+                    .push((pos.clone(), end_ident_pos.cloned(), ent.declaration()));
+            }
+        }
+        NotFinished
+    }
+
+    fn search_pos_with_ref(&mut self, pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        self.leave_bodies(pos);
+        if let (Some((_, end_ident_pos, caller)), Some(id)) =
+            (self.bodies.last(), reference.as_ref())
+        {
+            // The end identifier of the body refers to the subprogram itself
+            if end_ident_pos.as_ref() == Some(pos) {
+                return NotFinished;
+            }
+            let callee = self.root.get_ent(*id);
+            if (callee.is_subprogram() || callee.is_subprogram_decl()) && !callee.is_implicit() {
+                self.calls.push((*caller, callee.declaration()));
+            }
+        }
+        NotFinished
+    }
+
+    fn search_source(&mut self, _source: &Source) -> SearchState {
+        self.bodies.clear();
+        NotFinished
+    }
+}
+
 // Search for reference to declaration/definition at cursor
 pub struct FindEnt<'a, T: Fn(EntRef<'a>) -> bool> {
     root: &'a DesignRoot,
@@ -1449,6 +1797,30 @@ impl<'a, T: FnMut(EntRef<'a>) -> bool> Searcher for FindAllEnt<'a, T> {
     }
 }
 
+// Collect all resolved references together with the referenced entity
+pub struct FindAllResolved<'a> {
+    root: &'a DesignRoot,
+    pub result: Vec<(SrcPos, EntRef<'a>)>,
+}
+
+impl<'a> FindAllResolved<'a> {
+    pub fn new(root: &'a DesignRoot) -> FindAllResolved<'a> {
+        FindAllResolved {
+            root,
+            result: Vec::default(),
+        }
+    }
+}
+
+impl<'a> Searcher for FindAllResolved<'a> {
+    fn search_pos_with_ref(&mut self, pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        if let Some(id) = reference {
+            self.result.push((pos.clone(), self.root.get_ent(*id)));
+        }
+        NotFinished
+    }
+}
+
 // Search for a declaration/definition and format it
 pub struct FormatDeclaration<'a> {
     ent: EntRef<'a>,
@@ -1596,6 +1968,7 @@ impl<'a> FoundDeclaration<'a> {
             FoundDeclaration::PhysicalTypePrimary(..) => None,
             FoundDeclaration::PhysicalTypeSecondary(..) => None,
             FoundDeclaration::Component(value) => value.end_ident_pos.as_ref(),
+            FoundDeclaration::View(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::Attribute(..) => None,
             FoundDeclaration::Alias(..) => None,
             FoundDeclaration::Package(value) => value.end_ident_pos.as_ref(),
@@ -1631,6 +2004,7 @@ impl<'a> HasEntityId for FoundDeclaration<'a> {
             FoundDeclaration::PhysicalTypePrimary(value) => value.decl,
             FoundDeclaration::PhysicalTypeSecondary(value, _) => value.decl,
             FoundDeclaration::Component(value) => value.ident.decl,
+            FoundDeclaration::View(value) => value.ident.decl,
             FoundDeclaration::Attribute(value) => value.ident.decl,
             FoundDeclaration::Alias(value) => value.designator.decl,
             FoundDeclaration::Package(value) => value.ident.decl,
@@ -1666,6 +2040,7 @@ impl<'a> HasSrcPos for FoundDeclaration<'a> {
             FoundDeclaration::PhysicalTypePrimary(value) => value.pos(),
             FoundDeclaration::PhysicalTypeSecondary(value, _) => value.as_ref(),
             FoundDeclaration::Component(value) => value.ident.pos(),
+            FoundDeclaration::View(value) => value.ident.pos(),
             FoundDeclaration::Alias(value) => &value.designator.tree.pos,
             FoundDeclaration::Attribute(value) => value.ident.pos(),
             FoundDeclaration::Package(value) => value.ident.pos(),
@@ -1736,6 +2111,9 @@ impl std::fmt::Display for FoundDeclaration<'_> {
             FoundDeclaration::Component(ref value) => {
                 write!(f, "{value}")
             }
+            FoundDeclaration::View(ref value) => {
+                write!(f, "{value}")
+            }
             FoundDeclaration::Alias(ref value) => {
                 write!(f, "{value}")
             }
@@ -27,6 +27,15 @@ impl From<WithPos<SelectedName>> for WithPos<Name> {
     }
 }
 
+impl SelectedName {
+    pub fn get_suffix_reference(&self) -> Option<EntityId> {
+        match self {
+            SelectedName::Designator(suffix) => suffix.reference,
+            SelectedName::Selected(_, suffix) => suffix.item.reference,
+        }
+    }
+}
+
 impl WithPos<SelectedName> {
     pub fn suffix_pos(&self) -> &SrcPos {
         match self.item {
@@ -332,6 +341,7 @@ impl Designator {
             Designator::Identifier(ident) => format!("'{ident}'"),
             Designator::OperatorSymbol(op) => format!("operator \"{op}\""),
             Designator::Anonymous(_) => "<anonymous>".to_owned(),
+            Designator::Others => "others".to_owned(),
         }
     }
 }
@@ -411,6 +421,22 @@ impl CallOrIndexed {
     }
 }
 
+impl AssociationElement {
+    /// True if the formal is others such as in others => open
+    pub fn is_others(&self) -> bool {
+        matches!(
+            self.formal,
+            Some(WithPos {
+                item: Name::Designator(WithRef {
+                    item: Designator::Others,
+                    ..
+                }),
+                ..
+            })
+        )
+    }
+}
+
 pub struct IndexedName<'a> {
     pub name: &'a mut WithPos<Name>,
     pub indexes: Vec<Index<'a>>,
@@ -487,7 +513,7 @@ impl SubprogramDeclaration {
 }
 
 impl ConcurrentStatement {
-    pub fn label_typ(&self) -> Option<Concurrent> {
+    pub fn label_typ<'a>(&self) -> Option<Concurrent<'a>> {
         use ConcurrentStatement::*;
         match self {
             ProcedureCall(_) => None,
@@ -495,7 +521,7 @@ impl ConcurrentStatement {
             Process(_) => Some(Concurrent::Process),
             Assert(_) => None,
             Assignment(_) => None,
-            Instance(_) => Some(Concurrent::Instance),
+            Instance(_) => Some(Concurrent::Instance(None)),
             ForGenerate(_) | IfGenerate(_) | CaseGenerate(_) => Some(Concurrent::Generate),
         }
     }
@@ -568,3 +594,46 @@ impl SequentialStatement {
         self.label_typ().is_some()
     }
 }
+
+impl Pragma {
+    pub fn is_translate_off(&self) -> bool {
+        ["translate_off", "synthesis_off"]
+            .iter()
+            .any(|directive| self.directive.eq_ignore_ascii_case(directive))
+    }
+
+    pub fn is_translate_on(&self) -> bool {
+        ["translate_on", "synthesis_on"]
+            .iter()
+            .any(|directive| self.directive.eq_ignore_ascii_case(directive))
+    }
+}
+
+impl DesignFile {
+    /// The source ranges from a translate_off pragma to the matching translate_on pragma
+    /// A region without a translate_on pragma extends to the end of the file
+    pub fn translate_off_regions(&self) -> Vec<SrcPos> {
+        let mut regions = Vec::new();
+        let mut off: Option<&Pragma> = None;
+
+        for pragma in self.pragmas.iter() {
+            if pragma.is_translate_off() {
+                off = off.or(Some(pragma));
+            } else if pragma.is_translate_on() {
+                if let Some(start) = off.take() {
+                    regions.push(start.pos.combine(&pragma.pos));
+                }
+            }
+        }
+
+        if let Some(start) = off {
+            let end = start.pos.source.contents().end();
+            regions.push(SrcPos::new(
+                start.pos.source.clone(),
+                crate::data::Range::new(start.pos.start(), end),
+            ));
+        }
+
+        regions
+    }
+}
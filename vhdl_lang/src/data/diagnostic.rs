@@ -5,6 +5,7 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use super::SrcPos;
+use crate::ast::UnitId;
 use std::convert::{AsRef, Into};
 
 #[derive(PartialEq, Debug, Clone, Copy, Eq, Hash)]
@@ -121,6 +122,11 @@ pub trait DiagnosticHandler {
             self.push(diagnostic);
         }
     }
+
+    /// Called as soon as the analysis of a design unit has finished, in the order the units finish
+    /// Allows consuming diagnostics unit by unit during a long analysis
+    /// The same diagnostics, together with lint diagnostics, are pushed once all units are analyzed
+    fn on_unit_analyzed(&mut self, _unit: UnitId, _diagnostics: &[Diagnostic]) {}
 }
 
 impl<'a> dyn DiagnosticHandler + 'a {
@@ -8,6 +8,22 @@
 // allow for now
 #![allow(clippy::vec_init_then_push)]
 
+/// Enter a tracing span for the phases of analysis, the span is exited when the
+/// returned guard is dropped and is only created with the tracing feature enabled
+#[cfg(feature = "tracing")]
+macro_rules! enter_span {
+    ($level:ident, $name:expr $(, $($fields:tt)*)?) => {
+        tracing::span!(tracing::Level::$level, $name $(, $($fields)*)?).entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! enter_span {
+    ($($args:tt)*) => {
+        ()
+    };
+}
+
 #[macro_use]
 pub mod ast;
 #[macro_use]
@@ -15,17 +31,20 @@ mod analysis;
 mod config;
 mod data;
 mod project;
+mod standard;
 mod syntax;
 
 pub use crate::config::Config;
 pub use crate::data::{
-    Diagnostic, Latin1String, Message, MessageHandler, MessagePrinter, MessageType,
-    NullDiagnostics, NullMessages, Position, Range, Severity, Source, SrcPos,
+    Diagnostic, DiagnosticHandler, Latin1String, Message, MessageHandler, MessagePrinter,
+    MessageType, NullDiagnostics, NullMessages, Position, Range, Severity, Source, SrcPos,
 };
 
 pub use crate::analysis::{
-    AnyEnt, AnyEntKind, Concurrent, Design, EntHierarchy, EntRef, EntityId, Object, Overloaded,
-    Type,
+    builtin_lint_rules, AnyEnt, AnyEntKind, CircularDependencyError, Concurrent, Design, DesignEnt,
+    EntHierarchy, EntRef, EntityId, InterfaceEnt, LintRule, LowercaseSignalNames,
+    NoConcurrentVariables, Object, Overloaded, ScopeSnapshot, Type,
 };
-pub use crate::project::{Project, SourceFile};
+pub use crate::project::{AnalyzedUnit, DiagnosticsDelta, Project, SourceFile};
+pub use crate::standard::VhdlStandard;
 pub use crate::syntax::{ParserResult, VHDLParser};
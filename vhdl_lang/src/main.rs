@@ -10,6 +10,7 @@
 #![allow(clippy::ptr_arg)]
 
 use clap::Parser;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::time::SystemTime;
 use vhdl_lang::{Config, Diagnostic, MessagePrinter, NullMessages, Project, Severity};
@@ -30,6 +31,14 @@ struct Args {
     #[arg(long, default_value_t = false)]
     bench: bool,
 
+    /// Only report syntax errors, semantic analysis is skipped
+    #[arg(long, default_value_t = false)]
+    syntax_only: bool,
+
+    /// Suppress further diagnostics after this many errors, must be at least one
+    #[arg(long)]
+    max_errors: Option<NonZeroUsize>,
+
     /// Hide hint diagnostics
     #[arg(long, default_value_t = false)]
     no_hint: bool,
@@ -79,6 +88,8 @@ fn main() {
     };
 
     let mut project = Project::from_config(&config, &mut msg_printer);
+    project.set_syntax_only(args.syntax_only);
+    project.set_max_errors(args.max_errors);
     let mut diagnostics = project.analyse();
     let duration = start.elapsed().unwrap() / iterations;
 
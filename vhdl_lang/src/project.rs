@@ -4,13 +4,17 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use crate::analysis::{AnyEnt, DesignRoot, EntRef};
-use crate::ast::DesignFile;
+use crate::analysis::{
+    AnyEnt, CircularDependencyError, DesignRoot, EntRef, EntityId, LintRule, ScopeSnapshot,
+};
+use crate::ast::search::AstNode;
+use crate::ast::{DesignFile, Pragma, UnitId};
 use crate::config::Config;
 use crate::syntax::VHDLParser;
-use crate::{data::*, EntHierarchy};
+use crate::{data::*, EntHierarchy, VhdlStandard};
 use fnv::{FnvHashMap, FnvHashSet};
 use std::collections::hash_map::Entry;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 pub struct Project {
@@ -18,6 +22,11 @@ pub struct Project {
     root: DesignRoot,
     files: FnvHashMap<PathBuf, SourceFile>,
     empty_libraries: FnvHashSet<Symbol>,
+    // Only report parser diagnostics
+    syntax_only: bool,
+    // The diagnostics of the latest analysis per design unit, diagnostics
+    // which do not belong to a unit such as syntax errors are kept under None
+    unit_diagnostics: Vec<(Option<UnitId>, Vec<Diagnostic>)>,
 }
 
 impl Project {
@@ -27,6 +36,8 @@ impl Project {
             root: DesignRoot::new(parser.symbols.clone()),
             files: FnvHashMap::default(),
             empty_libraries: FnvHashSet::default(),
+            syntax_only: false,
+            unit_diagnostics: Vec::new(),
             parser,
         }
     }
@@ -46,8 +57,30 @@ impl Project {
     /// The design state is reset, new files are added and parsed. Existing source files will be
     /// kept and parsed from in-memory source (required for incremental document updates).
     pub fn update_config(&mut self, config: &Config, messages: &mut dyn MessageHandler) {
+        let missing_end_identifier_warnings = self.parser.missing_end_identifier_warnings();
+        let standard = self.parser.standard();
         self.parser = VHDLParser::default();
+        self.parser
+            .set_missing_end_identifier_warnings(missing_end_identifier_warnings);
+        self.parser.set_standard(standard);
+        let lint_rules = self.root.take_lint_rules();
+        let unreachable_warnings = self.root.unreachable_warnings();
+        let combinational_loop_warnings = self.root.combinational_loop_warnings();
+        let read_before_write_warnings = self.root.read_before_write_warnings();
+        let max_errors = self.root.max_errors();
+        let record_scopes = self.root.record_scopes();
         self.root = DesignRoot::new(self.parser.symbols.clone());
+        self.root.set_standard(standard);
+        self.root.set_unreachable_warnings(unreachable_warnings);
+        self.root
+            .set_combinational_loop_warnings(combinational_loop_warnings);
+        self.root
+            .set_read_before_write_warnings(read_before_write_warnings);
+        self.root.set_max_errors(max_errors);
+        self.root.set_record_scopes(record_scopes);
+        for rule in lint_rules {
+            self.root.add_lint_rule(rule);
+        }
 
         // Reset library associations for known files,
         // all project files are added to the corresponding libraries later on.
@@ -162,10 +195,96 @@ impl Project {
         libs
     }
 
+    /// Enable a lint rule that runs after analysis
+    pub fn add_lint_rule(&mut self, rule: Box<dyn LintRule>) {
+        self.root.add_lint_rule(rule);
+    }
+
+    pub fn remove_lint_rule(&mut self, name: &str) {
+        self.root.remove_lint_rule(name);
+    }
+
+    /// The VHDL standard to analyze against
+    pub fn standard(&self) -> VhdlStandard {
+        self.root.standard()
+    }
+
+    /// Set the VHDL standard to parse and analyze against
+    pub fn set_standard(&mut self, standard: VhdlStandard) {
+        self.root.set_standard(standard);
+        if self.parser.standard() != standard {
+            self.parser.set_standard(standard);
+            // Files that are already parsed may use words that are reserved in the new standard
+            for source_file in self.files.values_mut() {
+                for library_name in source_file.library_names.iter() {
+                    self.root
+                        .remove_source(library_name.clone(), &source_file.source);
+                }
+                source_file.parser_diagnostics.clear();
+                source_file.design_file = self
+                    .parser
+                    .parse_design_source(&source_file.source, &mut source_file.parser_diagnostics);
+            }
+        }
+    }
+
+    /// Only report syntax errors and skip semantic analysis
+    pub fn set_syntax_only(&mut self, enable: bool) {
+        self.syntax_only = enable;
+    }
+
+    /// Warn on alternatives of signal assignments that can never be selected
+    pub fn set_unreachable_warnings(&mut self, enable: bool) {
+        self.root.set_unreachable_warnings(enable);
+    }
+
+    /// Warn on combinational loops among concurrent signal assignments
+    pub fn set_combinational_loop_warnings(&mut self, enable: bool) {
+        self.root.set_combinational_loop_warnings(enable);
+    }
+
+    /// Warn on process variables that are read before they are assigned
+    pub fn set_read_before_write_warnings(&mut self, enable: bool) {
+        self.root.set_read_before_write_warnings(enable);
+    }
+
+    /// Warn when the end of a declaration omits the identifier such as 'end entity;'
+    /// Only files parsed after the call are affected
+    pub fn set_missing_end_identifier_warnings(&mut self, enable: bool) {
+        self.parser.set_missing_end_identifier_warnings(enable);
+    }
+
+    /// Stop reporting diagnostics once this many errors have been reported
+    /// The analysis itself always covers all design units
+    pub fn set_max_errors(&mut self, max_errors: Option<NonZeroUsize>) {
+        self.root.set_max_errors(max_errors);
+    }
+
+    /// Record the names visible within processes, blocks and subprogram bodies
+    pub fn set_record_scopes(&mut self, enable: bool) {
+        self.root.set_record_scopes(enable);
+    }
+
     pub fn get_source(&self, file_name: &Path) -> Option<Source> {
         self.files.get(file_name).map(|file| file.source.clone())
     }
 
+    /// The tool directives within the comments of a file
+    pub fn pragmas(&self, file_name: &Path) -> &[Pragma] {
+        self.files
+            .get(file_name)
+            .map(|file| file.design_file.pragmas.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The regions of a file between translate_off and translate_on pragmas
+    pub fn translate_off_regions(&self, file_name: &Path) -> Vec<SrcPos> {
+        self.files
+            .get(file_name)
+            .map(|file| file.design_file.translate_off_regions())
+            .unwrap_or_default()
+    }
+
     pub fn update_source(&mut self, source: &Source) {
         let mut source_file = {
             if let Some(mut source_file) = self.files.remove(source.file_name()) {
@@ -201,6 +320,16 @@ impl Project {
     }
 
     pub fn analyse(&mut self) -> Vec<Diagnostic> {
+        if self.syntax_only {
+            let diagnostics: Vec<_> = self
+                .files
+                .values()
+                .flat_map(|source_file| source_file.parser_diagnostics.iter().cloned())
+                .collect();
+            self.unit_diagnostics = vec![(None, diagnostics.clone())];
+            return diagnostics;
+        }
+
         let mut diagnostics = Vec::new();
 
         for source_file in self.files.values_mut() {
@@ -222,10 +351,79 @@ impl Project {
             self.root.ensure_library(library_name.clone());
         }
 
-        self.root.analyze(&mut diagnostics);
+        let units = self.root.analyze_by_unit(&mut diagnostics);
+
+        let mut unit_diagnostics = Vec::with_capacity(units.len() + 1);
+        unit_diagnostics.push((None, diagnostics.clone()));
+        for (unit_id, unit_diags) in units {
+            diagnostics.extend(unit_diags.iter().cloned());
+            unit_diagnostics.push((Some(unit_id), unit_diags));
+        }
+        self.unit_diagnostics = unit_diagnostics;
         diagnostics
     }
 
+    /// Analyze the project and return the diagnostics which were added or removed
+    /// since the previous analysis, a diagnostic is identified by its position,
+    /// severity and message
+    pub fn analyse_delta(&mut self) -> DiagnosticsDelta {
+        let previous: FnvHashMap<_, _> = std::mem::take(&mut self.unit_diagnostics)
+            .into_iter()
+            .collect();
+        self.analyse();
+
+        let mut delta = DiagnosticsDelta::default();
+        for (unit_id, diagnostics) in self.unit_diagnostics.iter() {
+            let old = previous
+                .get(unit_id)
+                .map(|diagnostics| diagnostics.as_slice())
+                .unwrap_or_default();
+            // Units that were not re-analyzed keep their diagnostics
+            if old != diagnostics.as_slice() {
+                delta.add_changes(old, diagnostics);
+            }
+        }
+
+        let current: FnvHashSet<_> = self
+            .unit_diagnostics
+            .iter()
+            .map(|(unit_id, _)| unit_id)
+            .collect();
+        for (unit_id, diagnostics) in previous.iter() {
+            if !current.contains(unit_id) {
+                delta.removed.extend(diagnostics.iter().cloned());
+            }
+        }
+        delta
+    }
+
+    /// Update and analyze a source file and collect the results that belong to it
+    pub fn analyse_source(&mut self, source: &Source) -> AnalyzedUnit<'_> {
+        self.update_source(source);
+        let diagnostics = self
+            .analyse()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.pos.source() == source)
+            .collect();
+
+        let mut unit = AnalyzedUnit {
+            diagnostics,
+            roots: Vec::new(),
+            references: Vec::new(),
+            outline: Vec::new(),
+        };
+
+        for library_name in self.library_mapping_of(source) {
+            let outline = self.root.document_symbols(&library_name, source);
+            unit.roots
+                .extend(outline.iter().map(|hierarchy| hierarchy.ent));
+            unit.references
+                .extend(self.root.references_in_source(&library_name, source));
+            unit.outline.extend(outline);
+        }
+        unit
+    }
+
     /// Search for reference at position
     /// Character offset on a line in a document (zero-based). Assuming that the line is
     /// represented as a string, the `character` value represents the gap between the
@@ -251,6 +449,37 @@ impl Project {
         self.root.item_at_cursor(source, cursor)
     }
 
+    /// The declaration position and id of the entity referenced by the name at `pos`
+    pub fn reference_at(&self, pos: &SrcPos) -> Option<(SrcPos, EntityId)> {
+        self.root.reference_at(pos)
+    }
+
+    /// All design units ordered such that each unit comes after the units it depends on
+    pub fn dependency_order(&self) -> Result<Vec<UnitId>, CircularDependencyError> {
+        self.root.dependency_order()
+    }
+
+    /// Find the innermost declaration such as a process, block or design unit
+    /// that contains the position
+    pub fn enclosing_declaration<'a>(&'a self, pos: &SrcPos) -> Option<EntRef<'a>> {
+        self.root.enclosing_declaration(pos)
+    }
+
+    /// The names visible at the position when scopes are recorded
+    pub fn scope_at<'a>(&'a self, pos: &SrcPos) -> Option<ScopeSnapshot<'a>> {
+        self.root.scope_at(pos)
+    }
+
+    /// Find the smallest name or expression that contains the position
+    pub fn node_at(&self, pos: &SrcPos) -> Option<AstNode> {
+        self.root.node_at(pos)
+    }
+
+    /// Read-only access to all named entities of the analyzed design
+    pub fn entities(&self) -> impl Iterator<Item = EntRef<'_>> {
+        self.root.entities()
+    }
+
     // Find symbols that are public such as primary design units and their interfaces
     pub fn public_symbols<'a>(&'a self) -> Box<dyn Iterator<Item = EntRef<'a>> + 'a> {
         self.root.public_symbols()
@@ -273,6 +502,15 @@ impl Project {
         }
     }
 
+    /// The members that may be selected from the object at the cursor
+    pub fn selectable_members<'a>(&'a self, source: &Source, cursor: Position) -> Vec<EntRef<'a>> {
+        if let Some(ent) = self.find_declaration(source, cursor) {
+            self.root.selectable_members(ent)
+        } else {
+            Vec::default()
+        }
+    }
+
     /// Search for the declaration at decl_pos and format it
     pub fn format_declaration(&self, ent: &AnyEnt) -> Option<String> {
         self.root.format_declaration(ent)
@@ -289,6 +527,16 @@ impl Project {
         self.root.find_all_unresolved()
     }
 
+    /// The subprograms that are called from within the body of a subprogram
+    pub fn callees_of(&self, subprogram: EntityId) -> Vec<EntityId> {
+        self.root.callees_of(subprogram)
+    }
+
+    /// The subprograms whose bodies call a subprogram
+    pub fn callers_of(&self, subprogram: EntityId) -> Vec<EntityId> {
+        self.root.callers_of(subprogram)
+    }
+
     pub fn files(&self) -> impl Iterator<Item = &SourceFile> {
         self.files.values()
     }
@@ -317,6 +565,58 @@ impl Default for Project {
     }
 }
 
+/// The result of analyzing a single source file
+pub struct AnalyzedUnit<'a> {
+    /// Parser and analysis diagnostics within the source file
+    pub diagnostics: Vec<Diagnostic>,
+    /// The top level entities of the outline such as entities and packages
+    pub roots: Vec<EntRef<'a>>,
+    /// Resolved references within the source file
+    pub references: Vec<(SrcPos, EntRef<'a>)>,
+    /// The declarations of the source file as a hierarchy
+    pub outline: Vec<EntHierarchy<'a>>,
+}
+
+fn diagnostic_identity(diagnostic: &Diagnostic) -> (&Path, Range, Severity, &str) {
+    (
+        diagnostic.pos.file_name(),
+        diagnostic.pos.range(),
+        diagnostic.severity,
+        &diagnostic.message,
+    )
+}
+
+/// The change of the diagnostics between two analyses of a project
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct DiagnosticsDelta {
+    /// Diagnostics which were not reported by the previous analysis
+    pub added: Vec<Diagnostic>,
+    /// Diagnostics of the previous analysis which are no longer reported
+    pub removed: Vec<Diagnostic>,
+}
+
+impl DiagnosticsDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    fn add_changes(&mut self, old: &[Diagnostic], new: &[Diagnostic]) {
+        let old_ids: FnvHashSet<_> = old.iter().map(diagnostic_identity).collect();
+        let new_ids: FnvHashSet<_> = new.iter().map(diagnostic_identity).collect();
+
+        self.added.extend(
+            new.iter()
+                .filter(|diagnostic| !old_ids.contains(&diagnostic_identity(diagnostic)))
+                .cloned(),
+        );
+        self.removed.extend(
+            old.iter()
+                .filter(|diagnostic| !new_ids.contains(&diagnostic_identity(diagnostic)))
+                .cloned(),
+        );
+    }
+}
+
 pub struct SourceFile {
     library_names: FnvHashSet<Symbol>,
     source: Source,
@@ -326,7 +626,11 @@ pub struct SourceFile {
 
 impl SourceFile {
     fn take_design_file(&mut self) -> DesignFile {
-        std::mem::take(&mut self.design_file)
+        // The pragmas are kept such that they can be queried after analysis
+        DesignFile {
+            design_units: std::mem::take(&mut self.design_file.design_units),
+            pragmas: self.design_file.pragmas.clone(),
+        }
     }
 
     pub fn num_lines(&self) -> usize {
@@ -405,6 +709,188 @@ end architecture;
         assert_eq!(diag.message, "Duplicate architecture 'rtl' of entity 'ent'")
     }
 
+    #[test]
+    fn syntax_only_skips_semantic_analysis() {
+        let root = tempfile::tempdir().unwrap();
+        let vhdl_file_path = root.path().join("file.vhd");
+        std::fs::write(
+            &vhdl_file_path,
+            "
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  signal sig : missing_t;
+begin
+end architecture;
+
+package pkg is
+  constant c : natural := ;
+end package;
+",
+        )
+        .unwrap();
+        let source = Source::from_latin1_file(&vhdl_file_path).unwrap();
+
+        let mut project = Project::new();
+        project.set_syntax_only(true);
+        project.update_source(&source);
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:#?}");
+        assert_eq!(diagnostics[0].message, "Expected {expression}");
+
+        project.set_syntax_only(false);
+        let diagnostics = project.analyse();
+        assert!(diagnostics
+            .iter()
+            .any(|diag| diag.message == "No declaration of 'missing_t'"));
+    }
+
+    #[test]
+    fn pragmas_can_be_queried_after_analysis() {
+        let mut project = Project::new();
+        let code = "
+entity ent is
+end entity;
+-- synthesis translate_off
+-- synthesis translate_on
+";
+        let source = Source::inline(Path::new("file.vhd"), code);
+        project.update_source(&source);
+        project.analyse();
+
+        let pragmas: Vec<_> = project
+            .pragmas(Path::new("file.vhd"))
+            .iter()
+            .map(|pragma| pragma.directive.as_str())
+            .collect();
+        assert_eq!(pragmas, vec!["translate_off", "translate_on"]);
+        assert_eq!(
+            project.translate_off_regions(Path::new("file.vhd")),
+            vec![source.pos(Position::new(3, 0), Position::new(4, 25))]
+        );
+        assert!(project.pragmas(Path::new("missing.vhd")).is_empty());
+    }
+
+    #[test]
+    fn analyse_delta_reports_changed_diagnostics() {
+        let mut project = Project::new();
+        let source = Source::inline(
+            Path::new("file.vhd"),
+            "
+package pkg is
+  constant c : missing_t;
+end package;
+",
+        );
+        project.update_source(&source);
+        let delta = project.analyse_delta();
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(delta.added[0].message, "No declaration of 'missing_t'");
+        assert!(delta.removed.is_empty());
+
+        // Re-analyzing an unchanged unit does not change the diagnostics
+        project.update_source(&source);
+        assert!(project.analyse_delta().is_empty());
+
+        source.change(None, "\npackage pkg is\nend package;\n");
+        project.update_source(&source);
+        let delta = project.analyse_delta();
+        assert!(delta.added.is_empty());
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(delta.removed[0].message, "No declaration of 'missing_t'");
+    }
+
+    #[test]
+    fn analyse_delta_is_relative_to_the_latest_analysis() {
+        let mut project = Project::new();
+        let source = Source::inline(
+            Path::new("file.vhd"),
+            "
+package pkg is
+  constant c : missing_t;
+end package;
+
+package pkg2 is
+end package;
+",
+        );
+        project.update_source(&source);
+        assert_eq!(project.analyse_delta().added.len(), 1);
+
+        source.change(
+            None,
+            "
+package pkg is
+end package;
+
+package pkg2 is
+  constant c : missing_t;
+end package;
+",
+        );
+        project.update_source(&source);
+        assert_eq!(project.analyse().len(), 1);
+
+        // The diagnostics of a full analysis are also the base of the next delta
+        project.update_source(&source);
+        assert!(project.analyse_delta().is_empty());
+
+        source.change(None, "\npackage pkg is\nend package;\n");
+        project.update_source(&source);
+        let delta = project.analyse_delta();
+        assert!(delta.added.is_empty());
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(delta.removed[0].pos.start().line, 5);
+    }
+
+    #[test]
+    fn analyse_source_collects_results_of_file() {
+        let mut project = Project::new();
+        let source = Source::inline(
+            Path::new("file.vhd"),
+            "
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  component comp is
+  end component;
+begin
+  inst : comp;
+  missing_inst : missing;
+end architecture;
+",
+        );
+
+        let unit = project.analyse_source(&source);
+        assert_eq!(unit.diagnostics.len(), 1, "{:#?}", unit.diagnostics);
+        assert_eq!(unit.diagnostics[0].message, "No declaration of 'missing'");
+
+        let names: Vec<_> = unit
+            .roots
+            .iter()
+            .map(|ent| ent.designator().to_string())
+            .collect();
+        assert_eq!(names, vec!["ent"]);
+
+        // The architecture is nested below its entity
+        assert_eq!(unit.outline.len(), 1);
+        let arch = &unit.outline[0].children[0];
+        assert_eq!(arch.ent.designator().to_string(), "rtl");
+        let children: Vec<_> = arch
+            .children
+            .iter()
+            .map(|child| child.ent.designator().to_string())
+            .collect();
+        assert_eq!(children, vec!["comp", "inst", "missing_inst"]);
+
+        assert!(unit
+            .references
+            .iter()
+            .any(|(pos, ent)| pos.source() == &source && ent.designator().to_string() == "comp"));
+    }
+
     /// Test that the same file can be added to several libraries
     #[test]
     fn test_same_file_in_multiple_libraries() {
@@ -621,4 +1107,53 @@ lib.files = ['file2.vhd']
         assert_eq!(diagnostics[0].pos.source, source2); // No such library
         assert_eq!(diagnostics[1].pos.source, source2); // No declaration
     }
+
+    #[test]
+    fn config_update_keeps_analysis_settings() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let root = dunce::canonicalize(tempdir.path()).unwrap();
+
+        std::fs::write(
+            root.join("file.vhd"),
+            "
+library unkown;
+use unkown.pkg.all;
+
+package pkg is
+end package;
+        ",
+        )
+        .unwrap();
+
+        let config = Config::from_str(
+            "
+[libraries]
+lib.files = ['file.vhd']
+        ",
+            &root,
+        )
+        .unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(&config, &mut messages);
+        project.set_unreachable_warnings(true);
+        project.set_combinational_loop_warnings(true);
+        project.set_read_before_write_warnings(true);
+        project.set_max_errors(NonZeroUsize::new(1));
+        project.set_record_scopes(true);
+
+        project.update_config(&config, &mut messages);
+        assert_eq!(messages, vec![]);
+        assert!(project.root.unreachable_warnings());
+        assert!(project.root.combinational_loop_warnings());
+        assert!(project.root.read_before_write_warnings());
+        assert_eq!(project.root.max_errors(), NonZeroUsize::new(1));
+        assert!(project.root.record_scopes());
+
+        // The second error is suppressed by the notice of the error limit
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[1].severity, Severity::Info);
+    }
 }
@@ -32,6 +32,7 @@ where
 }
 
 pub fn check_end_identifier_mismatch<T: std::fmt::Display + std::cmp::PartialEq>(
+    stream: &TokenStream,
     ident: &WithPos<T>,
     end_ident: Option<WithPos<T>>,
     diagnostics: &mut dyn DiagnosticHandler,
@@ -45,6 +46,14 @@ pub fn check_end_identifier_mismatch<T: std::fmt::Display + std::cmp::PartialEq>
                 format!("End identifier mismatch, expected {}", ident.item),
             );
         }
+    } else if stream.missing_end_identifier_warnings() {
+        // The identifier is optional, it is only a matter of style
+        if let Some(token) = stream.last() {
+            diagnostics.warning(
+                &token.pos,
+                format!("Missing end identifier, expected {}", ident.item),
+            );
+        }
     }
     None
 }
@@ -87,7 +87,7 @@ pub fn parse_component_declaration(
     stream.expect_kind(SemiColon)?;
 
     Ok(ComponentDeclaration {
-        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
         ident,
         generic_list: generic_list.unwrap_or_default(),
         port_list: port_list.unwrap_or_default(),
@@ -8,7 +8,8 @@ use super::common::check_end_identifier_mismatch;
 use super::common::ParseResult;
 use super::concurrent_statement::parse_generic_and_port_map;
 use super::context::parse_use_clause;
-use super::names::{parse_name, parse_selected_name};
+use super::expression::parse_expression;
+use super::names::{parse_name, parse_selected_name, parse_type_mark};
 use super::tokens::{Kind::*, TokenStream};
 use crate::ast::*;
 use crate::data::*;
@@ -296,16 +297,17 @@ pub fn parse_configuration_declaration(
     stream.expect_kind(End)?;
     stream.pop_if_kind(Configuration);
     let end_ident = stream.pop_optional_ident();
-    stream.expect_kind(SemiColon)?;
+    let end_pos = stream.expect_kind(SemiColon)?.pos.clone();
 
     Ok(ConfigurationDeclaration {
         context_clause: ContextClause::default(),
-        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
         ident,
         entity_name,
         decl,
         vunit_bind_inds,
         block_config,
+        end_pos,
     })
 }
 
@@ -345,6 +347,34 @@ pub fn parse_configuration_specification(
     }
 }
 
+/// LRM 7.4 Disconnection specification
+pub fn parse_disconnection_specification(
+    stream: &TokenStream,
+) -> ParseResult<DisconnectionSpecification> {
+    stream.expect_kind(Disconnect)?;
+    let signals = if stream.skip_if_kind(All) {
+        GuardedSignalList::All
+    } else if stream.skip_if_kind(Others) {
+        GuardedSignalList::Others
+    } else {
+        let mut names = vec![parse_name(stream)?];
+        while stream.skip_if_kind(Comma) {
+            names.push(parse_name(stream)?);
+        }
+        GuardedSignalList::Names(names)
+    };
+    stream.expect_kind(Colon)?;
+    let type_mark = parse_type_mark(stream)?;
+    stream.expect_kind(After)?;
+    let time = parse_expression(stream)?;
+    stream.expect_kind(SemiColon)?;
+    Ok(DisconnectionSpecification {
+        signals,
+        type_mark,
+        time,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,6 +404,7 @@ end;
                     items: vec![],
                 },
                 end_ident_pos: None,
+                end_pos: code.sa("end", ";").pos(),
             }
         );
     }
@@ -401,7 +432,8 @@ end configuration cfg;
                     use_clauses: vec![],
                     items: vec![],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -433,7 +465,8 @@ end configuration cfg;
                     use_clauses: vec![],
                     items: vec![],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -467,7 +500,8 @@ end configuration cfg;
                     use_clauses: vec![],
                     items: vec![],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -495,7 +529,8 @@ end configuration cfg;
                     use_clauses: vec![],
                     items: vec![],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -538,7 +573,8 @@ end configuration cfg;
                         })
                     ],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -584,7 +620,8 @@ end configuration cfg;
                         }),
                     }),],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -641,7 +678,8 @@ end configuration cfg;
                         }),
                     }),],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -689,7 +727,8 @@ end configuration cfg;
                         block_config: None,
                     }),],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -768,7 +807,8 @@ end configuration cfg;
                         })
                     ],
                 },
-                end_ident_pos: Some(code.s("cfg", 2).pos())
+                end_ident_pos: Some(code.s("cfg", 2).pos()),
+                end_pos: code.sa("end configuration cfg", ";").pos(),
             }
         );
     }
@@ -884,4 +924,33 @@ end configuration cfg;
             }
         );
     }
+
+    #[test]
+    fn disconnection_specification() {
+        let code = Code::new("disconnect foo, bar : bit after 10 ns;");
+        assert_eq!(
+            code.with_stream(parse_disconnection_specification),
+            DisconnectionSpecification {
+                signals: GuardedSignalList::Names(vec![
+                    code.s1("foo").name(),
+                    code.s1("bar").name()
+                ]),
+                type_mark: code.s1("bit").type_mark(),
+                time: code.s1("10 ns").expr(),
+            }
+        );
+    }
+
+    #[test]
+    fn disconnection_specification_others() {
+        let code = Code::new("disconnect others : bit after 0 ns;");
+        assert_eq!(
+            code.with_stream(parse_disconnection_specification),
+            DisconnectionSpecification {
+                signals: GuardedSignalList::Others,
+                type_mark: code.s1("bit").type_mark(),
+                time: code.s1("0 ns").expr(),
+            }
+        );
+    }
 }
@@ -80,6 +80,7 @@ pub fn parse_context(
     if stream.skip_if_kind(Is) {
         let mut items = Vec::with_capacity(16);
         let end_ident;
+        let end_pos;
         loop {
             let token = stream.peek_expect()?;
             try_init_token_kind!(
@@ -91,7 +92,7 @@ pub fn parse_context(
                     stream.skip();
                     stream.pop_if_kind(Context);
                     end_ident = stream.pop_optional_ident();
-                    stream.expect_kind(SemiColon)?;
+                    end_pos = stream.expect_kind(SemiColon)?.pos.clone();
                     break;
                 }
             )
@@ -99,9 +100,15 @@ pub fn parse_context(
 
         let ident = WithDecl::new(to_simple_name(name)?);
         Ok(DeclarationOrReference::Declaration(ContextDeclaration {
-            end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+            end_ident_pos: check_end_identifier_mismatch(
+                stream,
+                &ident.tree,
+                end_ident,
+                diagnostics,
+            ),
             ident,
             items,
+            end_pos,
         }))
     } else {
         // Context reference
@@ -246,7 +253,8 @@ end context ident;
                         Some(code.s("ident", 2).pos())
                     } else {
                         None
-                    }
+                    },
+                    end_pos: code.s1(";").pos(),
                 })
             );
         }
@@ -273,11 +281,57 @@ end context ident2;
             DeclarationOrReference::Declaration(ContextDeclaration {
                 ident: code.s1("ident").decl_ident(),
                 items: vec![],
-                end_ident_pos: None
+                end_ident_pos: None,
+                end_pos: code.s1(";").pos(),
             })
         );
     }
 
+    fn parse_with_end_identifier_warnings(code: &Code) -> Vec<Diagnostic> {
+        let mut parser = crate::syntax::VHDLParser::default();
+        parser.set_missing_end_identifier_warnings(true);
+        let mut diagnostics = Vec::new();
+        parser.parse_design_source(code.source(), &mut diagnostics);
+        diagnostics
+    }
+
+    #[test]
+    fn test_context_clause_warning_missing_end_identifier() {
+        let code = Code::new(
+            "\
+context ident is
+end context;
+",
+        );
+        let (_, diagnostics) = code.with_stream_diagnostics(parse_context);
+        assert_eq!(diagnostics, vec![]);
+
+        assert_eq!(
+            parse_with_end_identifier_warnings(&code),
+            vec![Diagnostic::warning(
+                code.s1(";"),
+                "Missing end identifier, expected ident"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_context_clause_end_identifier_mismatch_is_not_a_missing_identifier() {
+        let code = Code::new(
+            "\
+context ident is
+end context ident2;
+",
+        );
+        assert_eq!(
+            parse_with_end_identifier_warnings(&code),
+            vec![Diagnostic::error(
+                code.s1("ident2"),
+                "End identifier mismatch, expected ident"
+            )]
+        );
+    }
+
     #[test]
     fn test_context_clause_items() {
         let code = Code::new(
@@ -313,7 +367,8 @@ end context;
                         code.s1("context foo.ctx;")
                     ),
                 ],
-                end_ident_pos: None
+                end_ident_pos: None,
+                end_pos: code.sa("end context", ";").pos(),
             })
         )
     }
@@ -8,8 +8,10 @@ use super::alias_declaration::parse_alias_declaration;
 use super::attributes::parse_attribute;
 use super::common::ParseResult;
 use super::component_declaration::parse_component_declaration;
-use super::configuration::parse_configuration_specification;
+use super::configuration::{parse_configuration_specification, parse_disconnection_specification};
 use super::context::parse_use_clause;
+use super::design_unit::{parse_package_body, parse_package_declaration};
+use super::interface_declaration::parse_mode_view_declaration;
 use super::names::{parse_association_list, parse_selected_name};
 use super::object_declaration::{parse_file_declaration, parse_object_declaration};
 use super::subprogram::parse_subprogram;
@@ -25,21 +27,22 @@ pub fn parse_package_instantiation(stream: &TokenStream) -> ParseResult<PackageI
     stream.expect_kind(New)?;
     let package_name = parse_selected_name(stream)?;
 
-    let generic_map = expect_token!(
+    let (generic_map, end_pos) = expect_token!(
         stream,
         token,
         Generic => {
             stream.expect_kind(Map)?;
             let association_list = parse_association_list(stream)?;
-            stream.expect_kind(SemiColon)?;
-            Some(association_list)
+            let semi_token = stream.expect_kind(SemiColon)?;
+            (Some(association_list), semi_token.pos.clone())
         },
-        SemiColon => None);
+        SemiColon => (None, token.pos.clone()));
     Ok(PackageInstantiation {
         context_clause: ContextClause::default(),
         ident: ident.into(),
         package_name,
         generic_map,
+        end_pos,
     })
 }
 
@@ -50,13 +53,14 @@ pub fn is_declarative_part(stream: &TokenStream, begin_is_end: bool) -> ParseRes
 fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> ParseResult<()> {
     match token.kind {
         Use | Type | Subtype | Shared | Constant | Signal | Variable | File | Component
-        | Attribute | Alias | Impure | Pure | Function | Procedure | Package | For => Ok(()),
+        | Attribute | Alias | Impure | Pure | Function | Procedure | Package | For | Disconnect
+        | View => Ok(()),
         Begin if may_begin => Ok(()),
         End if may_end => Ok(()),
         _ => {
             let decl_kinds = [
                 Use, Type, Subtype, Shared, Constant, Signal, Variable, File, Component, Attribute,
-                Alias, Impure, Pure, Function, Procedure, Package, For,
+                Alias, Impure, Pure, Function, Procedure, Package, For, Disconnect, View,
             ];
 
             Err(token.kinds_error(&decl_kinds))
@@ -89,6 +93,8 @@ pub fn parse_declarative_part(
                 | Attribute
                 | Use
                 | Alias
+                | Disconnect
+                | View
                 | Begin
                 | End
         )
@@ -97,7 +103,8 @@ pub fn parse_declarative_part(
     while let Some(token) = stream.peek() {
         match token.kind {
             Begin | End => break,
-            Type | Subtype | Component | Impure | Pure | Function | Procedure | Package | For => {
+            Type | Subtype | Component | Impure | Pure | Function | Procedure | Package | For
+            | View => {
                 let decl = match token.kind {
                     Type | Subtype => {
                         parse_type_declaration(stream, diagnostics).map(Declaration::Type)?
@@ -105,10 +112,22 @@ pub fn parse_declarative_part(
                     Component => parse_component_declaration(stream, diagnostics)
                         .map(Declaration::Component)?,
                     Impure | Pure | Function | Procedure => parse_subprogram(stream, diagnostics)?,
-                    Package => parse_package_instantiation(stream).map(Declaration::Package)?,
+                    Package => {
+                        if stream.nth_kind_is(1, Body) {
+                            parse_package_body(stream, diagnostics).map(Declaration::PackageBody)?
+                        } else if stream.nth_kind_is(3, New) {
+                            parse_package_instantiation(stream).map(Declaration::Package)?
+                        } else {
+                            parse_package_declaration(stream, diagnostics)
+                                .map(Declaration::PackageDeclaration)?
+                        }
+                    }
                     For => {
                         parse_configuration_specification(stream).map(Declaration::Configuration)?
                     }
+                    View => {
+                        parse_mode_view_declaration(stream, diagnostics).map(Declaration::View)?
+                    }
                     _ => unreachable!(),
                 };
                 declarations.push(decl);
@@ -133,10 +152,13 @@ pub fn parse_declarative_part(
                 }
             }
 
-            Use | Alias => {
+            Use | Alias | Disconnect => {
                 let decl: ParseResult<Declaration> = match token.kind {
                     Use => parse_use_clause(stream).map(Declaration::Use),
                     Alias => parse_alias_declaration(stream).map(Declaration::Alias),
+                    Disconnect => {
+                        parse_disconnection_specification(stream).map(Declaration::Disconnection)
+                    }
                     _ => unreachable!(),
                 };
                 match decl.or_recover_until(stream, diagnostics, is_recover_token) {
@@ -151,7 +173,8 @@ pub fn parse_declarative_part(
             _ => {
                 diagnostics.push(token.kinds_error(&[
                     Type, Subtype, Component, Impure, Pure, Function, Procedure, Package, For,
-                    File, Shared, Constant, Signal, Variable, Attribute, Use, Alias,
+                    File, Shared, Constant, Signal, Variable, Attribute, Use, Alias, Disconnect,
+                    View,
                 ]));
                 stream.skip_until(is_recover_token)?;
                 continue;
@@ -165,7 +188,7 @@ pub fn parse_declarative_part(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{ObjectClass, ObjectDeclaration};
+    use crate::ast::{ObjectClass, ObjectDeclaration, PackageBody, PackageDeclaration};
     use crate::data::Diagnostic;
     use crate::syntax::test::Code;
 
@@ -182,7 +205,8 @@ package ident is new lib.foo.bar;
                 context_clause: ContextClause::default(),
                 ident: code.s1("ident").decl_ident(),
                 package_name: code.s1("lib.foo.bar").selected_name(),
-                generic_map: None
+                generic_map: None,
+                end_pos: code.s1(";").pos(),
             }
         );
     }
@@ -208,11 +232,50 @@ package ident is new lib.foo.bar
     foo => bar
   )")
                         .association_list()
-                )
+                ),
+                end_pos: code.s1(";").pos(),
             }
         );
     }
 
+    #[test]
+    fn nested_package_declaration_and_body() {
+        let code = Code::new(
+            "\
+package inner is
+  constant x : natural := 0;
+end package;
+package body inner is
+end package body;
+package inst is new work.inner;
+",
+        );
+        let decls = code.with_stream_no_diagnostics(parse_declarative_part);
+        assert_eq!(decls.len(), 3);
+        assert_eq!(
+            decls[0],
+            Declaration::PackageDeclaration(PackageDeclaration {
+                context_clause: ContextClause::default(),
+                ident: code.s1("inner").decl_ident(),
+                generic_clause: None,
+                decl: code.s1("constant x : natural := 0;").declarative_part(),
+                end_ident_pos: None,
+                end_pos: code.sa("end package", ";").pos(),
+            })
+        );
+        assert_eq!(
+            decls[1],
+            Declaration::PackageBody(PackageBody {
+                context_clause: ContextClause::default(),
+                ident: code.s("inner", 2).decl_ident(),
+                decl: Vec::new(),
+                end_ident_pos: None,
+                end_pos: code.sa("end package body", ";").pos(),
+            })
+        );
+        assert!(matches!(decls[2], Declaration::Package(..)));
+    }
+
     #[test]
     fn parse_declarative_part_recover() {
         let code = Code::new(
@@ -228,6 +291,7 @@ constant x: natural := 5;
                 class: ObjectClass::Constant,
                 ident: code.s1("x").decl_ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("5").expr())
             })])
         );
@@ -239,7 +303,7 @@ constant x: natural := 5;
                 "Expected 'type', 'subtype', 'component', 'impure', 'pure', \
                  'function', 'procedure', 'package', 'for', 'file', \
                  'shared', 'constant', 'signal', 'variable', 'attribute', \
-                 'use' or 'alias'"
+                 'use', 'alias', 'disconnect' or 'view'"
             )]
         );
     }
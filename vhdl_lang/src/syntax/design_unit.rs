@@ -43,15 +43,16 @@ pub fn parse_entity_declaration(
     stream.pop_if_kind(End);
     stream.pop_if_kind(Entity);
     let end_ident = stream.pop_optional_ident();
-    stream.expect_kind(SemiColon)?;
+    let end_pos = stream.expect_kind(SemiColon)?.pos.clone();
     Ok(EntityDeclaration {
         context_clause: ContextClause::default(),
-        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
         ident,
         generic_clause,
         port_clause,
         decl,
         statements,
+        end_pos,
     })
 }
 
@@ -74,15 +75,16 @@ pub fn parse_architecture_body(
     stream.pop_if_kind(Architecture);
 
     let end_ident = stream.pop_optional_ident();
-    stream.expect_kind(SemiColon)?;
+    let end_pos = stream.expect_kind(SemiColon)?.pos.clone();
 
     Ok(ArchitectureBody {
         context_clause: ContextClause::default(),
-        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
         ident,
         entity_name: entity_name.into_ref(),
         decl,
         statements,
+        end_pos,
     })
 }
 
@@ -108,13 +110,14 @@ pub fn parse_package_declaration(
     stream.expect_kind(End)?;
     stream.pop_if_kind(Package);
     let end_ident = stream.pop_optional_ident();
-    stream.expect_kind(SemiColon)?;
+    let end_pos = stream.expect_kind(SemiColon)?.pos.clone();
     Ok(PackageDeclaration {
         context_clause: ContextClause::default(),
-        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
         ident,
         generic_clause,
         decl,
+        end_pos,
     })
 }
 
@@ -134,13 +137,14 @@ pub fn parse_package_body(
         stream.expect_kind(Body)?;
     }
     let end_ident = stream.pop_optional_ident();
-    stream.expect_kind(SemiColon)?;
+    let end_pos = stream.expect_kind(SemiColon)?.pos.clone();
 
     Ok(PackageBody {
         context_clause: ContextClause::default(),
         decl,
-        end_ident_pos: check_end_identifier_mismatch(&ident, end_ident, diagnostics),
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident, end_ident, diagnostics),
         ident: ident.into(),
+        end_pos,
     })
 }
 
@@ -264,7 +268,10 @@ pub fn parse_design_file(
         ));
     }
 
-    Ok(DesignFile { design_units })
+    Ok(DesignFile {
+        design_units,
+        pragmas: stream.pragmas().to_vec(),
+    })
 }
 
 #[cfg(test)]
@@ -301,7 +308,11 @@ mod tests {
     }
 
     /// An simple entity with only a name
-    fn simple_entity(ident: Ident, end_ident_pos: Option<SrcPos>) -> AnyDesignUnit {
+    fn simple_entity(
+        ident: Ident,
+        end_ident_pos: Option<SrcPos>,
+        end_pos: SrcPos,
+    ) -> AnyDesignUnit {
         AnyDesignUnit::Primary(AnyPrimaryUnit::Entity(EntityDeclaration {
             context_clause: ContextClause::default(),
             ident: ident.into(),
@@ -310,6 +321,7 @@ mod tests {
             decl: vec![],
             statements: vec![],
             end_ident_pos,
+            end_pos,
         }))
     }
 
@@ -323,7 +335,11 @@ end entity;
         );
         assert_eq!(
             design_file.design_units,
-            [simple_entity(code.s1("myent").ident(), None)]
+            [simple_entity(
+                code.s1("myent").ident(),
+                None,
+                code.s1(";").pos()
+            )]
         );
 
         let (code, design_file) = parse_ok(
@@ -336,7 +352,8 @@ end entity myent;
             design_file.design_units,
             [simple_entity(
                 code.s1("myent").ident(),
-                Some(code.s("myent", 2).pos())
+                Some(code.s("myent", 2).pos()),
+                code.s1(";").pos()
             )]
         );
     }
@@ -360,6 +377,7 @@ end entity;
                 decl: vec![],
                 statements: vec![],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -385,6 +403,7 @@ end entity;
                 decl: vec![],
                 statements: vec![],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -408,6 +427,7 @@ end entity;
                 decl: vec![],
                 statements: vec![],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -431,6 +451,7 @@ end entity;
                 decl: vec![],
                 statements: vec![],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -454,6 +475,7 @@ end entity;
                 decl: code.s1("constant foo : natural := 0;").declarative_part(),
                 statements: vec![],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -478,6 +500,7 @@ end entity;
                 decl: vec![],
                 statements: vec![code.s1("check(clk, valid);").concurrent_statement()],
                 end_ident_pos: None,
+                end_pos: code.sa("end entity", ";").pos(),
             }
         );
     }
@@ -502,10 +525,18 @@ end;
         assert_eq!(
             design_file.design_units,
             [
-                simple_entity(code.s1("myent").ident(), None),
-                simple_entity(code.s1("myent2").ident(), Some(code.s("myent2", 2).pos())),
-                simple_entity(code.s1("myent3").ident(), Some(code.s("myent3", 2).pos())),
-                simple_entity(code.s1("myent4").ident(), None)
+                simple_entity(code.s1("myent").ident(), None, code.s(";", 1).pos()),
+                simple_entity(
+                    code.s1("myent2").ident(),
+                    Some(code.s("myent2", 2).pos()),
+                    code.s(";", 2).pos()
+                ),
+                simple_entity(
+                    code.s1("myent3").ident(),
+                    Some(code.s("myent3", 2).pos()),
+                    code.s(";", 3).pos()
+                ),
+                simple_entity(code.s1("myent4").ident(), None, code.s(";", 4).pos())
             ]
         );
     }
@@ -515,6 +546,7 @@ end;
         ident: WithDecl<Ident>,
         entity_name: Ident,
         end_ident_pos: Option<SrcPos>,
+        end_pos: SrcPos,
     ) -> AnyDesignUnit {
         AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(ArchitectureBody {
             context_clause: ContextClause::default(),
@@ -523,6 +555,7 @@ end;
             decl: Vec::new(),
             statements: vec![],
             end_ident_pos,
+            end_pos,
         }))
     }
 
@@ -541,6 +574,7 @@ end architecture;
                 WithDecl::new(code.s1("arch_name").ident()),
                 code.s1("myent").ident(),
                 None,
+                code.s1(";").pos(),
             )]
         );
     }
@@ -559,7 +593,8 @@ end architecture arch_name;
             [simple_architecture(
                 WithDecl::new(code.s1("arch_name").ident()),
                 code.s1("myent").ident(),
-                Some(code.s("arch_name", 2).pos())
+                Some(code.s("arch_name", 2).pos()),
+                code.s1(";").pos()
             )]
         );
     }
@@ -578,7 +613,8 @@ end;
             [simple_architecture(
                 WithDecl::new(code.s1("arch_name").ident()),
                 code.s1("myent").ident(),
-                None
+                None,
+                code.s1(";").pos()
             )]
         );
     }
@@ -598,7 +634,8 @@ end package;
                 ident: code.s1("pkg_name").decl_ident(),
                 generic_clause: None,
                 decl: vec![],
-                end_ident_pos: None
+                end_ident_pos: None,
+                end_pos: code.sa("end package", ";").pos(),
             }
         );
     }
@@ -625,7 +662,8 @@ end package;
   constant bar : natural := 0;
 ")
                     .declarative_part(),
-                end_ident_pos: None
+                end_ident_pos: None,
+                end_pos: code.sa("end package", ";").pos(),
             }
         );
     }
@@ -652,7 +690,8 @@ end package;
                     code.s1("type bar").generic()
                 ]),
                 decl: vec![],
-                end_ident_pos: None
+                end_ident_pos: None,
+                end_pos: code.sa("end package", ";").pos(),
             }
         );
     }
@@ -686,9 +725,11 @@ end entity;
                         port_clause: None,
                         decl: vec![],
                         statements: vec![],
-                        end_ident_pos: None
+                        end_ident_pos: None,
+                        end_pos: code.sa("end entity", ";").pos(),
                     }
-                ))]
+                ))],
+                pragmas: vec![],
             }
         );
     }
@@ -723,7 +764,8 @@ context lib.ctx;
         assert_eq!(
             design_file,
             DesignFile {
-                design_units: vec![]
+                design_units: vec![],
+                pragmas: vec![],
             }
         );
     }
@@ -771,4 +813,73 @@ end entity;
             _ => panic!("Expected entity"),
         }
     }
+
+    #[test]
+    fn pragmas_are_captured_from_comments() {
+        let (code, design_file) = parse_ok(
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+  -- a regular comment
+  -- synthesis translate_off
+  signal sim : bit;
+  -- synthesis translate_on
+  signal s : bit; -- pragma keep
+  /* synthesis translate_off */
+begin
+end architecture;
+",
+        );
+
+        assert_eq!(
+            design_file.pragmas,
+            vec![
+                Pragma {
+                    tool: "synthesis".to_owned(),
+                    directive: "translate_off".to_owned(),
+                    pos: code.s1("-- synthesis translate_off").pos(),
+                },
+                Pragma {
+                    tool: "synthesis".to_owned(),
+                    directive: "translate_on".to_owned(),
+                    pos: code.s1("-- synthesis translate_on").pos(),
+                },
+                Pragma {
+                    tool: "pragma".to_owned(),
+                    directive: "keep".to_owned(),
+                    pos: code.s1("-- pragma keep").pos(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            design_file.translate_off_regions(),
+            vec![code
+                .s1("-- synthesis translate_off")
+                .pos()
+                .combine(&code.s1("-- synthesis translate_on").pos())]
+        );
+    }
+
+    #[test]
+    fn translate_off_region_without_translate_on_extends_to_end_of_file() {
+        let (code, design_file) = parse_ok(
+            "\
+entity ent is
+end entity;
+-- Pragma Translate_Off
+entity ent2 is
+end entity;",
+        );
+
+        assert_eq!(
+            design_file.translate_off_regions(),
+            vec![code
+                .s1("-- Pragma Translate_Off")
+                .pos()
+                .combine(&code.s("end entity;", 2).pos())]
+        );
+    }
 }
@@ -11,6 +11,7 @@ use super::tokens::{Kind, Kind::*, TokenStream};
 use crate::ast;
 use crate::ast::*;
 use crate::data::{Diagnostic, WithPos};
+use crate::VhdlStandard;
 
 fn name_to_expression(name: WithPos<Name>) -> WithPos<Expression> {
     WithPos {
@@ -357,6 +358,19 @@ fn parse_expression_or_aggregate(stream: &TokenStream) -> ParseResult<WithPos<Ex
                     pos: token.pos.clone(),
                 };
                 Ok(expr)
+            },
+
+            // Was conditional expression
+            When => {
+                if stream.standard() < VhdlStandard::VHDL2019 {
+                    return Err(Diagnostic::error(
+                        token,
+                        "Conditional expressions require VHDL-2019",
+                    ));
+                }
+                let conds = parse_conditional_expression(stream, WithPos::new(expr, pos))?;
+                let rpar = stream.expect_kind(RightPar)?;
+                Ok(WithPos::new(Expression::Conditional(Box::new(conds)), rpar.pos.clone()))
             }
         )
     } else {
@@ -365,6 +379,60 @@ fn parse_expression_or_aggregate(stream: &TokenStream) -> ParseResult<WithPos<Ex
     }
 }
 
+/// Parse the remainder of a VHDL-2019 conditional expression after the first value
+/// value when condition { else value when condition } else value
+fn parse_conditional_expression(
+    stream: &TokenStream,
+    first: WithPos<Expression>,
+) -> ParseResult<Conditionals<WithPos<Expression>>> {
+    let mut conditionals = Vec::new();
+    let mut item = first;
+    loop {
+        stream.expect_kind(When)?;
+        let condition = parse_expression(stream)?;
+        conditionals.push(Conditional { condition, item });
+        stream.expect_kind(Else)?;
+        item = parse_expression(stream)?;
+
+        if !stream.next_kind_is(When) {
+            return Ok(Conditionals {
+                conditionals,
+                else_item: Some(item),
+            });
+        }
+    }
+}
+
+/// Parse a VHDL-2019 selected expression
+/// with expression select ( value when choices { , value when choices } )
+fn parse_selected_expression(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
+    let with_token = stream.expect_kind(With)?;
+    let expression = parse_expression(stream)?;
+    stream.expect_kind(Select)?;
+    stream.expect_kind(LeftPar)?;
+
+    let mut alternatives = Vec::with_capacity(2);
+    loop {
+        let item = parse_expression(stream)?;
+        stream.expect_kind(When)?;
+        let choices = parse_choices(stream)?;
+        alternatives.push(Alternative { choices, item });
+
+        if !stream.skip_if_kind(Comma) {
+            break;
+        }
+    }
+    let rpar = stream.expect_kind(RightPar)?;
+
+    Ok(WithPos::new(
+        Expression::Selected(Box::new(Selection {
+            expression,
+            alternatives,
+        })),
+        with_token.pos.combine(&rpar.pos),
+    ))
+}
+
 /// Parse a primary value which is:
 /// 1. CHARACTER_LITERAL|INTEGER_LITERAL|IDENTIFIER|BOOLEAN_LITERAL
 /// 2. (expression)
@@ -420,6 +488,7 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
                 pos: token.pos.clone(),
             })
         }
+        With => parse_selected_expression(stream),
         New => {
             let alloc = parse_allocator(stream)?;
 
@@ -895,6 +964,74 @@ mod tests {
         assert_eq!(code.with_stream(parse_expression), qexpr);
     }
 
+    #[test]
+    fn parses_conditional_expression() {
+        let code = Code::with_standard(
+            "(a when c0 else b + 1 when c1 else c)",
+            VhdlStandard::VHDL2019,
+        );
+
+        let expr = WithPos {
+            item: Expression::Conditional(Box::new(Conditionals {
+                conditionals: vec![
+                    Conditional {
+                        condition: code.s1("c0").expr(),
+                        item: code.s1("a").expr(),
+                    },
+                    Conditional {
+                        condition: code.s1("c1").expr(),
+                        item: code.s1("b + 1").expr(),
+                    },
+                ],
+                else_item: Some(code.s1("c)").s1("c").expr()),
+            })),
+            pos: code.pos(),
+        };
+
+        assert_eq!(code.with_stream(parse_expression), expr);
+        assert_eq!(expr.to_string(), "(a when c0 else b + 1 when c1 else c)");
+    }
+
+    #[test]
+    fn conditional_expression_requires_vhdl2019() {
+        let code = Code::new("(a when c0 else b)");
+        assert_eq!(
+            code.with_partial_stream(parse_expression),
+            Err(Diagnostic::error(
+                code.s1("when"),
+                "Conditional expressions require VHDL-2019"
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_selected_expression() {
+        let code = Code::new("with sel select (a when 0 | 1, b + 1 when others)");
+
+        let expr = WithPos {
+            item: Expression::Selected(Box::new(Selection {
+                expression: code.s1("sel").expr(),
+                alternatives: vec![
+                    Alternative {
+                        choices: code.s1("0 | 1").choices(),
+                        item: code.s1("a").expr(),
+                    },
+                    Alternative {
+                        choices: code.s1("others").choices(),
+                        item: code.s1("b + 1").expr(),
+                    },
+                ],
+            })),
+            pos: code.pos(),
+        };
+
+        assert_eq!(code.with_stream(parse_expression), expr);
+        assert_eq!(
+            expr.to_string(),
+            "with sel select (a when 0 | 1, b + 1 when others)"
+        );
+    }
+
     #[test]
     fn qualified_expression_precedence() {
         let code = Code::new("mark0'(0) < mark1'(1)");
@@ -4,7 +4,7 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use super::common::ParseResult;
+use super::common::{check_end_identifier_mismatch, ParseResult};
 use super::names::{parse_association_list_no_leftpar, parse_identifier_list, parse_selected_name};
 use super::object_declaration::{parse_file_declaration_no_semi, parse_optional_assignment};
 use super::subprogram::parse_subprogram_declaration_no_semi;
@@ -94,6 +94,10 @@ fn parse_interface_object_declaration(
 
     stream.expect_kind(Colon)?;
 
+    if stream.skip_if_kind(View) {
+        return parse_mode_view_indication(stream, list_type, explicit_object_class, idents);
+    }
+
     let mode_with_pos = parse_optional_mode(stream)?;
     let mode = mode_with_pos
         .as_ref()
@@ -149,6 +153,7 @@ fn parse_interface_object_declaration(
             InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                 list_type,
                 mode,
+                mode_view: None,
                 class: object_class,
                 ident: ident.into(),
                 subtype_indication: subtype.clone(),
@@ -158,6 +163,88 @@ fn parse_interface_object_declaration(
         .collect())
 }
 
+/// A VHDL-2019 mode view indication, the record subtype must be given explicitly
+fn parse_mode_view_indication(
+    stream: &TokenStream,
+    list_type: InterfaceType,
+    explicit_object_class: Option<WithPos<ObjectClass>>,
+    idents: Vec<Ident>,
+) -> ParseResult<Vec<InterfaceDeclaration>> {
+    let mode_view = parse_selected_name(stream)?;
+    stream.expect_kind(Of)?;
+    let subtype = parse_subtype_indication(stream)?;
+
+    if list_type == InterfaceType::Generic {
+        return Err(Diagnostic::error(
+            &mode_view,
+            "Generic list does not allow mode views",
+        ));
+    }
+
+    if let Some(class) = explicit_object_class {
+        if class.item != ObjectClass::Signal {
+            return Err(Diagnostic::error(
+                &class.pos,
+                "Only a signal may have a mode view",
+            ));
+        }
+    }
+
+    Ok(idents
+        .into_iter()
+        .map(|ident| {
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type,
+                // The mode of each element is given by the view
+                mode: Mode::InOut,
+                mode_view: Some(mode_view.clone()),
+                class: ObjectClass::Signal,
+                ident: ident.into(),
+                subtype_indication: subtype.clone(),
+                expression: None,
+            })
+        })
+        .collect())
+}
+
+/// LRM 6.5.2 Mode view declaration
+pub fn parse_mode_view_declaration(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<ModeViewDeclaration> {
+    stream.expect_kind(View)?;
+    let ident = WithDecl::new(stream.expect_ident()?);
+    stream.expect_kind(Of)?;
+    let typ = parse_subtype_indication(stream)?;
+    stream.expect_kind(Is)?;
+
+    let mut elements = Vec::new();
+    while !stream.skip_if_kind(End) {
+        let names = parse_identifier_list(stream)?
+            .into_iter()
+            .map(WithRef::new)
+            .collect();
+        stream.expect_kind(Colon)?;
+        let Some(mode) = parse_optional_mode(stream)? else {
+            let token = stream.peek_expect()?;
+            return Err(token.kinds_error(&[In, Out, InOut, Buffer, Linkage]));
+        };
+        stream.expect_kind(SemiColon)?;
+        elements.push(ModeViewElementDefinition { names, mode });
+    }
+
+    stream.expect_kind(View)?;
+    let end_ident = stream.pop_optional_ident();
+    stream.expect_kind(SemiColon)?;
+
+    Ok(ModeViewDeclaration {
+        end_ident_pos: check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics),
+        ident,
+        typ,
+        elements,
+    })
+}
+
 fn parse_subprogram_default(stream: &TokenStream) -> ParseResult<Option<SubprogramDefault>> {
     if stream.skip_if_kind(Is) {
         let default = {
@@ -389,6 +476,7 @@ mod tests {
     use super::*;
     use crate::syntax::test::Code;
     use crate::syntax::tokens::kinds_error;
+    use crate::VhdlStandard;
 
     #[test]
     fn parses_interface_identifier_list() {
@@ -399,6 +487,7 @@ mod tests {
                 InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                     list_type: InterfaceType::Generic,
                     mode: Mode::In,
+                    mode_view: None,
                     class: ObjectClass::Constant,
                     ident: code.s1("foo").decl_ident(),
                     subtype_indication: code.s1("natural").subtype_indication(),
@@ -407,6 +496,7 @@ mod tests {
                 InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                     list_type: InterfaceType::Generic,
                     mode: Mode::In,
+                    mode_view: None,
                     class: ObjectClass::Constant,
                     ident: code.s1("bar").decl_ident(),
                     subtype_indication: code.s1("natural").subtype_indication(),
@@ -424,6 +514,7 @@ mod tests {
             InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                 list_type: InterfaceType::Generic,
                 mode: Mode::In,
+                mode_view: None,
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").decl_ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
@@ -476,6 +567,7 @@ mod tests {
             InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                 list_type: InterfaceType::Port,
                 mode: Mode::In,
+                mode_view: None,
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").decl_ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
@@ -544,6 +636,7 @@ mod tests {
             InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                 list_type: InterfaceType::Generic,
                 mode: Mode::In,
+                mode_view: None,
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").decl_ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
@@ -560,6 +653,7 @@ mod tests {
             InterfaceDeclaration::Object(InterfaceObjectDeclaration {
                 list_type: InterfaceType::Port,
                 mode: Mode::In,
+                mode_view: None,
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").decl_ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
@@ -568,6 +662,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_port_with_mode_view() {
+        let code = Code::with_standard(
+            "signal foo : view rec_view of rec_t",
+            VhdlStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type: InterfaceType::Port,
+                mode: Mode::InOut,
+                mode_view: Some(code.s1("rec_view").selected_name()),
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").decl_ident(),
+                subtype_indication: code.s1("rec_t").subtype_indication(),
+                expression: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_mode_view_not_allowed_for_generic() {
+        let code = Code::with_standard("foo : view rec_view of rec_t", VhdlStandard::VHDL2019);
+        assert_eq!(
+            code.with_stream_err(parse_generic),
+            Diagnostic::error(
+                code.s1("rec_view"),
+                "Generic list does not allow mode views"
+            )
+        );
+    }
+
+    #[test]
+    fn parses_mode_view_declaration() {
+        let code = Code::with_standard(
+            "\
+view rec_view of rec_t is
+  a, b : in;
+  c : out;
+end view rec_view;",
+            VhdlStandard::VHDL2019,
+        );
+        let view = code.with_stream_no_diagnostics(parse_mode_view_declaration);
+        assert_eq!(view.ident, code.s1("rec_view").decl_ident());
+        assert_eq!(view.typ, code.s1("rec_t").subtype_indication());
+        assert_eq!(
+            view.elements,
+            vec![
+                ModeViewElementDefinition {
+                    names: vec![
+                        WithRef::new(code.s1("a").ident()),
+                        WithRef::new(code.s1("b").ident())
+                    ],
+                    mode: WithPos::new(Mode::In, code.s1("in;").s1("in").pos()),
+                },
+                ModeViewElementDefinition {
+                    names: vec![WithRef::new(code.s1("c :").s1("c").ident())],
+                    mode: WithPos::new(Mode::Out, code.s1("out").pos()),
+                }
+            ]
+        );
+        assert_eq!(view.end_ident_pos, Some(code.s("rec_view", 2).pos()));
+    }
+
     #[test]
     fn parse_generic_non_in_mode_error() {
         let code = Code::new("foo : out boolean");
@@ -161,6 +161,19 @@ fn parse_actual_part(stream: &TokenStream) -> ParseResult<WithPos<ActualPart>> {
 }
 
 fn parse_association_element(stream: &TokenStream) -> ParseResult<AssociationElement> {
+    if let Some(token) = stream.pop_if_kind(Others) {
+        // others => actual
+        let formal = WithPos::from(
+            Name::Designator(Designator::Others.into_ref()),
+            token.pos.clone(),
+        );
+        stream.expect_kind(RightArrow)?;
+        return Ok(AssociationElement {
+            formal: Some(formal),
+            actual: parse_actual_part(stream)?,
+        });
+    }
+
     let actual = parse_actual_part(stream)?;
     if stream.skip_if_kind(RightArrow) {
         Ok(AssociationElement {
@@ -1020,6 +1033,26 @@ mod tests {
         assert_eq!(code.with_stream(parse_association_list), vec![elem1, elem2]);
     }
 
+    #[test]
+    fn test_association_list_others() {
+        let code = Code::new("(arg => sig, others => open)");
+        let elem1 = AssociationElement {
+            formal: Some(code.s1("arg").name()),
+            actual: code.s1("sig").expr().map_into(ActualPart::Expression),
+        };
+        let elem2 = AssociationElement {
+            formal: Some(WithPos::new(
+                Name::Designator(Designator::Others.into_ref()),
+                code.s1("others").pos(),
+            )),
+            actual: WithPos::new(ActualPart::Open, code.s1("open").pos()),
+        };
+        let assocs = code.with_stream(parse_association_list);
+        assert_eq!(assocs, vec![elem1, elem2]);
+        assert!(!assocs[0].is_others());
+        assert!(assocs[1].is_others());
+    }
+
     #[test]
     fn test_external_name_implicit_relative() {
         let code = Code::new("<< signal dut.foo : std_logic >>");
@@ -8,12 +8,16 @@ use super::design_unit::parse_design_file;
 use super::tokens::{Symbols, TokenStream, Tokenizer};
 use crate::ast::DesignFile;
 use crate::data::*;
+use crate::VhdlStandard;
 use std::io;
 use std::sync::Arc;
 
 #[derive(Default)]
 pub struct VHDLParser {
     pub symbols: Arc<Symbols>,
+    // Warn when the end of a declaration omits the identifier
+    missing_end_identifier_warnings: bool,
+    standard: VhdlStandard,
 }
 
 pub type ParserResult = Result<(Source, DesignFile), io::Error>;
@@ -23,14 +27,35 @@ impl VHDLParser {
         self.symbols.symtab().insert(name)
     }
 
+    /// Warn when the end of a declaration omits the identifier such as 'end entity;'
+    pub fn set_missing_end_identifier_warnings(&mut self, enable: bool) {
+        self.missing_end_identifier_warnings = enable;
+    }
+
+    pub fn missing_end_identifier_warnings(&self) -> bool {
+        self.missing_end_identifier_warnings
+    }
+
+    /// The reserved words depend on the standard such as 'view' which is a keyword in VHDL-2019
+    pub fn set_standard(&mut self, standard: VhdlStandard) {
+        self.standard = standard;
+    }
+
+    pub fn standard(&self) -> VhdlStandard {
+        self.standard
+    }
+
     pub fn parse_design_source(
         &self,
         source: &Source,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> DesignFile {
+        let _span = enter_span!(INFO, "parse", file = %source.file_name().display());
         let contents = source.contents();
-        let tokenizer = Tokenizer::new(&self.symbols, source, ContentReader::new(&contents));
-        let stream = TokenStream::new(tokenizer, diagnostics);
+        let mut tokenizer = Tokenizer::new(&self.symbols, source, ContentReader::new(&contents));
+        tokenizer.set_standard(self.standard);
+        let mut stream = TokenStream::new(tokenizer, diagnostics);
+        stream.set_missing_end_identifier_warnings(self.missing_end_identifier_warnings);
 
         match parse_design_file(&stream, diagnostics) {
             Ok(design_file) => design_file,
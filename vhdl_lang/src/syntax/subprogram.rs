@@ -136,6 +136,7 @@ pub fn parse_subprogram_declaration(
 /// LRM 4.3 Subprogram bodies
 pub fn parse_subprogram_body(
     stream: &TokenStream,
+    start_pos: &SrcPos,
     specification: SubprogramDeclaration,
     diagnostics: &mut dyn DiagnosticHandler,
 ) -> ParseResult<SubprogramBody> {
@@ -160,13 +161,14 @@ pub fn parse_subprogram_body(
             } else {
                 None
             };
-            stream.expect_kind(SemiColon)?;
+            let semicolon = stream.expect_kind(SemiColon)?;
 
             Ok(SubprogramBody {
-                end_ident_pos: check_end_identifier_mismatch(specification.subpgm_designator(), end_ident, diagnostics),
+                end_ident_pos: check_end_identifier_mismatch(stream, specification.subpgm_designator(), end_ident, diagnostics),
                 specification,
                 declarations,
                 statements,
+                pos: start_pos.combine(&semicolon.pos),
             })
         }
     )
@@ -176,12 +178,13 @@ pub fn parse_subprogram(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
 ) -> ParseResult<Declaration> {
+    let start_pos = stream.peek_expect()?.pos.clone();
     let specification = parse_subprogram_declaration_no_semi(stream, diagnostics)?;
     expect_token!(
         stream,
         token,
         Is => {
-            Ok(Declaration::SubprogramBody(parse_subprogram_body(stream, specification, diagnostics)?))
+            Ok(Declaration::SubprogramBody(parse_subprogram_body(stream, &start_pos, specification, diagnostics)?))
         },
         SemiColon => {
             Ok(Declaration::SubprogramDeclaration(specification))
@@ -445,6 +448,7 @@ end function;
             declarations,
             statements,
             end_ident_pos: None,
+            pos: code.s1("function").pos().combine(&code.s1("end function;")),
         };
         assert_eq!(
             code.with_stream_no_diagnostics(parse_subprogram),
@@ -485,6 +489,10 @@ end function foo;
             declarations: vec![],
             statements: vec![],
             end_ident_pos: Some(code.s("foo", 2).pos()),
+            pos: code
+                .s1("function")
+                .pos()
+                .combine(&code.s1("end function foo;")),
         };
         assert_eq!(
             code.with_stream_no_diagnostics(parse_subprogram),
@@ -509,6 +517,10 @@ end function \"+\";
             declarations: vec![],
             statements: vec![],
             end_ident_pos: Some(code.s("\"+\"", 2).pos()),
+            pos: code
+                .s1("function")
+                .pos()
+                .combine(&code.s1("end function \"+\";")),
         };
         assert_eq!(
             code.with_stream_no_diagnostics(parse_subprogram),
@@ -28,6 +28,7 @@ use crate::ast;
 use crate::ast::*;
 use crate::data::Range;
 use crate::data::*;
+use crate::VhdlStandard;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -37,12 +38,18 @@ use std::sync::Arc;
 
 pub struct CodeBuilder {
     pub symbols: Arc<Symbols>,
+    pub standard: VhdlStandard,
 }
 
 impl CodeBuilder {
     pub fn new() -> CodeBuilder {
+        Self::with_standard(VhdlStandard::default())
+    }
+
+    pub fn with_standard(standard: VhdlStandard) -> CodeBuilder {
         CodeBuilder {
             symbols: Arc::new(Symbols::default()),
+            standard,
         }
     }
 
@@ -53,6 +60,7 @@ impl CodeBuilder {
 
         let code = Code {
             symbols: self.symbols.clone(),
+            standard: self.standard,
             pos,
         };
 
@@ -80,6 +88,7 @@ impl CodeBuilder {
 #[derive(Clone)]
 pub struct Code {
     pub symbols: Arc<Symbols>,
+    standard: VhdlStandard,
     pos: SrcPos,
 }
 
@@ -88,6 +97,10 @@ impl Code {
         CodeBuilder::new().code(code)
     }
 
+    pub fn with_standard(code: &str, standard: VhdlStandard) -> Code {
+        CodeBuilder::with_standard(standard).code(code)
+    }
+
     pub fn new_with_file_name(file_name: &Path, code: &str) -> Code {
         CodeBuilder::new().code_with_file_name(file_name, code)
     }
@@ -95,6 +108,7 @@ impl Code {
     fn in_range(&self, range: Range) -> Code {
         Code {
             symbols: self.symbols.clone(),
+            standard: self.standard,
             pos: SrcPos::new(self.pos.source.clone(), range),
         }
     }
@@ -171,6 +185,7 @@ impl Code {
             let contents = self.pos.source.contents();
             let reader = ContentReader::new(&contents);
             let mut tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader);
+            tokenizer.set_standard(self.standard);
             loop {
                 let token = tokenizer.pop();
 
@@ -206,7 +221,8 @@ impl Code {
         );
         let contents = source.contents();
         let reader = ContentReader::new(&contents);
-        let tokenizer = Tokenizer::new(&self.symbols, &source, reader);
+        let mut tokenizer = Tokenizer::new(&self.symbols, &source, reader);
+        tokenizer.set_standard(self.standard);
         let mut stream = TokenStream::new(tokenizer, &mut NoDiagnostics);
         forward(&stream, self.pos.start());
         parse_fun(&mut stream)
@@ -231,7 +247,8 @@ impl Code {
     {
         let contents = self.pos.source.contents();
         let reader = ContentReader::new(&contents);
-        let tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader);
+        let mut tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader);
+        tokenizer.set_standard(self.standard);
         let mut stream = TokenStream::new(tokenizer, &mut NoDiagnostics);
         parse_fun(&mut stream)
     }
@@ -7,8 +7,9 @@
 use fnv::FnvHashMap;
 
 use crate::ast::{self, AttributeDesignator, Operator};
-use crate::ast::{BaseSpecifier, Ident};
+use crate::ast::{BaseSpecifier, Ident, Pragma};
 use crate::data::*;
+use crate::VhdlStandard;
 
 /// The kind of a Token
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -19,6 +20,10 @@ pub enum Kind {
     Configuration,
     Package,
     Block,
+    Guarded,
+    Disconnect,
+    Register,
+    Bus,
     Process,
     Generate,
     Postponed,
@@ -95,6 +100,7 @@ pub enum Kind {
     Function,
     Procedure,
     Vunit,
+    View,
 
     // Unary operators
     Abs,
@@ -268,6 +274,10 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Configuration => "configuration",
         Package => "package",
         Block => "block",
+        Guarded => "guarded",
+        Disconnect => "disconnect",
+        Register => "register",
+        Bus => "bus",
         Process => "process",
         Generate => "generate",
         Postponed => "postponed",
@@ -344,6 +354,7 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Function => "function",
         Procedure => "procedure",
         Vunit => "vunit",
+        View => "view",
 
         // Unary operators
         Abs => "abs",
@@ -1113,6 +1124,7 @@ fn parse_basic_identifier_or_keyword(
     buffer: &mut Latin1String,
     reader: &mut ContentReader,
     symbols: &Symbols,
+    standard: VhdlStandard,
 ) -> Result<(Kind, Value), TokenError> {
     buffer.bytes.clear();
     while let Some(b) = reader.peek()? {
@@ -1127,7 +1139,7 @@ fn parse_basic_identifier_or_keyword(
         }
     }
 
-    Ok(symbols.insert_or_keyword(buffer))
+    Ok(symbols.insert_or_keyword(buffer, standard))
 }
 
 /// Assumes leading ' has already been consumed
@@ -1149,6 +1161,27 @@ fn parse_character_literal(
     }
 }
 
+/// Comments starting with one of these words are tool directives
+const PRAGMA_TOOLS: [&str; 4] = ["pragma", "synthesis", "synopsys", "rtl_synthesis"];
+
+fn parse_pragma(source: &Source, comment: &Comment) -> Option<Pragma> {
+    if comment.multi_line {
+        return None;
+    }
+
+    let (tool, directive) = comment.value.trim().split_once(char::is_whitespace)?;
+    let tool = tool.to_ascii_lowercase();
+    if !PRAGMA_TOOLS.contains(&tool.as_str()) {
+        return None;
+    }
+
+    Some(Pragma {
+        tool,
+        directive: directive.trim().to_owned(),
+        pos: source.pos(comment.range.start, comment.range.end),
+    })
+}
+
 fn get_leading_comments(reader: &mut ContentReader) -> Result<Vec<Comment>, TokenError> {
     let mut comments: Vec<Comment> = Vec::new();
 
@@ -1237,6 +1270,14 @@ fn get_trailing_comment(reader: &mut ContentReader) -> Result<Option<Comment>, T
     }
 }
 
+/// Keywords introduced by a later standard are identifiers in earlier ones
+fn is_reserved(kind: Kind, standard: VhdlStandard) -> bool {
+    match kind {
+        View => standard >= VhdlStandard::VHDL2019,
+        _ => true,
+    }
+}
+
 /// Static tokenizer data
 pub struct Symbols {
     symtab: SymbolTable,
@@ -1249,12 +1290,11 @@ impl Symbols {
         &self.symtab
     }
 
-    fn insert_or_keyword(&self, name: &Latin1String) -> (Kind, Value) {
+    fn insert_or_keyword(&self, name: &Latin1String, standard: VhdlStandard) -> (Kind, Value) {
         let symbol = self.symtab.insert(name);
-        if let Some(kind) = self.keywords.get(symbol.id) {
-            (*kind, Value::NoValue)
-        } else {
-            (Identifier, Value::Identifier(symbol))
+        match self.keywords.get(symbol.id) {
+            Some(kind) if is_reserved(*kind, standard) => (*kind, Value::NoValue),
+            _ => (Identifier, Value::Identifier(symbol)),
         }
     }
 }
@@ -1267,6 +1307,10 @@ impl std::default::Default for Symbols {
             ("configuration", Configuration),
             ("package", Package),
             ("block", Block),
+            ("guarded", Guarded),
+            ("disconnect", Disconnect),
+            ("register", Register),
+            ("bus", Bus),
             ("process", Process),
             ("generate", Generate),
             ("postponed", Postponed),
@@ -1359,6 +1403,7 @@ impl std::default::Default for Symbols {
             ("mod", Mod),
             ("rem", Rem),
             ("vunit", Vunit),
+            ("view", View),
         ];
 
         let attributes = [
@@ -1461,11 +1506,13 @@ impl std::default::Default for Symbols {
 
 pub struct Tokenizer<'a> {
     symbols: &'a Symbols,
+    standard: VhdlStandard,
     buffer: Latin1String,
     state: TokenState,
     pub source: &'a Source,
     reader: ContentReader<'a>,
     final_comments: Option<Vec<Comment>>,
+    pragmas: Vec<Pragma>,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -1476,14 +1523,25 @@ impl<'a> Tokenizer<'a> {
     ) -> Tokenizer<'a> {
         Tokenizer {
             symbols,
+            standard: VhdlStandard::default(),
             state: TokenState::new(reader.state()),
             buffer: Latin1String::empty(),
             source,
             reader,
             final_comments: None,
+            pragmas: Vec::new(),
         }
     }
 
+    /// The standard that decides which words are reserved
+    pub fn set_standard(&mut self, standard: VhdlStandard) {
+        self.standard = standard;
+    }
+
+    pub fn standard(&self) -> VhdlStandard {
+        self.standard
+    }
+
     pub fn attribute(&self, sym: Symbol) -> AttributeDesignator {
         self.symbols
             .attributes
@@ -1519,6 +1577,7 @@ impl<'a> Tokenizer<'a> {
                         &mut self.buffer,
                         &mut self.reader,
                         self.symbols,
+                        self.standard,
                     )?
                 }
             }
@@ -1713,6 +1772,7 @@ impl<'a> Tokenizer<'a> {
 
     fn pop_raw(&mut self) -> Result<Option<Token>, TokenError> {
         let leading_comments = get_leading_comments(&mut self.reader)?;
+        self.add_pragmas(&leading_comments);
         self.state.start = self.reader.state();
 
         match self.parse_token()? {
@@ -1721,6 +1781,7 @@ impl<'a> Tokenizer<'a> {
                 let pos_start = self.state.start.pos();
                 let pos_end = self.reader.pos();
                 let trailing_comment = get_trailing_comment(&mut self.reader)?;
+                self.add_pragmas(trailing_comment.as_slice());
                 let token_comments = if (!leading_comments.is_empty()) | trailing_comment.is_some()
                 {
                     Some(Box::new(TokenComments {
@@ -1760,6 +1821,20 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    fn add_pragmas(&mut self, comments: &[Comment]) {
+        let source = self.source;
+        self.pragmas.extend(
+            comments
+                .iter()
+                .filter_map(|comment| parse_pragma(source, comment)),
+        );
+    }
+
+    /// The tool directives found in comments so far
+    pub fn pragmas(&self) -> &[Pragma] {
+        &self.pragmas
+    }
+
     #[allow(dead_code)]
     pub fn get_final_comments(&self) -> Option<Vec<Comment>> {
         self.final_comments.clone()
@@ -1804,6 +1879,18 @@ mod tests {
         assert_eq!(kinds_tokenize("not"), vec![Not]);
     }
 
+    #[test]
+    fn tokenize_view_is_keyword_only_in_vhdl_2019() {
+        let code = Code::with_standard("view", VhdlStandard::VHDL2019);
+        assert_eq!(kinds(&code.tokenize()), vec![View]);
+
+        let code = Code::with_standard("view", VhdlStandard::VHDL2008);
+        assert_eq!(
+            code.tokenize()[0].value,
+            Value::Identifier(code.symbol("view"))
+        );
+    }
+
     #[test]
     fn tokenize_newline() {
         assert_eq!(
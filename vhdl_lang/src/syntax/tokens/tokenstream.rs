@@ -8,14 +8,16 @@ use std::cell::Cell;
 
 use super::tokenizer::Kind::*;
 use super::tokenizer::*;
-use crate::ast::{AttributeDesignator, Ident, RangeAttribute, TypeAttribute};
+use crate::ast::{AttributeDesignator, Ident, Pragma, RangeAttribute, TypeAttribute};
 use crate::data::{DiagnosticHandler, DiagnosticResult, WithPos};
-use crate::{Diagnostic, SrcPos};
+use crate::{Diagnostic, SrcPos, VhdlStandard};
 
 pub struct TokenStream<'a> {
     tokenizer: Tokenizer<'a>,
     idx: Cell<usize>,
     tokens: Vec<Token>,
+    // Warn when the end of a declaration omits the identifier
+    missing_end_identifier_warnings: bool,
 }
 
 impl<'a> TokenStream<'a> {
@@ -35,9 +37,27 @@ impl<'a> TokenStream<'a> {
             tokenizer,
             idx: Cell::new(0),
             tokens,
+            missing_end_identifier_warnings: false,
         }
     }
 
+    pub fn set_missing_end_identifier_warnings(&mut self, enable: bool) {
+        self.missing_end_identifier_warnings = enable;
+    }
+
+    pub fn missing_end_identifier_warnings(&self) -> bool {
+        self.missing_end_identifier_warnings
+    }
+
+    /// The standard that decides which language constructs are parsed
+    pub fn standard(&self) -> VhdlStandard {
+        self.tokenizer.standard()
+    }
+
+    pub fn pragmas(&self) -> &[Pragma] {
+        self.tokenizer.pragmas()
+    }
+
     pub fn state(&self) -> usize {
         self.get_idx()
     }
@@ -231,7 +231,7 @@ pub fn parse_type_declaration(
                 SemiColon => TypeDefinition::Numeric(constraint),
                 Units => {
                     let (def, end_ident) = parse_physical_type_definition(stream, constraint)?;
-                    end_ident_pos = check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics);
+                    end_ident_pos = check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics);
                     def
                 }
             )
@@ -251,12 +251,12 @@ pub fn parse_type_declaration(
                 stream.expect_kind(Body)?;
                 let end_ident = stream.pop_optional_ident();
                 stream.expect_kind(SemiColon)?;
-                end_ident_pos = check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics);
+                end_ident_pos = check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics);
 
                 TypeDefinition::ProtectedBody(ProtectedTypeBody {decl})
             } else {
                 let (protected_type_decl, end_ident) = parse_protected_type_declaration(stream, diagnostics)?;
-                end_ident_pos = check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics);
+                end_ident_pos = check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics);
                 stream.expect_kind(SemiColon)?;
                 TypeDefinition::Protected(protected_type_decl)
             }
@@ -270,7 +270,7 @@ pub fn parse_type_declaration(
         Array => parse_array_type_definition(stream)?,
         Record =>  {
             let (def, end_ident) = parse_record_type_definition(stream)?;
-            end_ident_pos = check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics);
+            end_ident_pos = check_end_identifier_mismatch(stream, &ident.tree, end_ident, diagnostics);
             def
         },
         // Enumeration
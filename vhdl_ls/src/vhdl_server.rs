@@ -453,7 +453,7 @@ impl VHDLServer {
                     Some((ent, ent.designator().to_string().to_ascii_lowercase()))
                 }
                 Designator::OperatorSymbol(op) => Some((ent, op.to_string().to_ascii_lowercase())),
-                Designator::Anonymous(_) => None,
+                Designator::Anonymous(_) | Designator::Others => None,
             })
             .collect();
         symbols.sort_by(|(_, n1), (_, n2)| n1.cmp(n2));
@@ -467,7 +467,7 @@ impl VHDLServer {
                             name: ent.describe(),
                             kind: to_symbol_kind(ent.kind()),
                             tags: None,
-                            container_name: ent.parent.map(|ent| ent.path_name()),
+                            container_name: ent.parent.map(|ent| ent.full_name()),
                             location: OneOf::Left(srcpos_to_location(decl_pos)),
                             data: None,
                         })
@@ -787,7 +787,7 @@ fn type_kind(t: &Type) -> SymbolKind {
     match t {
         vhdl_lang::Type::Array { .. } => SymbolKind::ARRAY,
         vhdl_lang::Type::Enum(_) => SymbolKind::ENUM,
-        vhdl_lang::Type::Integer => SymbolKind::NUMBER,
+        vhdl_lang::Type::Integer(..) => SymbolKind::NUMBER,
         vhdl_lang::Type::Real => SymbolKind::NUMBER,
         vhdl_lang::Type::Physical => SymbolKind::NUMBER,
         vhdl_lang::Type::Access(_) => SymbolKind::ENUM,
@@ -817,13 +817,14 @@ fn to_symbol_kind(kind: &AnyEntKind) -> SymbolKind {
         AnyEntKind::Overloaded(o) => overloaded_kind(o),
         AnyEntKind::Type(t) => type_kind(t),
         AnyEntKind::ElementDeclaration(_) => SymbolKind::FIELD,
+        AnyEntKind::View(_) => SymbolKind::INTERFACE,
         AnyEntKind::Sequential(_) => SymbolKind::NAMESPACE,
-        AnyEntKind::Concurrent(Some(Concurrent::Instance)) => SymbolKind::MODULE,
+        AnyEntKind::Concurrent(Some(Concurrent::Instance(_))) => SymbolKind::MODULE,
         AnyEntKind::Concurrent(_) => SymbolKind::NAMESPACE,
         AnyEntKind::Library => SymbolKind::NAMESPACE,
         AnyEntKind::Design(d) => match d {
             vhdl_lang::Design::Entity(_, _) => SymbolKind::MODULE,
-            vhdl_lang::Design::Architecture(_) => SymbolKind::MODULE,
+            vhdl_lang::Design::Architecture(..) => SymbolKind::MODULE,
             vhdl_lang::Design::Configuration => SymbolKind::MODULE,
             vhdl_lang::Design::Package(_, _) => SymbolKind::PACKAGE,
             vhdl_lang::Design::PackageBody => SymbolKind::PACKAGE,